@@ -3,7 +3,9 @@
 use std::iter::Iterator;
 use std::slice::Iter;
 
-use crate::{FromGenericParam, FromGenerics, FromTypeParam, Result};
+use crate::{
+    FromConstParam, FromGenericParam, FromGenerics, FromLifetimeParam, FromTypeParam, Result,
+};
 
 /// Extension trait for `GenericParam` to support getting values by variant.
 ///
@@ -89,14 +91,20 @@ impl<T: FromTypeParam> FromTypeParam for GenericParam<T> {
     }
 }
 
-impl<T: FromTypeParam> FromGenericParam for GenericParam<T> {
+impl<T: FromTypeParam, L: FromLifetimeParam, C: FromConstParam> FromGenericParam
+    for GenericParam<T, L, C>
+{
     fn from_generic_param(param: &syn::GenericParam) -> Result<Self> {
         Ok(match *param {
             syn::GenericParam::Type(ref ty) => {
                 GenericParam::Type(FromTypeParam::from_type_param(ty)?)
             }
-            syn::GenericParam::Lifetime(ref val) => GenericParam::Lifetime(val.clone()),
-            syn::GenericParam::Const(ref val) => GenericParam::Const(val.clone()),
+            syn::GenericParam::Lifetime(ref val) => {
+                GenericParam::Lifetime(FromLifetimeParam::from_lifetime_param(val)?)
+            }
+            syn::GenericParam::Const(ref val) => {
+                GenericParam::Const(FromConstParam::from_const_param(val)?)
+            }
         })
     }
 }
@@ -1,6 +1,6 @@
 use proc_macro2::{Span, TokenStream};
 use quote::{quote, quote_spanned, ToTokens, TokenStreamExt};
-use syn::{spanned::Spanned, Ident};
+use syn::{spanned::Spanned, Ident, Member};
 
 use crate::util::Callable;
 
@@ -11,8 +11,8 @@ const DEFAULT_STRUCT_NAME: &str = "__default";
 #[derive(Debug, Clone)]
 pub enum DefaultExpression<'a> {
     /// Only valid on fields, `Inherit` indicates that the value should be taken from a pre-constructed
-    /// fallback object. The value in the variant is the ident of the field.
-    Inherit(&'a Ident),
+    /// fallback object. The value in the variant is the member of the field.
+    Inherit(&'a Member),
     /// `default = path::to::function` or `default = || default_val()`.
     Explicit(&'a Callable),
     Trait {
@@ -29,9 +29,9 @@ impl<'a> DefaultExpression<'a> {
 impl ToTokens for DefaultExpression<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         tokens.append_all(match *self {
-            DefaultExpression::Inherit(ident) => {
+            DefaultExpression::Inherit(member) => {
                 let dsn = Ident::new(DEFAULT_STRUCT_NAME, ::proc_macro2::Span::call_site());
-                quote!(#dsn.#ident)
+                quote!(#dsn.#member)
             }
             DefaultExpression::Explicit(callable) => {
                 // Use quote_spanned to properly set the span of the parentheses
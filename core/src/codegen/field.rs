@@ -1,11 +1,13 @@
 use std::borrow::Cow;
 
 use proc_macro2::TokenStream;
-use quote::{quote, quote_spanned, ToTokens, TokenStreamExt};
-use syn::{spanned::Spanned, Ident, Type};
+use quote::{format_ident, quote, quote_spanned, ToTokens, TokenStreamExt};
+use syn::{spanned::Spanned, Ident, Member, Type};
 
 use crate::codegen::{DefaultExpression, PostfixTransform};
+use crate::options::{Alias, Constraints, Relations};
 use crate::usage::{self, IdentRefSet, IdentSet, UsesTypeParams};
+use crate::util::Override;
 
 /// Properties needed to generate code for a field in all the contexts
 /// where one may appear.
@@ -15,10 +17,21 @@ pub struct Field<'a> {
     /// in error messages and will be looked when parsing names.
     pub name_in_attr: Cow<'a, str>,
 
+    /// Additional names which will be accepted in place of `name_in_attr`.
+    pub aliases: &'a [Alias],
+
     /// The name presented to the author of the library. This will appear
     /// in the setters or temporary variables which contain the values.
     pub ident: &'a Ident,
 
+    /// The name of the field when constructing the struct; for tuple structs, this is
+    /// the field's index.
+    pub member: &'a Member,
+
+    /// The index of this field among the positional arguments, if it can be provided
+    /// positionally.
+    pub position: Option<usize>,
+
     /// The type of the field in the input.
     pub ty: &'a Type,
     pub default_expression: Option<DefaultExpression<'a>>,
@@ -29,24 +42,140 @@ pub struct Field<'a> {
     pub post_transform: Option<&'a PostfixTransform>,
     pub skip: bool,
     pub multiple: bool,
-    /// If set, this field will be given all unclaimed meta items and will
+    /// If set, this field will be given the meta items its type claims and will
     /// not be exposed as a standard named field.
     pub flatten: bool,
+    /// If set, this flattened field is given all meta items which are not claimed by another
+    /// field. A struct's only flattened field is always the catch-all.
+    pub catch_all: bool,
+    /// If set, matching this field records a deprecation warning with an optional note.
+    pub deprecated: Option<&'a Override<String>>,
+    /// Checks which the parsed value must pass.
+    pub constraints: &'a Constraints,
+    /// Other fields which must or must not be present alongside this one.
+    pub relations: &'a Relations,
+    /// If set, the span of the key which provided this field is recorded, so that errors
+    /// about relations between fields can point to it.
+    pub track_key: bool,
+    /// If set, this field is not named in the input. Instead, it is provided by exactly one
+    /// of the keys its type knows, as returned by `FromMeta::known_keys`.
+    pub one_of_keys: bool,
+    /// The doc comment of the field, if any.
+    pub doc: Option<&'a str>,
 }
 
 impl<'a> Field<'a> {
     /// Get the name of the meta item that should be matched against input and should be used in diagnostics.
     ///
-    /// This will be `None` if the field is `skip` or `flatten`, as neither kind of field is addressable
-    /// by name from the input meta.
+    /// This will be `None` if the field is `skip`, `flatten` or `one_of_keys`, as none of those kinds
+    /// of field are addressable by name from the input meta.
     pub fn as_name(&'a self) -> Option<&'a str> {
-        if self.skip || self.flatten {
+        if self.skip || self.flatten || self.one_of_keys {
             None
         } else {
             Some(&self.name_in_attr)
         }
     }
 
+    /// Get the names that should be offered as alternates in "did you mean" errors: the
+    /// canonical name and any aliases which are not hidden.
+    ///
+    /// This will be empty if the field is `skip`, `flatten` or `one_of_keys`.
+    pub fn as_alts(&'a self) -> impl Iterator<Item = &'a str> {
+        let aliases = if self.as_name().is_some() {
+            self.aliases
+        } else {
+            &[]
+        };
+
+        self.as_name().into_iter().chain(
+            aliases
+                .iter()
+                .filter(|alias| !alias.hidden)
+                .map(|alias| alias.name.as_str()),
+        )
+    }
+
+    /// Get all the names which are accepted for this field, including hidden aliases.
+    ///
+    /// This will be empty if the field is `skip`, `flatten` or `one_of_keys`.
+    pub fn as_keys(&'a self) -> impl Iterator<Item = &'a str> {
+        let aliases = if self.as_name().is_some() {
+            self.aliases
+        } else {
+            &[]
+        };
+
+        self.as_name()
+            .into_iter()
+            .chain(aliases.iter().map(|alias| alias.name.as_str()))
+    }
+
+    /// Mark the fields whose keys must be tracked because they are part of a relation between
+    /// fields: those which declare relations, and those which are referred to by one.
+    pub fn track_related_keys(fields: &mut [Field<'_>]) {
+        let related: Vec<String> = fields
+            .iter()
+            .flat_map(|field| field.relations.targets().map(|(name, _)| name))
+            .collect();
+
+        for field in fields.iter_mut() {
+            field.track_key =
+                !field.relations.is_empty() || related.contains(&field.ident.to_string());
+        }
+    }
+
+    /// Make a struct's only flattened field its catch-all, so that it is given every item which
+    /// is not claimed by another field.
+    pub fn resolve_catch_all(fields: &mut [Field<'_>]) {
+        let mut flattened = fields.iter_mut().filter(|f| f.flatten);
+        if let (Some(field), None) = (flattened.next(), flattened.next()) {
+            field.catch_all = true;
+        }
+    }
+
+    /// Check if this is a flattened field which is only given the keys its type claims.
+    pub(in crate::codegen) fn is_routed(&self) -> bool {
+        self.flatten && !self.catch_all
+    }
+
+    /// Get the local variable which buffers the meta items given to a flattened field.
+    pub(in crate::codegen) fn flatten_var(&self) -> Ident {
+        format_ident!("__flatten_{}", self.ident)
+    }
+
+    /// Get the local variable which holds the span of the key that provided this field.
+    pub(in crate::codegen) fn key_var(&self) -> Ident {
+        format_ident!("__key_{}", self.ident)
+    }
+
+    /// Generate code which records the span of `__item` as the key of this field, if the
+    /// field's key is tracked.
+    fn record_key(&self) -> Option<TokenStream> {
+        if !self.track_key {
+            return None;
+        }
+
+        let key_var = self.key_var();
+        Some(quote! {
+            if #key_var.is_none() {
+                #key_var = _darling::export::Some(_darling::export::syn::spanned::Spanned::span(__item));
+            }
+        })
+    }
+
+    /// Get the local variable which holds the keys claimed by a `one_of_keys` field or a flattened
+    /// field which is not the catch-all.
+    pub(in crate::codegen) fn one_of_keys_var(&self) -> Ident {
+        format_ident!("__keys_{}", self.ident)
+    }
+
+    /// Get the local variable which holds the keys of a `one_of_keys` field that were given,
+    /// each with the span of its item.
+    fn given_keys_var(&self) -> Ident {
+        format_ident!("__given_{}", self.ident)
+    }
+
     pub fn as_declaration(&'a self) -> Declaration<'a> {
         Declaration(self)
     }
@@ -65,6 +194,14 @@ impl<'a> Field<'a> {
         MatchArm(self)
     }
 
+    pub fn as_one_of_keys_match(&'a self) -> OneOfKeysMatch<'a> {
+        OneOfKeysMatch(self)
+    }
+
+    pub fn as_positional_match(&'a self) -> PositionalMatchArm<'a> {
+        PositionalMatchArm(self)
+    }
+
     pub fn as_initializer(&'a self) -> Initializer<'a> {
         Initializer(self)
     }
@@ -72,6 +209,64 @@ impl<'a> Field<'a> {
     pub fn as_presence_check(&'a self) -> CheckMissing<'a> {
         CheckMissing(self)
     }
+
+    /// Generate code which pushes an error for each constraint in `scope` that `value` fails.
+    ///
+    /// `value` must evaluate to a reference to the checked value. If `node` is provided, the
+    /// errors are spanned to it.
+    fn constraint_checks(
+        &self,
+        scope: ConstraintScope,
+        value: TokenStream,
+        node: Option<TokenStream>,
+        location: &TokenStream,
+    ) -> TokenStream {
+        let constraints = self.constraints;
+        let on_collection = scope != ConstraintScope::Item;
+        let on_item = scope != ConstraintScope::Collection;
+
+        let range = constraints
+            .range
+            .as_ref()
+            .filter(|_| on_item)
+            .map(|range| quote_spanned!(range.span()=> check_range(#value, #range)));
+        let len = constraints
+            .len
+            .as_ref()
+            .filter(|_| on_collection)
+            .map(|range| quote_spanned!(range.span()=> check_len(#value, #range)));
+        let non_empty = Some(constraints.non_empty)
+            .filter(|flag| flag.is_present() && on_collection)
+            .map(|flag| quote_spanned!(flag.span()=> check_non_empty(#value)));
+        let one_of = constraints
+            .one_of
+            .as_ref()
+            .filter(|_| on_item)
+            .map(|values| quote!(check_one_of(#value, &[#(#values),*])));
+
+        let with_span = node.map(|node| quote!(.with_span(&#node)));
+        let checks = range.into_iter().chain(len).chain(non_empty).chain(one_of);
+
+        quote! {
+            #(
+                if let _darling::export::Err(__err) = _darling::util::constraint::#checks {
+                    __errors.push(__err #with_span .at(#location));
+                }
+            )*
+        }
+    }
+}
+
+/// The constraints which apply to a value, depending on whether it is one of many values of
+/// a `multiple` field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConstraintScope {
+    /// A field which holds a single value.
+    Value,
+    /// One of the values of a `multiple` field, which `range` and `one_of` apply to.
+    Item,
+    /// All the values of a `multiple` field, which `len` and `non_empty` apply to.
+    Collection,
 }
 
 impl UsesTypeParams for Field<'_> {
@@ -93,6 +288,11 @@ impl ToTokens for Declaration<'_> {
         let ident = field.ident;
         let ty = field.ty;
 
+        if field.track_key {
+            let key_var = field.key_var();
+            tokens.append_all(quote!(let mut #key_var = _darling::export::None;));
+        }
+
         tokens.append_all(if field.multiple {
             // This is NOT mutable, as it will be declared mutable only temporarily.
             quote!(let mut #ident: #ty = _darling::export::Default::default();)
@@ -100,14 +300,34 @@ impl ToTokens for Declaration<'_> {
             quote!(let mut #ident: (bool, _darling::export::Option<#ty>) = (false, None);)
         });
 
-        // The flatten field additionally needs a place to buffer meta items
+        // A `one_of_keys` field needs the keys it is provided by, which are only known once
+        // its type is, and the keys that were given so that conflicts can name them.
+        if field.one_of_keys {
+            let keys_var = field.one_of_keys_var();
+            let given_var = field.given_keys_var();
+            tokens.append_all(quote! {
+                let #keys_var: _darling::export::Vec<&'static str> =
+                    <#ty as _darling::FromMeta>::known_keys().unwrap_or_default();
+                let mut #given_var: _darling::export::Vec<(_darling::export::String, _)> = _darling::export::Vec::new();
+            });
+        }
+
+        // A flatten field additionally needs a place to buffer meta items
         // until attribute walking is done, so declare that now.
-        //
-        // We expect there can only be one field marked `flatten`, so it shouldn't
-        // be possible for this to shadow another declaration.
         if field.flatten {
+            let flatten_var = field.flatten_var();
             tokens.append_all(quote! {
-                let mut __flatten = _darling::export::Vec::<_darling::ast::NestedMeta>::new();
+                let mut #flatten_var = _darling::export::Vec::<_darling::ast::NestedMeta>::new();
+            });
+        }
+
+        // Unless it takes everything that is left over, it is only given the keys its type claims.
+        // A type which doesn't know its keys claims none, and is reported by `FlattenInitializer`.
+        if field.is_routed() {
+            let keys_var = field.one_of_keys_var();
+            tokens.append_all(quote! {
+                let #keys_var: _darling::export::Vec<&'static str> =
+                    <#ty as _darling::FromMeta>::known_keys().unwrap_or_default();
             });
         }
     }
@@ -125,6 +345,7 @@ impl ToTokens for FlattenInitializer<'_> {
             parent_field_names,
         } = self;
         let ident = field.ident;
+        let flatten_var = field.flatten_var();
 
         let add_parent_fields = if parent_field_names.is_empty() {
             None
@@ -134,10 +355,26 @@ impl ToTokens for FlattenInitializer<'_> {
             })
         };
 
+        // A routed field whose type doesn't know its keys would silently be given nothing.
+        if field.is_routed() {
+            let ty = field.ty;
+            let msg = format!(
+                "`{}` is flattened with other fields, but its type does not implement \
+                 `FromMeta::known_keys`; mark it `#[darling(flatten, catch_all)]` to give it \
+                 every key no other field claims",
+                ident
+            );
+            tokens.append_all(quote! {
+                if <#ty as _darling::FromMeta>::known_keys().is_none() {
+                    __errors.push(_darling::Error::custom(#msg));
+                }
+            });
+        }
+
         tokens.append_all(quote! {
             #ident = (true,
                 __errors.handle(
-                    _darling::FromMeta::from_list(&__flatten) #add_parent_fields
+                    _darling::FromMeta::from_list(&#flatten_var) #add_parent_fields
                     )
                 );
         });
@@ -151,14 +388,20 @@ impl ToTokens for MatchArm<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let field = self.0;
 
-        // Skipped and flattened fields cannot be populated by a meta
+        // Skipped, flattened and `one_of_keys` fields cannot be populated by a meta
         // with their name, so they do not have a match arm.
-        if field.skip || field.flatten {
+        if field.skip || field.flatten || field.one_of_keys {
             return;
         }
 
         let name_str = &field.name_in_attr;
+        let aliases = field.aliases.iter().map(|alias| &alias.name);
+        let pattern = quote!(#name_str #(| #aliases)*);
         let ident = field.ident;
+        let warn_deprecated = field
+            .deprecated
+            .map(|note| deprecation_warning(name_str, note, Some(quote!(__path))));
+        let record_key = field.record_key();
         let with_callable = &field.with_callable;
         let post_transform = field.post_transform.as_ref();
 
@@ -196,22 +439,51 @@ impl ToTokens for MatchArm<'_> {
             .map_err(|e| e.with_span(&__item).at(#location))
         );
 
+        let check_value = field.constraint_checks(
+            ConstraintScope::Value,
+            quote!(__value),
+            Some(quote!(__item)),
+            &location,
+        );
+        let check_value = quote! {
+            if let _darling::export::Some(ref __value) = #ident.1 {
+                #check_value
+            }
+        };
+        let check_item = field.constraint_checks(
+            ConstraintScope::Item,
+            quote!(__value),
+            Some(quote!(__item)),
+            &location,
+        );
+        let check_item = quote! {
+            if let _darling::export::Some(__value) = #ident.last() {
+                #check_item
+            }
+        };
+
         tokens.append_all(if field.multiple {
                 quote!(
-                    #name_str => {
+                    #pattern => {
+                        #warn_deprecated
+                        #record_key
                         // Store the index of the name we're assessing in case we need
                         // it for error reporting.
                         let __len = #ident.len();
                         if let _darling::export::Some(__val) = __errors.handle(#extractor) {
-                            #ident.push(__val)
+                            #ident.push(__val);
+                            #check_item
                         }
                     }
                 )
             } else {
                 quote!(
-                    #name_str => {
+                    #pattern => {
+                        #warn_deprecated
+                        #record_key
                         if !#ident.0 {
                             #ident = (true, __errors.handle(#extractor));
+                            #check_value
                         } else {
                             __errors.push(_darling::Error::duplicate_field(#name_str).with_span(&__item));
                         }
@@ -221,6 +493,124 @@ impl ToTokens for MatchArm<'_> {
     }
 }
 
+/// Claims the unrecognized item `__item` named `__other` for a `one_of_keys` field if its name
+/// is one of the field's keys. This generates the condition and body of an `if` branch.
+pub struct OneOfKeysMatch<'a>(&'a Field<'a>);
+
+impl ToTokens for OneOfKeysMatch<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let field = self.0;
+        if !field.one_of_keys {
+            return;
+        }
+
+        let ident = field.ident;
+        let ty = field.ty;
+        let name_str = &field.name_in_attr;
+        let keys_var = field.one_of_keys_var();
+        let given_var = field.given_keys_var();
+        let record_key = field.record_key();
+        let post_transform = field.post_transform.as_ref();
+
+        // The item is parsed as if it were the only item in a list named after the field,
+        // which is how an externally-tagged enum expects to find its variant.
+        let extractor = quote_spanned!(ty.span()=>
+            <#ty as _darling::FromMeta>::from_meta(&_darling::util::meta_list(
+                &_darling::util::path_from_string(#name_str),
+                &[_darling::export::Clone::clone(__item)],
+            ))
+            #post_transform
+            .map_err(|e| e.with_span(&__item))
+        );
+
+        let check_value = field.constraint_checks(
+            ConstraintScope::Value,
+            quote!(__value),
+            Some(quote!(__item)),
+            &quote!(#name_str),
+        );
+
+        tokens.append_all(quote! {
+            #keys_var.iter().any(|__key| *__key == __other) {
+                #record_key
+                if #given_var.iter().any(|(__key, _)| __key == __other) {
+                    __errors.push(_darling::Error::duplicate_field(__other).with_span(__item));
+                } else {
+                    // Only the first key is parsed. Conflicts are reported once all keys are known.
+                    if !#ident.0 {
+                        #ident = (true, __errors.handle(#extractor));
+                        if let _darling::export::Some(ref __value) = #ident.1 {
+                            #check_value
+                        }
+                    }
+                    #given_var.push((
+                        _darling::export::String::from(__other),
+                        _darling::export::syn::spanned::Spanned::span(__item),
+                    ));
+                }
+            }
+        });
+    }
+}
+
+/// Represents an individual field in the match on the index of a positional argument.
+pub struct PositionalMatchArm<'a>(&'a Field<'a>);
+
+impl ToTokens for PositionalMatchArm<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let field = self.0;
+        let position = match field.position {
+            Some(position) => position,
+            None => return,
+        };
+
+        let ident = field.ident;
+        let name_str = &field.name_in_attr;
+        let post_transform = field.post_transform.as_ref();
+
+        // A bare path in positional position is a value, such as an enum variant, rather than
+        // a word, so it is parsed as an expression. Named items never fill a positional slot:
+        // their name would otherwise be ignored.
+        let extractor = quote_spanned!(field.ty.span()=>
+            match *__item {
+                _darling::export::NestedMeta::Lit(ref __inner) => _darling::FromMeta::from_value(__inner),
+                _darling::export::NestedMeta::Meta(_darling::export::syn::Meta::Path(ref __inner)) => {
+                    _darling::FromMeta::from_expr(&_darling::export::syn::Expr::Path(_darling::export::syn::ExprPath {
+                        attrs: _darling::export::Vec::new(),
+                        qself: _darling::export::None,
+                        path: _darling::export::Clone::clone(__inner),
+                    }))
+                }
+                _darling::export::NestedMeta::Meta(_)
+                | _darling::export::NestedMeta::NameValueInvalidExpr(_) => {
+                    _darling::export::Err(_darling::Error::custom("Positional arguments must not be named"))
+                }
+            }
+            #post_transform
+            .map_err(|e| e.with_span(&__item).at(#name_str))
+        );
+
+        let check_value = field.constraint_checks(
+            ConstraintScope::Value,
+            quote!(__value),
+            Some(quote!(__item)),
+            &quote!(#name_str),
+        );
+
+        let record_key = field.record_key();
+
+        tokens.append_all(quote!(
+            #position => {
+                #record_key
+                #ident = (true, __errors.handle(#extractor));
+                if let _darling::export::Some(ref __value) = #ident.1 {
+                    #check_value
+                }
+            }
+        ));
+    }
+}
+
 /// Wrapper to generate initialization code for a field.
 pub struct Initializer<'a>(&'a Field<'a>);
 
@@ -228,39 +618,89 @@ impl ToTokens for Initializer<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let field = self.0;
         let ident = field.ident;
+        let member = field.member;
         tokens.append_all(if field.multiple {
             if let Some(ref expr) = field.default_expression {
-                quote_spanned!(expr.span()=> #ident: if !#ident.is_empty() {
+                quote_spanned!(expr.span()=> #member: if !#ident.is_empty() {
                     #ident
                 } else {
                     #expr
                 })
             } else {
-                quote!(#ident: #ident)
+                quote!(#member: #ident)
             }
         } else if let Some(ref expr) = field.default_expression {
-            quote_spanned!(expr.span()=> #ident: if let Some(__val) = #ident.1 {
+            quote_spanned!(expr.span()=> #member: if let Some(__val) = #ident.1 {
                 __val
             } else {
                 #expr
             })
         } else {
-            quote!(#ident: #ident.1.expect("Uninitialized fields without defaults were already checked"))
+            quote!(#member: #ident.1.expect("Uninitialized fields without defaults were already checked"))
         });
     }
 }
 
-/// Creates an error if a field has no value and no default.
+/// Creates an error if a field has no value and no default, or if a `one_of_keys` field was
+/// given more than one of its keys.
 pub struct CheckMissing<'a>(&'a Field<'a>);
 
 impl ToTokens for CheckMissing<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        // One error lists every key that was given, spanned to the first one that conflicts.
+        if self.0.one_of_keys {
+            let given_var = self.0.given_keys_var();
+            tokens.append_all(quote! {
+                if #given_var.len() > 1 {
+                    __errors.push(
+                        _darling::Error::conflicting_keys(#given_var.iter().map(|(__key, _)| __key))
+                            .with_span(&#given_var[1].1),
+                    );
+                }
+            });
+        }
+
+        // The number of values of a `multiple` field is only known once all items are read.
+        // If none were provided, the default is used instead and is not checked.
+        if self.0.multiple {
+            let ident = self.0.ident;
+            let name_in_attr = &self.0.name_in_attr;
+            let checks = self.0.constraint_checks(
+                ConstraintScope::Collection,
+                quote!(&#ident),
+                None,
+                &quote!(#name_in_attr),
+            );
+            if !checks.is_empty() {
+                tokens.append_all(if self.0.default_expression.is_some() {
+                    quote!(if !#ident.is_empty() { #checks })
+                } else {
+                    checks
+                });
+            }
+        }
+
         if !self.0.multiple && self.0.default_expression.is_none() {
             let ident = self.0.ident;
             let ty = self.0.ty;
             let name_in_attr = &self.0.name_in_attr;
             let from_none_call = super::from_none_call(ty);
 
+            // Fields of tuple structs have no name, so the error is about the number of items
+            // instead. Only the last missing field's position is reported, since all fields
+            // before it are required too.
+            let on_missing = match (self.0.member, self.0.position) {
+                (Member::Unnamed(_), Some(position)) => {
+                    let required = position + 1;
+                    quote!(__missing_positional = _darling::export::Some(#required);)
+                }
+                _ if self.0.one_of_keys => {
+                    let keys_var = self.0.one_of_keys_var();
+                    quote!(__errors.push(_darling::Error::missing_one_of(&#keys_var));)
+                }
+                _ => quote!(__errors.push(_darling::Error::missing_field(#name_in_attr))),
+            };
+
             tokens.append_all(quote! {
                 if !#ident.0 {
                     match #from_none_call {
@@ -268,7 +708,7 @@ impl ToTokens for CheckMissing<'_> {
                             #ident.1 = _darling::export::Some(__type_fallback);
                         }
                         _darling::export::None => {
-                            __errors.push(_darling::Error::missing_field(#name_in_attr))
+                            #on_missing
                         }
                     }
                 }
@@ -276,3 +716,116 @@ impl ToTokens for CheckMissing<'_> {
         }
     }
 }
+
+/// Generate code which records a warning that the deprecated field or variant `name` was used.
+///
+/// If `node` is provided, it must evaluate to a value whose span the warning should point to.
+pub(in crate::codegen) fn deprecation_warning(
+    name: &str,
+    deprecated: &Override<String>,
+    node: Option<TokenStream>,
+) -> TokenStream {
+    let note = match deprecated {
+        Override::Inherit => quote!(_darling::export::None),
+        Override::Explicit(note) => quote!(_darling::export::Some(#note)),
+    };
+    let with_span = node.map(|node| quote!(.with_span(#node)));
+
+    quote! {
+        _darling::warning::push(_darling::Warning::deprecated(#name, #note) #with_span);
+    }
+}
+
+/// Generate code which pushes an error for each relation between `fields` that the input
+/// breaks. Each error is spanned to the offending keys.
+pub(in crate::codegen) fn relation_checks(fields: &[Field<'_>]) -> TokenStream {
+    let find = |name: &str| fields.iter().find(|f| f.ident == name);
+    let mut checks = TokenStream::new();
+    // Conflicts are symmetric, so each pair is only reported once.
+    let mut conflicts: Vec<(&Ident, &Ident)> = Vec::new();
+
+    for field in fields.iter().filter(|f| !f.relations.is_empty()) {
+        let key = field.key_var();
+        let name = &field.name_in_attr;
+
+        for other in field
+            .relations
+            .conflicts_with
+            .iter()
+            .filter_map(|n| find(n))
+        {
+            if conflicts.contains(&(other.ident, field.ident)) {
+                continue;
+            }
+            conflicts.push((field.ident, other.ident));
+
+            let other_key = other.key_var();
+            let other_name = &other.name_in_attr;
+            let msg = format!("`{}` cannot be used with `{}`", name, other_name);
+            let other_msg = format!("`{}` cannot be used with `{}`", other_name, name);
+            checks.append_all(quote! {
+                if let (_darling::export::Some(__span), _darling::export::Some(__other_span)) = (#key, #other_key) {
+                    __errors.push(_darling::Error::custom(#msg).with_span(&__span));
+                    __errors.push(_darling::Error::custom(#other_msg).with_span(&__other_span));
+                }
+            });
+        }
+
+        for other in field.relations.requires.iter().filter_map(|n| find(n)) {
+            let other_key = other.key_var();
+            let msg = format!("`{}` requires `{}`", name, other.name_in_attr);
+            checks.append_all(quote! {
+                if let (_darling::export::Some(__span), _darling::export::None) = (#key, #other_key) {
+                    __errors.push(_darling::Error::custom(#msg).with_span(&__span));
+                }
+            });
+        }
+
+        for cond in &field.relations.required_if {
+            let other = match find(&cond.field.to_string()) {
+                Some(other) => other,
+                None => continue,
+            };
+            let other_key = other.key_var();
+            let other_ident = other.ident;
+            let other_ty = other.ty;
+            let value = &cond.value;
+            let msg = format!(
+                "Missing field `{}`, which is required when `{} = {}`",
+                name,
+                other.name_in_attr,
+                value.to_token_stream()
+            );
+            // The value can't be checked until the macro runs, so a value which is invalid for
+            // the other field's type is reported whenever that field is present.
+            let invalid = format!(
+                "Invalid value in `required_if({} = {})` on `{}`",
+                cond.field,
+                value.to_token_stream(),
+                name
+            );
+            checks.append_all(quote_spanned! {value.span()=>
+                if let _darling::export::Some(__other_span) = #other_key {
+                    // The expected value is parsed like the input, so it can be written the
+                    // same way as it would be in the attribute.
+                    let __expected = <#other_ty as _darling::FromMeta>::from_meta(
+                        &_darling::export::syn::parse_quote!(__value = #value),
+                    );
+                    match (&#other_ident.1, __expected) {
+                        (_, _darling::export::Err(__err)) => {
+                            __errors.push(_darling::Error::custom(format!("{}: {}", #invalid, __err)));
+                        }
+                        (_darling::export::Some(__actual), _darling::export::Ok(__expected)) => {
+                            if #key.is_none() && *__actual == __expected {
+                                __errors.push(_darling::Error::custom(#msg).with_span(&__other_span));
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            });
+        }
+    }
+
+    checks
+}
@@ -2,7 +2,10 @@ use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 
 use crate::{
-    codegen::{ExtractAttribute, OuterFromImpl, TraitImpl},
+    ast::Data,
+    codegen::{
+        ExtractAttribute, FieldsGen, OuterFromImpl, SchemaImpl, Tagging, TraitImpl, Variant,
+    },
     util::PathList,
 };
 
@@ -12,10 +15,21 @@ pub struct FromAttributesImpl<'a> {
     pub base: TraitImpl<'a>,
     pub attr_names: &'a PathList,
     pub forward_attrs: ForwardAttrs<'a>,
+    /// Whether or not to generate a `schema` function.
+    pub schema: bool,
 }
 
 impl ToTokens for FromAttributesImpl<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        if self.schema {
+            SchemaImpl {
+                base: &self.base,
+                attr_names: self.attr_names.to_strings(),
+                tagging: Tagging::default(),
+            }
+            .to_tokens(tokens);
+        }
+
         let ty_ident = self.base.ident;
         let input = self.param_name();
         let post_transform = self.base.post_transform_call();
@@ -40,6 +54,20 @@ impl ToTokens for FromAttributesImpl<'_> {
             return;
         };
 
+        if let Data::Enum(ref variants) = self.base.data {
+            let body = self.enum_body(variants);
+            self.wrap(
+                quote! {
+                    fn from_attributes(#input: &[_darling::export::syn::Attribute]) -> _darling::Result<Self> {
+                        #body
+                    }
+                },
+                tokens,
+            );
+
+            return;
+        }
+
         let forwarded_fields = vec![self.forward_attrs.to_field_value()]
             .into_iter()
             .flatten();
@@ -77,6 +105,131 @@ impl ToTokens for FromAttributesImpl<'_> {
     }
 }
 
+impl FromAttributesImpl<'_> {
+    /// Generate the body of `from_attributes` for an enum, where each variant
+    /// consumes the attribute whose path matches the variant's name.
+    fn enum_body(&self, variants: &[Variant<'_>]) -> TokenStream {
+        let input = self.param_name();
+        let post_transform = self.base.post_transform_call();
+        let variants = variants.iter().filter(|v| !v.skip).collect::<Vec<_>>();
+        let names = variants.iter().flat_map(|v| v.as_alts());
+        let patterns = variants.iter().map(|v| v.as_name_pattern());
+        let arms = variants.iter().map(|v| VariantAttrArm(v));
+
+        quote! {
+            let mut __errors = _darling::Error::accumulator();
+            let mut __matched: _darling::export::Option<_darling::export::String> = _darling::export::None;
+            let mut __items = _darling::export::Vec::<_darling::export::NestedMeta>::new();
+
+            for __attr in #input {
+                let __name = _darling::util::path_to_string(__attr.path());
+                match __name.as_str() {
+                    #(#patterns)|* => {}
+                    _ => continue,
+                }
+
+                if let _darling::export::Some(__prev) = &__matched {
+                    if *__prev != __name {
+                        __errors.push(
+                            _darling::Error::custom(format!(
+                                "Conflicting attributes: `#[{}]` cannot be used with `#[{}]`",
+                                __name, __prev
+                            ))
+                            .with_span(__attr),
+                        );
+                        continue;
+                    }
+                }
+
+                match &__attr.meta {
+                    _darling::export::syn::Meta::Path(_) => {}
+                    _darling::export::syn::Meta::List(__data) => {
+                        match _darling::export::NestedMeta::parse_meta_list(__data.tokens.clone()) {
+                            _darling::export::Ok(__parsed) => __items.extend(__parsed),
+                            _darling::export::Err(__err) => __errors.push(__err.into()),
+                        }
+                    }
+                    _darling::export::syn::Meta::NameValue(_) => {
+                        __errors.push(_darling::Error::unsupported_format("name-value").with_span(__attr));
+                    }
+                }
+
+                __matched = _darling::export::Some(__name);
+            }
+
+            let __items = &__items;
+
+            (match __matched.as_deref() {
+                #(#arms)*
+                _ => {
+                    __errors.push(_darling::Error::missing_attribute_with_alts(#input, &[#(#names),*]));
+                    _darling::export::Err(_darling::Error::multiple(__errors.into_inner()))
+                }
+            }) #post_transform
+        }
+    }
+}
+
+/// Code generator for the match arm which builds a variant from the items of its attribute.
+struct VariantAttrArm<'a>(&'a Variant<'a>);
+
+impl ToTokens for VariantAttrArm<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let val = self.0;
+        let pattern = val.as_name_pattern();
+        let variant_ident = val.variant_ident;
+        let ty_ident = val.ty_ident;
+
+        let body = if val.data.is_unit() {
+            quote! {
+                if let _darling::export::Some(__item) = __items.first() {
+                    __errors.push(_darling::Error::too_many_items(0).with_span(__item));
+                }
+
+                __errors.finish()?;
+
+                _darling::export::Ok(#ty_ident::#variant_ident)
+            }
+        } else if let Some((member, _)) = super::extract_transparent(&val.data, val.transparent) {
+            quote! {
+                let __value = __errors.handle(_darling::FromMeta::from_list(__items));
+
+                __errors.finish()?;
+
+                _darling::export::Ok(#ty_ident::#variant_ident {
+                    #member: __value.expect("Errors were already checked"),
+                })
+            }
+        } else {
+            let vdg = FieldsGen::new(&val.data, val.allow_unknown_fields);
+            let decls = vdg.declarations();
+            let core_loop = vdg.core_loop();
+            let require_fields = vdg.require_fields();
+            let inits = vdg.initializers();
+
+            quote! {
+                #decls
+
+                #core_loop
+
+                #require_fields
+
+                __errors.finish()?;
+
+                _darling::export::Ok(#ty_ident::#variant_ident {
+                    #inits
+                })
+            }
+        };
+
+        tokens.extend(quote! {
+            _darling::export::Some(#pattern) => {
+                #body
+            }
+        });
+    }
+}
+
 impl ExtractAttribute for FromAttributesImpl<'_> {
     fn local_declarations(&self) -> TokenStream {
         self.base.local_declarations()
@@ -5,7 +5,7 @@ use quote::{quote, quote_spanned, ToTokens};
 use syn::{parse_quote, parse_quote_spanned, spanned::Spanned, Ident};
 
 use crate::{
-    codegen::{ident_field, ExtractAttribute, OuterFromImpl, TraitImpl},
+    codegen::{ident_field, ExtractAttribute, OuterFromImpl, SchemaImpl, Tagging, TraitImpl},
     options::{DeriveInputShapeSet, ForwardedField},
     util::PathList,
 };
@@ -22,10 +22,21 @@ pub struct FromDeriveInputImpl<'a> {
     pub forward_attrs: ForwardAttrs<'a>,
     pub from_ident: bool,
     pub supports: Option<&'a DeriveInputShapeSet>,
+    /// Whether or not to generate a `schema` function.
+    pub schema: bool,
 }
 
 impl ToTokens for FromDeriveInputImpl<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        if self.schema {
+            SchemaImpl {
+                base: &self.base,
+                attr_names: self.attr_names.to_strings(),
+                tagging: Tagging::default(),
+            }
+            .to_tokens(tokens);
+        }
+
         let ty_ident = self.base.ident;
         let input = self.param_name();
         let post_transform = self.base.post_transform_call();
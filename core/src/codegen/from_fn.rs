@@ -0,0 +1,189 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{parse_quote, parse_quote_spanned, spanned::Spanned, Ident};
+
+use crate::codegen::signature_fields::read_forwarded_field;
+use crate::codegen::{
+    ident_field, ExtractAttribute, ForwardAttrs, OuterFromImpl, SignatureFields, TraitImpl,
+};
+use crate::options::ForwardedField;
+use crate::util::PathList;
+
+/// The kind of function item a [`FromFnImpl`] reads from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FnKind {
+    /// A free-standing `syn::ItemFn`, read by `FromFn`.
+    Item,
+    /// A `syn::ImplItemFn` inside an impl block, read by `FromImplItemFn`.
+    ImplItem,
+    /// A `syn::TraitItemFn` inside a trait definition, read by `FromTraitItemFn`.
+    TraitItem,
+}
+
+impl FnKind {
+    /// Whether the function has a visibility of its own. Trait methods do not.
+    pub fn has_vis(self) -> bool {
+        self != FnKind::TraitItem
+    }
+
+    fn trait_path(self) -> syn::Path {
+        match self {
+            FnKind::Item => path!(_darling::FromFn),
+            FnKind::ImplItem => path!(_darling::FromImplItemFn),
+            FnKind::TraitItem => path!(_darling::FromTraitItemFn),
+        }
+    }
+
+    fn method_ident(self) -> Ident {
+        let name = match self {
+            FnKind::Item => "from_fn",
+            FnKind::ImplItem => "from_impl_item_fn",
+            FnKind::TraitItem => "from_trait_item_fn",
+        };
+
+        Ident::new(name, Span::call_site())
+    }
+
+    fn input_ty(self) -> TokenStream {
+        match self {
+            FnKind::Item => quote!(_darling::export::syn::ItemFn),
+            FnKind::ImplItem => quote!(_darling::export::syn::ImplItemFn),
+            FnKind::TraitItem => quote!(_darling::export::syn::TraitItemFn),
+        }
+    }
+
+    /// An expression that reads the function body from `input`.
+    fn block(self, input: &TokenStream) -> TokenStream {
+        match self {
+            FnKind::Item | FnKind::ImplItem => quote!(#input.block.clone()),
+            FnKind::TraitItem => quote!(#input.default.clone()),
+        }
+    }
+}
+
+/// `impl FromFn` generator. This is used for parsing a function and its attributes,
+/// and is shared by `FromFn`, `FromImplItemFn`, and `FromTraitItemFn`.
+pub struct FromFnImpl<'a> {
+    pub kind: FnKind,
+    pub base: TraitImpl<'a>,
+    pub ident: Option<&'a ForwardedField>,
+    pub vis: Option<&'a Ident>,
+    pub sig: Option<&'a ForwardedField>,
+    pub block: Option<&'a Ident>,
+    pub sig_fields: SignatureFields<'a>,
+    pub attr_names: &'a PathList,
+    pub forward_attrs: ForwardAttrs<'a>,
+    pub from_ident: bool,
+}
+
+impl ToTokens for FromFnImpl<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let input = self.param_name();
+        let sig = quote!(#input.sig);
+
+        let error_declaration = self.base.declare_errors();
+        let grab_attrs = self.extractor();
+        let read_sig_fields = self.sig_fields.read_fields(&sig);
+        let require_fields = self.base.require_fields();
+        let error_check = self.base.check_errors();
+
+        let read_sig = self.sig.map(|field| {
+            read_forwarded_field(
+                field,
+                parse_quote_spanned!(field.ty.span()=> _darling::FromSignature::from_signature),
+                quote!(&#sig),
+            )
+        });
+
+        let default = if self.from_ident {
+            quote!(let __default: Self = _darling::export::From::from(#sig.ident.clone());)
+        } else {
+            self.base.fallback_decl()
+        };
+
+        let forwarded_fields = vec![
+            self.ident.as_ref().map(|i| ident_field::create(i, &sig)),
+            self.vis
+                .as_ref()
+                .map(|i| parse_quote!(#i: #input.vis.clone())),
+            self.forward_attrs.to_field_value(),
+            self.sig.map(|f| f.to_field_value()),
+            self.block.as_ref().map(|i| {
+                let block = self.kind.block(&input);
+                parse_quote!(#i: #block)
+            }),
+        ]
+        .into_iter()
+        .flatten()
+        .chain(self.sig_fields.field_values(&sig));
+
+        let initializers = self.base.initializers();
+
+        let post_transform = self.base.post_transform_call();
+
+        let method = self.kind.method_ident();
+        let input_ty = self.kind.input_ty();
+
+        self.wrap(
+            quote! {
+                fn #method(#input: &#input_ty) -> _darling::Result<Self> {
+                    #error_declaration
+
+                    #grab_attrs
+
+                    #read_sig
+
+                    #read_sig_fields
+
+                    #require_fields
+
+                    #error_check
+
+                    #default
+
+                    _darling::export::Ok(Self {
+                        #(#forwarded_fields,)*
+                        #initializers
+                    }) #post_transform
+                }
+            },
+            tokens,
+        );
+    }
+}
+
+impl ExtractAttribute for FromFnImpl<'_> {
+    fn attr_names(&self) -> &PathList {
+        self.attr_names
+    }
+
+    fn forward_attrs(&self) -> &ForwardAttrs<'_> {
+        &self.forward_attrs
+    }
+
+    fn param_name(&self) -> TokenStream {
+        quote!(__fn)
+    }
+
+    fn core_loop(&self) -> TokenStream {
+        self.base.core_loop()
+    }
+
+    fn local_declarations(&self) -> TokenStream {
+        self.base.local_declarations()
+    }
+}
+
+impl<'a> OuterFromImpl<'a> for FromFnImpl<'a> {
+    fn trait_path(&self) -> syn::Path {
+        self.kind.trait_path()
+    }
+
+    fn trait_bound(&self) -> syn::Path {
+        path!(_darling::FromMeta)
+    }
+
+    fn base(&'a self) -> &'a TraitImpl<'a> {
+        &self.base
+    }
+}
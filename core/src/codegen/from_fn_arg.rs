@@ -0,0 +1,157 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{parse_quote, Ident};
+
+use crate::codegen::{ident_field, ExtractAttribute, ForwardAttrs, OuterFromImpl, TraitImpl};
+use crate::options::ForwardedField;
+use crate::util::PathList;
+
+/// `impl FromFnArg` generator. This is used for parsing an individual
+/// function argument and its attributes.
+pub struct FromFnArgImpl<'a> {
+    pub base: TraitImpl<'a>,
+    pub ident: Option<&'a ForwardedField>,
+    pub pat: Option<&'a Ident>,
+    pub ty: Option<&'a Ident>,
+    pub attr_names: &'a PathList,
+    pub forward_attrs: ForwardAttrs<'a>,
+    pub from_ident: bool,
+}
+
+impl FromFnArgImpl<'_> {
+    /// An expression that evaluates to the `Option<syn::Ident>` bound by the argument.
+    /// Receivers produce `self`, and arguments that destructure their input produce `None`.
+    fn ident_accessor(&self) -> TokenStream {
+        let input = self.param_name();
+        quote! {
+            match #input {
+                _darling::export::syn::FnArg::Receiver(__arg) => {
+                    _darling::export::Some(_darling::export::From::from(__arg.self_token))
+                }
+                _darling::export::syn::FnArg::Typed(__arg) => match &*__arg.pat {
+                    _darling::export::syn::Pat::Ident(__pat) => {
+                        _darling::export::Some(__pat.ident.clone())
+                    }
+                    _ => _darling::export::None,
+                },
+            }
+        }
+    }
+}
+
+impl ToTokens for FromFnArgImpl<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let input = self.param_name();
+        let ident_accessor = self.ident_accessor();
+
+        let error_declaration = self.base.declare_errors();
+        let grab_attrs = self.extractor();
+        let require_fields = self.base.require_fields();
+        let error_check = self.base.check_errors();
+
+        let default = if self.from_ident {
+            quote!(let __default: Self = _darling::export::From::from(#ident_accessor);)
+        } else {
+            self.base.fallback_decl()
+        };
+
+        let forwarded_fields = vec![
+            self.ident
+                .as_ref()
+                .map(|i| ident_field::create_optional_from_source(i, &ident_accessor)),
+            self.forward_attrs.to_field_value(),
+            self.pat.as_ref().map(|i| {
+                parse_quote! {
+                    #i: match #input {
+                        _darling::export::syn::FnArg::Receiver(_) => _darling::export::None,
+                        _darling::export::syn::FnArg::Typed(__arg) => {
+                            _darling::export::Some((*__arg.pat).clone())
+                        }
+                    }
+                }
+            }),
+            self.ty.as_ref().map(|i| {
+                parse_quote! {
+                    #i: match #input {
+                        _darling::export::syn::FnArg::Receiver(__arg) => (*__arg.ty).clone(),
+                        _darling::export::syn::FnArg::Typed(__arg) => (*__arg.ty).clone(),
+                    }
+                }
+            }),
+        ]
+        .into_iter()
+        .flatten();
+
+        let initializers = self.base.initializers();
+
+        let post_transform = self.base.post_transform_call();
+
+        self.wrap(
+            quote! {
+                fn from_fn_arg(#input: &_darling::export::syn::FnArg) -> _darling::Result<Self> {
+                    #error_declaration
+
+                    #grab_attrs
+
+                    #require_fields
+
+                    #error_check
+
+                    #default
+
+                    _darling::export::Ok(Self {
+                        #(#forwarded_fields,)*
+                        #initializers
+                    }) #post_transform
+                }
+            },
+            tokens,
+        );
+    }
+}
+
+impl ExtractAttribute for FromFnArgImpl<'_> {
+    fn attr_names(&self) -> &PathList {
+        self.attr_names
+    }
+
+    fn forward_attrs(&self) -> &ForwardAttrs<'_> {
+        &self.forward_attrs
+    }
+
+    fn param_name(&self) -> TokenStream {
+        quote!(__fn_arg)
+    }
+
+    fn attrs_accessor(&self) -> TokenStream {
+        let input = self.param_name();
+        quote! {
+            match #input {
+                _darling::export::syn::FnArg::Receiver(__arg) => &__arg.attrs,
+                _darling::export::syn::FnArg::Typed(__arg) => &__arg.attrs,
+            }
+        }
+    }
+
+    fn core_loop(&self) -> TokenStream {
+        self.base.core_loop()
+    }
+
+    fn local_declarations(&self) -> TokenStream {
+        self.base.local_declarations()
+    }
+}
+
+impl<'a> OuterFromImpl<'a> for FromFnArgImpl<'a> {
+    fn trait_path(&self) -> syn::Path {
+        path!(_darling::FromFnArg)
+    }
+
+    fn trait_bound(&self) -> syn::Path {
+        path!(_darling::FromMeta)
+    }
+
+    fn base(&'a self) -> &'a TraitImpl<'a> {
+        &self.base
+    }
+}
@@ -0,0 +1,128 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+use crate::codegen::{ident_field, ExtractAttribute, ForwardAttrs, OuterFromImpl, TraitImpl};
+use crate::options::ForwardedField;
+use crate::util::PathList;
+
+pub struct FromGenericParamImpl<'a> {
+    pub base: TraitImpl<'a>,
+    pub ident: Option<&'a ForwardedField>,
+    pub attr_names: &'a PathList,
+    pub forward_attrs: ForwardAttrs<'a>,
+    pub from_ident: bool,
+}
+
+impl FromGenericParamImpl<'_> {
+    /// An expression that borrows the identifier of the generic param, regardless of its kind.
+    fn ident_accessor(&self) -> TokenStream {
+        let input = self.param_name();
+        quote! {
+            match #input {
+                _darling::export::syn::GenericParam::Type(__param) => &__param.ident,
+                _darling::export::syn::GenericParam::Lifetime(__param) => &__param.lifetime.ident,
+                _darling::export::syn::GenericParam::Const(__param) => &__param.ident,
+            }
+        }
+    }
+}
+
+impl ToTokens for FromGenericParamImpl<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let input = self.param_name();
+        let ident_accessor = self.ident_accessor();
+
+        let error_declaration = self.base.declare_errors();
+        let grab_attrs = self.extractor();
+        let require_fields = self.base.require_fields();
+        let error_check = self.base.check_errors();
+
+        let default = if self.from_ident {
+            quote!(let __default: Self = _darling::export::From::from(_darling::export::Clone::clone(#ident_accessor));)
+        } else {
+            self.base.fallback_decl()
+        };
+
+        let forwarded_fields = vec![
+            self.ident
+                .as_ref()
+                .map(|i| ident_field::create_from_source(i, &quote!(*(#ident_accessor)))),
+            self.forward_attrs.to_field_value(),
+        ]
+        .into_iter()
+        .flatten();
+
+        let initializers = self.base.initializers();
+
+        let post_transform = self.base.post_transform_call();
+
+        self.wrap(
+            quote! {
+                fn from_generic_param(#input: &_darling::export::syn::GenericParam) -> _darling::Result<Self> {
+                    #error_declaration
+
+                    #grab_attrs
+
+                    #require_fields
+
+                    #error_check
+
+                    #default
+
+                    _darling::export::Ok(Self {
+                        #(#forwarded_fields,)*
+                        #initializers
+                    }) #post_transform
+                }
+            },
+            tokens,
+        );
+    }
+}
+
+impl ExtractAttribute for FromGenericParamImpl<'_> {
+    fn attr_names(&self) -> &PathList {
+        self.attr_names
+    }
+
+    fn forward_attrs(&self) -> &ForwardAttrs<'_> {
+        &self.forward_attrs
+    }
+
+    fn param_name(&self) -> TokenStream {
+        quote!(__generic_param)
+    }
+
+    fn attrs_accessor(&self) -> TokenStream {
+        let input = self.param_name();
+        quote! {
+            match #input {
+                _darling::export::syn::GenericParam::Type(__param) => &__param.attrs,
+                _darling::export::syn::GenericParam::Lifetime(__param) => &__param.attrs,
+                _darling::export::syn::GenericParam::Const(__param) => &__param.attrs,
+            }
+        }
+    }
+
+    fn core_loop(&self) -> TokenStream {
+        self.base.core_loop()
+    }
+
+    fn local_declarations(&self) -> TokenStream {
+        self.base.local_declarations()
+    }
+}
+
+impl<'a> OuterFromImpl<'a> for FromGenericParamImpl<'a> {
+    fn trait_path(&self) -> syn::Path {
+        path!(_darling::FromGenericParam)
+    }
+
+    fn trait_bound(&self) -> syn::Path {
+        path!(_darling::FromMeta)
+    }
+
+    fn base(&'a self) -> &'a TraitImpl<'a> {
+        &self.base
+    }
+}
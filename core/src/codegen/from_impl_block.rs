@@ -0,0 +1,128 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{parse_quote, parse_quote_spanned, spanned::Spanned, Ident};
+
+use crate::codegen::signature_fields::read_forwarded_field;
+use crate::codegen::{ExtractAttribute, ForwardAttrs, OuterFromImpl, TraitImpl};
+use crate::options::ForwardedField;
+use crate::util::PathList;
+
+/// `impl FromImplBlock` generator. This is used for parsing an impl block,
+/// its attributes, and its methods.
+pub struct FromImplBlockImpl<'a> {
+    pub base: TraitImpl<'a>,
+    pub self_ty: Option<&'a Ident>,
+    pub trait_: Option<&'a Ident>,
+    pub generics: Option<&'a ForwardedField>,
+    pub items: Option<&'a ForwardedField>,
+    pub attr_names: &'a PathList,
+    pub forward_attrs: ForwardAttrs<'a>,
+}
+
+impl ToTokens for FromImplBlockImpl<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let input = self.param_name();
+
+        let error_declaration = self.base.declare_errors();
+        let grab_attrs = self.extractor();
+        let require_fields = self.base.require_fields();
+        let error_check = self.base.check_errors();
+        let default = self.base.fallback_decl();
+
+        let read_generics = self.generics.map(|field| {
+            read_forwarded_field(
+                field,
+                parse_quote_spanned!(field.ty.span()=> _darling::FromGenerics::from_generics),
+                quote!(&#input.generics),
+            )
+        });
+
+        let read_items = self.items.map(|field| {
+            read_forwarded_field(
+                field,
+                parse_quote_spanned!(field.ty.span()=> _darling::util::parse_impl_fns),
+                quote!(&#input.items),
+            )
+        });
+
+        let forwarded_fields = vec![
+            self.self_ty
+                .as_ref()
+                .map(|i| parse_quote!(#i: (*#input.self_ty).clone())),
+            self.trait_.as_ref().map(
+                |i| parse_quote!(#i: #input.trait_.as_ref().map(|(_, __path, _)| __path.clone())),
+            ),
+            self.forward_attrs.to_field_value(),
+            self.generics.map(|f| f.to_field_value()),
+            self.items.map(|f| f.to_field_value()),
+        ]
+        .into_iter()
+        .flatten();
+
+        let initializers = self.base.initializers();
+
+        let post_transform = self.base.post_transform_call();
+
+        self.wrap(
+            quote! {
+                fn from_impl_block(#input: &_darling::export::syn::ItemImpl) -> _darling::Result<Self> {
+                    #error_declaration
+
+                    #grab_attrs
+
+                    #read_generics
+
+                    #read_items
+
+                    #require_fields
+
+                    #error_check
+
+                    #default
+
+                    _darling::export::Ok(Self {
+                        #(#forwarded_fields,)*
+                        #initializers
+                    }) #post_transform
+                }
+            },
+            tokens,
+        );
+    }
+}
+
+impl ExtractAttribute for FromImplBlockImpl<'_> {
+    fn attr_names(&self) -> &PathList {
+        self.attr_names
+    }
+
+    fn forward_attrs(&self) -> &ForwardAttrs<'_> {
+        &self.forward_attrs
+    }
+
+    fn param_name(&self) -> TokenStream {
+        quote!(__impl)
+    }
+
+    fn core_loop(&self) -> TokenStream {
+        self.base.core_loop()
+    }
+
+    fn local_declarations(&self) -> TokenStream {
+        self.base.local_declarations()
+    }
+}
+
+impl<'a> OuterFromImpl<'a> for FromImplBlockImpl<'a> {
+    fn trait_path(&self) -> syn::Path {
+        path!(_darling::FromImplBlock)
+    }
+
+    fn trait_bound(&self) -> syn::Path {
+        path!(_darling::FromMeta)
+    }
+
+    fn base(&'a self) -> &'a TraitImpl<'a> {
+        &self.base
+    }
+}
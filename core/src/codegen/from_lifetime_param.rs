@@ -0,0 +1,111 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{parse_quote, Ident};
+
+use crate::codegen::{ident_field, ExtractAttribute, ForwardAttrs, OuterFromImpl, TraitImpl};
+use crate::options::ForwardedField;
+use crate::util::PathList;
+
+pub struct FromLifetimeParamImpl<'a> {
+    pub base: TraitImpl<'a>,
+    pub ident: Option<&'a ForwardedField>,
+    pub lifetime: Option<&'a Ident>,
+    pub bounds: Option<&'a Ident>,
+    pub attr_names: &'a PathList,
+    pub forward_attrs: ForwardAttrs<'a>,
+    pub from_ident: bool,
+}
+
+impl ToTokens for FromLifetimeParamImpl<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let input = self.param_name();
+
+        let error_declaration = self.base.declare_errors();
+        let grab_attrs = self.extractor();
+        let require_fields = self.base.require_fields();
+        let error_check = self.base.check_errors();
+
+        let default = if self.from_ident {
+            quote!(let __default: Self = _darling::export::From::from(#input.lifetime.ident.clone());)
+        } else {
+            self.base.fallback_decl()
+        };
+
+        let forwarded_fields = vec![
+            self.ident
+                .as_ref()
+                .map(|i| ident_field::create(i, &quote!(#input.lifetime))),
+            self.forward_attrs.to_field_value(),
+            self.lifetime
+                .as_ref()
+                .map(|i| parse_quote!(#i: #input.lifetime.clone())),
+            self.bounds
+                .as_ref()
+                .map(|i| parse_quote!(#i: #input.bounds.clone().into_iter().collect::<Vec<_>>())),
+        ]
+        .into_iter()
+        .flatten();
+
+        let initializers = self.base.initializers();
+
+        let post_transform = self.base.post_transform_call();
+
+        self.wrap(
+            quote! {
+                fn from_lifetime_param(#input: &_darling::export::syn::LifetimeParam) -> _darling::Result<Self> {
+                    #error_declaration
+
+                    #grab_attrs
+
+                    #require_fields
+
+                    #error_check
+
+                    #default
+
+                    _darling::export::Ok(Self {
+                        #(#forwarded_fields,)*
+                        #initializers
+                    }) #post_transform
+                }
+            },
+            tokens,
+        );
+    }
+}
+
+impl ExtractAttribute for FromLifetimeParamImpl<'_> {
+    fn attr_names(&self) -> &PathList {
+        self.attr_names
+    }
+
+    fn forward_attrs(&self) -> &ForwardAttrs<'_> {
+        &self.forward_attrs
+    }
+
+    fn param_name(&self) -> TokenStream {
+        quote!(__lifetime_param)
+    }
+
+    fn core_loop(&self) -> TokenStream {
+        self.base.core_loop()
+    }
+
+    fn local_declarations(&self) -> TokenStream {
+        self.base.local_declarations()
+    }
+}
+
+impl<'a> OuterFromImpl<'a> for FromLifetimeParamImpl<'a> {
+    fn trait_path(&self) -> syn::Path {
+        path!(_darling::FromLifetimeParam)
+    }
+
+    fn trait_bound(&self) -> syn::Path {
+        path!(_darling::FromMeta)
+    }
+
+    fn base(&'a self) -> &'a TraitImpl<'a> {
+        &self.base
+    }
+}
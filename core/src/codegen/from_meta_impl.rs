@@ -4,8 +4,11 @@ use proc_macro2::TokenStream;
 use quote::{quote, quote_spanned, ToTokens};
 use syn::spanned::Spanned;
 
-use crate::ast::{Data, Fields, Style};
-use crate::codegen::{Field, OuterFromImpl, TraitImpl, Variant};
+use crate::ast::Data;
+use crate::codegen::field::deprecation_warning;
+use crate::codegen::{
+    Field, OuterFromImpl, SchemaImpl, Tagging, TraitImpl, UntaggedMethod, Variant,
+};
 use crate::util::Callable;
 
 pub struct FromMetaImpl<'a> {
@@ -14,16 +17,26 @@ pub struct FromMetaImpl<'a> {
     pub from_none: Option<&'a Callable>,
     pub from_expr: Option<&'a Callable>,
     pub derive_syn_parse: bool,
+    /// How the variant is selected when the deriving type is an enum.
+    pub tagging: Tagging<'a>,
+    /// Whether or not unit variants can be selected by their integer discriminant.
+    pub discriminant: bool,
+    /// Whether or not to generate a `schema` function, which `describe` returns.
+    pub schema: bool,
 }
 
 impl ToTokens for FromMetaImpl<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let base = &self.base;
 
+        // Values are validated by the method which builds them. Generated methods which only
+        // delegate to another method, such as an enum's `from_expr`, rely on that method instead.
+        let validate = base.validate_call();
+
         let from_word = self.from_word.as_ref().map(|body| {
             quote_spanned! {body.span()=>
                 fn from_word() -> _darling::Result<Self> {
-                    _darling::export::identity::<fn() -> _darling::Result<Self>>(#body)()
+                    _darling::export::identity::<fn() -> _darling::Result<Self>>(#body)() #validate
                 }
             }
         });
@@ -39,11 +52,22 @@ impl ToTokens for FromMetaImpl<'_> {
         let from_expr = self.from_expr.map(|body| {
             quote_spanned! {body.span()=>
                 fn from_expr(expr: &_darling::export::syn::Expr) -> _darling::Result<Self> {
-                    _darling::export::identity::<fn(&_darling::export::syn::Expr) -> _darling::Result<Self>>(#body)(expr)
+                    _darling::export::identity::<fn(&_darling::export::syn::Expr) -> _darling::Result<Self>>(#body)(expr) #validate
+                }
+            }
+        });
+
+        // `Self::schema` resolves to the inherent function generated by `SchemaImpl`.
+        let describe = self.schema.then(|| {
+            quote! {
+                fn describe() -> _darling::export::Option<_darling::schema::Schema> {
+                    _darling::export::Some(Self::schema())
                 }
             }
         });
 
+        let expected = self.expected();
+
         // Transparent proxies to the sole value it contains
         if let Some((member, _)) = base
             .data
@@ -52,14 +76,20 @@ impl ToTokens for FromMetaImpl<'_> {
         {
             let ty_ident = base.ident;
             let impl_block = quote!(
+                #describe
+
+                #expected
+
                 fn from_meta(__item: &_darling::export::syn::Meta) -> _darling::Result<Self> {
                     _darling::FromMeta::from_meta(__item)
                         .map_err(|e| e.with_span(&__item))
                         .map(|value| #ty_ident { #member: value })
+                        #validate
                 }
             );
 
             self.wrap(impl_block, tokens);
+            self.schema_impl().to_tokens(tokens);
             if self.derive_syn_parse {
                 ParseImpl(self).to_tokens(tokens);
             }
@@ -76,12 +106,6 @@ impl ToTokens for FromMetaImpl<'_> {
                     }
                 )
             }
-            Data::Struct(Fields {
-                style: Style::Tuple,
-                ..
-            }) => {
-                panic!("Multi-field tuples are not supported");
-            }
             Data::Struct(ref data) => {
                 let inits = data.fields.iter().map(Field::as_initializer);
                 let declare_errors = base.declare_errors();
@@ -92,7 +116,29 @@ impl ToTokens for FromMetaImpl<'_> {
                 let default = base.fallback_decl();
                 let post_transform = base.post_transform_call();
 
+                // The keys of flattened and `one_of_keys` fields are claimed by their types.
+                let known_keys = if data.style.is_struct() {
+                    let keys = data.fields.iter().flat_map(Field::as_keys);
+                    let claimed = data
+                        .fields
+                        .iter()
+                        .filter(|f| f.flatten || f.one_of_keys)
+                        .map(|f| f.ty);
+                    Some(quote! {
+                        fn known_keys() -> _darling::export::Option<_darling::export::Vec<&'static str>> {
+                            let mut __keys = _darling::export::Vec::new();
+                            __keys.extend_from_slice(&[#(#keys),*]);
+                            #(__keys.extend(<#claimed as _darling::FromMeta>::known_keys()?);)*
+                            _darling::export::Some(__keys)
+                        }
+                    })
+                } else {
+                    None
+                };
+
                 quote!(
+                    #known_keys
+
                     #from_word
 
                     #from_none
@@ -119,11 +165,62 @@ impl ToTokens for FromMetaImpl<'_> {
                     }
                 )
             }
-            Data::Enum(ref variants) => {
-                let unit_arms = variants.iter().map(Variant::as_unit_match_arm);
+            Data::Enum(ref variants) if matches!(self.tagging, Tagging::Untagged) => {
+                let method = |method: UntaggedMethod, signature: TokenStream| {
+                    let attempts: Vec<_> = variants
+                        .iter()
+                        .map(|variant| variant.as_untagged_attempt(method, validate.as_ref()))
+                        .filter(|attempt| attempt.is_possible())
+                        .collect();
+
+                    // Leave the default implementation in place if no variant can be produced.
+                    if attempts.is_empty() {
+                        return None;
+                    }
+
+                    Some(quote! {
+                        #signature {
+                            let mut __attempts = _darling::export::Vec::new();
+                            #(#attempts)*
+                            _darling::export::Err(_darling::Error::multiple(__attempts))
+                        }
+                    })
+                };
+
+                let from_word = method(
+                    UntaggedMethod::Word,
+                    quote!(fn from_word() -> _darling::Result<Self>),
+                );
+                let from_list = method(
+                    UntaggedMethod::List,
+                    quote!(fn from_list(__items: &[_darling::export::NestedMeta]) -> _darling::Result<Self>),
+                );
+                let from_expr = method(
+                    UntaggedMethod::Expr,
+                    quote!(fn from_expr(__expr: &_darling::export::syn::Expr) -> _darling::Result<Self>),
+                );
+
+                quote!(
+                    #from_word
+
+                    #from_list
+
+                    #from_expr
 
-                let (unknown_variant_err, unknown_unit_variant_err) = if !variants.is_empty() {
-                    let names = variants.iter().map(Variant::as_name);
+                    #from_none
+                )
+            }
+            Data::Enum(ref variants) => {
+                let unit_arms: Vec<_> =
+                    variants.iter().map(|v| v.as_unit_match_arm(None)).collect();
+                // Arms for a string literal, which warnings can be spanned to.
+                let lit_unit_arms: Vec<_> = variants
+                    .iter()
+                    .map(|v| v.as_unit_match_arm(Some(quote!(__value))))
+                    .collect();
+
+                let mut names = variants.iter().flat_map(Variant::as_alts).peekable();
+                let (unknown_variant_err, unknown_unit_variant_err) = if names.peek().is_some() {
                     let names = quote!(&[#(#names),*]);
                     (
                         quote! {
@@ -144,33 +241,321 @@ impl ToTokens for FromMetaImpl<'_> {
 
                 let data_variants = variants.iter().map(Variant::as_data_match_arm);
 
-                quote!(
-                    fn from_list(__outer: &[_darling::export::NestedMeta]) -> _darling::Result<Self> {
+                // The variant which is produced for unrecognized names, instead of an error.
+                let other = variants.iter().find(|v| v.other && !v.skip);
+                // Name a literal for the unrecognized name, spanned to `node`.
+                let lit_at = |node: TokenStream| {
+                    Some(quote! {
+                        &_darling::export::syn::Lit::Str(_darling::export::syn::LitStr::new(
+                            __other,
+                            _darling::export::syn::spanned::Spanned::span(#node),
+                        ))
+                    })
+                };
+                // Accept bare identifiers and `Enum::Variant` paths wherever a unit variant's name
+                // could be written as a string, unless the author has provided `from_expr`. Enums
+                // with data variants keep the default, since a path can't produce those.
+                let names_only = variants
+                    .iter()
+                    .filter(|v| !v.skip)
+                    .all(|v| v.data.is_unit() || v.other);
+                let from_expr = from_expr.or_else(|| {
+                    if !names_only {
+                        return None;
+                    }
+
+                    let ty_name = base.ident.to_string();
+                    let idents = variants
+                        .iter()
+                        .filter(|v| !v.skip && !v.other)
+                        .map(|v| v.variant_ident.to_string());
+                    let names = variants
+                        .iter()
+                        .filter(|v| !v.skip && !v.other)
+                        .map(|v| &v.name_in_attr);
+                    // Other paths are passed to the `other` variant, if there is one.
+                    let unmatched_path = if let Some(other) = other {
+                        let body = other.as_other_body(lit_at(quote!(__path)));
+                        quote!((#body) #validate)
+                    } else if names.clone().next().is_some() {
+                        let names = variants.iter().flat_map(Variant::as_alts);
+                        quote! {
+                            _darling::export::Err(
+                                _darling::Error::unknown_value_with_alts(__other, &[#(#names),*]).with_span(__path),
+                            )
+                        }
+                    } else {
+                        quote!(_darling::export::Err(_darling::Error::unknown_value(__other).with_span(__path)))
+                    };
+
+                    // Negative discriminants are written as a negated integer literal.
+                    let negative_int_arm = if self.discriminant {
+                        Some(quote! {
+                            _darling::export::syn::Expr::Unary(_darling::export::syn::ExprUnary {
+                                op: _darling::export::syn::UnOp::Neg(_),
+                                expr: ref __inner,
+                                ..
+                            }) => match **__inner {
+                                _darling::export::syn::Expr::Lit(_darling::export::syn::ExprLit {
+                                    lit: _darling::export::syn::Lit::Int(ref __lit),
+                                    ..
+                                }) => Self::from_value(&_darling::export::syn::Lit::Int(
+                                    _darling::export::syn::LitInt::new(
+                                        &format!("-{}", __lit),
+                                        __lit.span(),
+                                    ),
+                                )),
+                                _ => _darling::export::Err(_darling::Error::unexpected_expr_type(__expr)),
+                            },
+                        })
+                    } else {
+                        None
+                    };
+
+                    Some(quote! {
+                        fn from_expr(__expr: &_darling::export::syn::Expr) -> _darling::Result<Self> {
+                            match *__expr {
+                                _darling::export::syn::Expr::Path(ref __path) if __path.qself.is_none() => {
+                                    let __segments = &__path.path.segments;
+                                    let __ident = if __path.path.leading_colon.is_none()
+                                        && __segments.iter().all(|s| s.arguments.is_none())
+                                        && (__segments.len() == 1
+                                            || (__segments.len() == 2 && __segments[0].ident == #ty_name))
+                                    {
+                                        &__segments[__segments.len() - 1].ident
+                                    } else {
+                                        let __other = &_darling::util::path_to_string(&__path.path);
+                                        return #unmatched_path;
+                                    };
+
+                                    // The variant's Rust name is accepted as well as its name in attributes.
+                                    let __ident_str = __ident.to_string();
+                                    let __name = match __ident_str.as_str() {
+                                        #(#idents => #names,)*
+                                        __other => __other,
+                                    };
+
+                                    Self::from_value(&_darling::export::syn::Lit::Str(
+                                        _darling::export::syn::LitStr::new(__name, __ident.span()),
+                                    ))
+                                }
+                                _darling::export::syn::Expr::Lit(ref __lit) => Self::from_value(&__lit.lit),
+                                #negative_int_arm
+                                _darling::export::syn::Expr::Group(ref __group) => Self::from_expr(&__group.expr),
+                                _ => _darling::export::Err(_darling::Error::unexpected_expr_type(__expr)),
+                            }
+                            .map_err(|e| e.with_span(__expr))
+                        }
+                    })
+                });
+
+                let (other_data_arm, other_unit_arm) = if let Some(other) = other {
+                    let data_body = other.as_other_body(lit_at(quote!(__other_path)));
+                    (
+                        quote! {
+                            if let _darling::export::syn::Meta::Path(ref __other_path) = *__nested {
+                                #data_body
+                            } else {
+                                _darling::export::Err(_darling::Error::unsupported_format("non-path").with_span(__nested))
+                            }
+                        },
+                        other.as_other_body(None),
+                    )
+                } else {
+                    (
+                        quote!(_darling::export::Err(_darling::Error::#unknown_variant_err.with_span(__nested))),
+                        quote!(_darling::export::Err(_darling::Error::#unknown_unit_variant_err)),
+                    )
+                };
+
+                // A newtype `other` variant is given the literal itself, so that its span is preserved.
+                let str_value_arm = other.filter(|other| !other.data.is_unit()).map(|other| {
+                    let other_value_arm = other.as_other_body(Some(quote!(__value)));
+                    quote! {
+                        _darling::export::syn::Lit::Str(ref __lit) => match __lit.value().as_str() {
+                            #(#lit_unit_arms)*
+                            __other => #other_value_arm
+                        },
+                    }
+                });
+
+                // In `discriminant` mode, integers are matched against the value of each variant
+                // when cast to an integer, which covers both explicit and implicit discriminants.
+                let int_value_arm = if self.discriminant {
+                    let ty_ident = base.ident;
+                    let matchable: Vec<_> =
+                        variants.iter().filter(|v| !v.skip && !v.other).collect();
+                    let checks = matchable.iter().map(|v| {
+                        let variant_ident = v.variant_ident;
+                        let warn_deprecated = v.deprecated.map(|note| {
+                            deprecation_warning(&v.name_in_attr, note, Some(quote!(__value)))
+                        });
+                        quote! {
+                            if __code == #ty_ident::#variant_ident as i128 {
+                                #warn_deprecated
+                                _darling::export::Ok(#ty_ident::#variant_ident)
+                            } else
+                        }
+                    });
+                    let codes = matchable.iter().map(|v| {
+                        let variant_ident = v.variant_ident;
+                        quote!((#ty_ident::#variant_ident as i128).to_string())
+                    });
+                    let fallback = match other {
+                        Some(other) => {
+                            let body = other.as_other_body(None);
+                            quote! {
+                                let __other = __lit.base10_digits();
+                                #body
+                            }
+                        }
+                        None => quote! {
+                            _darling::export::Err(_darling::Error::unknown_value_with_alts(
+                                &__code.to_string(),
+                                &[#(#codes),*],
+                            ))
+                        },
+                    };
+
+                    Some(quote! {
+                        _darling::export::syn::Lit::Int(ref __lit) => {
+                            let __code: i128 = __lit.base10_parse()?;
+                            #(#checks)* {
+                                #fallback
+                            }
+                        }
+                    })
+                } else {
+                    None
+                };
+
+                // Strings are matched here rather than by calling `from_string`, so that deprecation
+                // warnings can be spanned to the literal, and so the value is only validated once.
+                let str_value_arm = str_value_arm.unwrap_or_else(|| {
+                    quote! {
+                        _darling::export::syn::Lit::Str(ref __lit) => match __lit.value().as_str() {
+                            #(#lit_unit_arms)*
+                            __other => #other_unit_arm
+                        },
+                    }
+                });
+
+                let from_value = quote! {
+                    fn from_value(__value: &_darling::export::syn::Lit) -> _darling::Result<Self> {
+                        (match *__value {
+                            #str_value_arm
+                            #int_value_arm
+                            _ => _darling::export::Err(_darling::Error::unexpected_lit_type(__value)),
+                        })
+                        #validate
+                        .map_err(|e| e.with_span(__value))
+                    }
+                };
+
+                // Only the variant names can appear in the list of an externally-tagged enum,
+                // unless the `other` variant accepts any name.
+                let known_keys = if matches!(self.tagging, Tagging::External) && other.is_none() {
+                    let keys = variants.iter().filter(|v| !v.skip).flat_map(|v| {
+                        std::iter::once(v.as_name())
+                            .chain(v.aliases.iter().map(|alias| alias.name.as_str()))
+                    });
+                    Some(quote! {
+                        fn known_keys() -> _darling::export::Option<_darling::export::Vec<&'static str>> {
+                            _darling::export::Some(_darling::export::Vec::from([#(#keys),*]))
+                        }
+                    })
+                } else {
+                    None
+                };
+
+                let from_list = if let Tagging::Internal(tag) = self.tagging {
+                    let tagged_arms = variants.iter().map(Variant::as_tagged_match_arm);
+                    // Items other than the tag are ignored when falling back to the `other` variant.
+                    let other_tagged_arm = match other {
+                        Some(other) => other.as_other_body(lit_at(quote!(__tag_meta))),
+                        None => quote! {
+                            _darling::export::Err(_darling::Error::#unknown_unit_variant_err.with_span(__tag_meta).at(#tag))
+                        },
+                    };
+
+                    quote!(
+                        fn from_list(__outer: &[_darling::export::NestedMeta]) -> _darling::Result<Self> {
+                            // Split the tag from the items which will be parsed into the variant.
+                            let mut __tag = _darling::export::None;
+                            let mut __rest = _darling::export::Vec::with_capacity(__outer.len());
+                            let mut __errors = _darling::Error::accumulator();
+                            for __item in __outer {
+                                if let _darling::export::NestedMeta::Meta(ref __meta) = *__item {
+                                    if __meta.path().is_ident(#tag) {
+                                        if __tag.is_some() {
+                                            __errors.push(_darling::Error::duplicate_field(#tag).with_span(__meta));
+                                        } else if let _darling::export::Some(__value) = __errors.handle(
+                                            // The tag may be written as a string or as a bare path.
+                                            match *__meta {
+                                                _darling::export::syn::Meta::NameValue(_darling::export::syn::MetaNameValue {
+                                                    value: _darling::export::syn::Expr::Path(ref __path),
+                                                    ..
+                                                }) if __path.qself.is_none() => {
+                                                    _darling::export::Ok(_darling::util::path_to_string(&__path.path))
+                                                }
+                                                _ => <_darling::export::String as _darling::FromMeta>::from_meta(__meta),
+                                            }
+                                            .map_err(|e| e.at(#tag))
+                                        ) {
+                                            __tag = _darling::export::Some((__value, __meta));
+                                        }
+                                        continue;
+                                    }
+                                }
+                                __rest.push(_darling::export::Clone::clone(__item));
+                            }
+                            __errors.finish()?;
+
+                            let (__tag, __tag_meta) = __tag.ok_or_else(|| _darling::Error::missing_field(#tag))?;
+                            let __items = &__rest;
+                            (match __tag.as_str() {
+                                #(#tagged_arms)*
+                                __other => #other_tagged_arm
+                            }) #validate
+                        }
+                    )
+                } else {
+                    quote!(
+                        fn from_list(__outer: &[_darling::export::NestedMeta]) -> _darling::Result<Self> {
                         // An enum must have exactly one value inside the parentheses if it's not a unit
                         // match arm.
-                        match __outer.len() {
+                        (match __outer.len() {
                             0 => _darling::export::Err(_darling::Error::too_few_items(1)),
                             1 => {
                                 if let _darling::export::NestedMeta::Meta(ref __nested) = __outer[0] {
                                     match _darling::util::path_to_string(__nested.path()).as_ref() {
                                         #(#data_variants)*
-                                        __other => _darling::export::Err(_darling::Error::#unknown_variant_err.with_span(__nested))
+                                        __other => #other_data_arm
                                     }
                                 } else {
                                     _darling::export::Err(_darling::Error::unsupported_format("literal"))
                                 }
                             }
                             _ => _darling::export::Err(_darling::Error::too_many_items(1)),
-                        }
+                        }) #validate
                     }
+                    )
+                };
+
+                quote!(
+                    #known_keys
+
+                    #from_list
 
                     fn from_string(lit: &str) -> _darling::Result<Self> {
-                        match lit {
+                        (match lit {
                             #(#unit_arms)*
-                            __other => _darling::export::Err(_darling::Error::#unknown_unit_variant_err)
-                        }
+                            __other => #other_unit_arm
+                        }) #validate
                     }
 
+                    #from_value
+
                     #from_word
 
                     #from_none
@@ -180,13 +565,114 @@ impl ToTokens for FromMetaImpl<'_> {
             }
         };
 
-        self.wrap(impl_block, tokens);
+        self.wrap(quote!(#describe #expected #impl_block), tokens);
+        self.schema_impl().to_tokens(tokens);
         if self.derive_syn_parse {
             ParseImpl(self).to_tokens(tokens);
         }
     }
 }
 
+impl FromMetaImpl<'_> {
+    /// Generate `FromMeta::expected`, describing the input the deriving type accepts.
+    fn expected(&self) -> TokenStream {
+        let base = &self.base;
+        let body = match base.data {
+            Data::Struct(ref vd) => {
+                if let Some((_, field)) = super::extract_transparent(vd, base.transparent) {
+                    let ty = field.ty;
+                    quote!(<#ty as _darling::FromMeta>::expected())
+                } else {
+                    let expected = if vd.style.is_unit() {
+                        "a word".to_string()
+                    } else if vd.style.is_struct() {
+                        "a list like `name(key = value)`".to_string()
+                    } else {
+                        format!("a list of {} items", vd.len())
+                    };
+                    let expected = if self.from_word.is_some() && !vd.style.is_unit() {
+                        format!("a word or {}", expected)
+                    } else {
+                        expected
+                    };
+                    quote!(_darling::export::Some(_darling::export::Cow::Borrowed(#expected)))
+                }
+            }
+            // Untagged enums accept whatever any of their variants do.
+            Data::Enum(ref variants) if matches!(self.tagging, Tagging::Untagged) => {
+                let forms = variants.iter().filter(|v| !v.skip).map(|v| {
+                    if v.data.is_unit() {
+                        quote!(_darling::export::Some(_darling::export::Cow::Borrowed("a word")))
+                    } else if let Some((_, field)) =
+                        super::extract_transparent(&v.data, v.transparent)
+                    {
+                        let ty = field.ty;
+                        quote!(<#ty as _darling::FromMeta>::expected())
+                    } else {
+                        quote! {
+                            _darling::export::Some(_darling::export::Cow::Borrowed("a list like `name(key = value)`"))
+                        }
+                    }
+                });
+                quote! {
+                    let mut __forms: _darling::export::Vec<_darling::export::Cow<'static, str>> =
+                        _darling::export::Vec::new();
+                    #(
+                        if let _darling::export::Some(__form) = #forms {
+                            if !__forms.contains(&__form) {
+                                __forms.push(__form);
+                            }
+                        }
+                    )*
+                    if __forms.is_empty() {
+                        return _darling::export::None;
+                    }
+                    _darling::export::Some(_darling::export::Cow::Owned(__forms.join(" or ")))
+                }
+            }
+            // An `other` variant accepts any name, so there is no list to give.
+            Data::Enum(ref variants) if variants.iter().any(|v| v.other && !v.skip) => {
+                quote!(_darling::export::None)
+            }
+            Data::Enum(ref variants) => {
+                let names = variants
+                    .iter()
+                    .filter(|v| !v.skip)
+                    .map(|v| format!("`{}`", v.as_name()))
+                    .collect::<Vec<_>>();
+                if names.is_empty() {
+                    quote!(_darling::export::None)
+                } else {
+                    let mut expected = match self.tagging {
+                        Tagging::Internal(tag) => {
+                            format!("a list with `{}` naming one of {}", tag, names.join(", "))
+                        }
+                        _ => format!("one of {}", names.join(", ")),
+                    };
+                    if self.discriminant {
+                        expected.push_str(", or an integer discriminant");
+                    }
+                    quote!(_darling::export::Some(_darling::export::Cow::Borrowed(#expected)))
+                }
+            }
+        };
+
+        quote! {
+            fn expected() -> _darling::export::Option<_darling::export::Cow<'static, str>> {
+                #body
+            }
+        }
+    }
+
+    fn schema_impl(&self) -> Option<SchemaImpl<'_>> {
+        self.schema.then(|| SchemaImpl {
+            base: &self.base,
+            attr_names: Vec::new(),
+            tagging: self.tagging,
+        })
+    }
+}
+
 impl<'a> OuterFromImpl<'a> for FromMetaImpl<'a> {
     fn trait_path(&self) -> syn::Path {
         path!(_darling::FromMeta)
@@ -0,0 +1,81 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+use crate::codegen::{ident_field, OuterFromImpl, SignatureFields, TraitImpl};
+use crate::options::ForwardedField;
+
+/// `impl FromSignature` generator. Signatures have no attributes, so this
+/// only reads magic fields and applies defaults.
+pub struct FromSignatureImpl<'a> {
+    pub base: TraitImpl<'a>,
+    pub ident: Option<&'a ForwardedField>,
+    pub sig: SignatureFields<'a>,
+    pub from_ident: bool,
+}
+
+impl ToTokens for FromSignatureImpl<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let input = quote!(__sig);
+
+        let error_declaration = self.base.declare_errors();
+        let declarations = self.base.local_declarations();
+        let read_sig_fields = self.sig.read_fields(&input);
+        let require_fields = self.base.require_fields();
+        let error_check = self.base.check_errors();
+
+        let default = if self.from_ident {
+            quote!(let __default: Self = _darling::export::From::from(#input.ident.clone());)
+        } else {
+            self.base.fallback_decl()
+        };
+
+        let forwarded_fields = self
+            .ident
+            .as_ref()
+            .map(|i| ident_field::create(i, &input))
+            .into_iter()
+            .chain(self.sig.field_values(&input));
+
+        let initializers = self.base.initializers();
+
+        let post_transform = self.base.post_transform_call();
+
+        self.wrap(
+            quote! {
+                fn from_signature(#input: &_darling::export::syn::Signature) -> _darling::Result<Self> {
+                    #error_declaration
+
+                    #declarations
+
+                    #read_sig_fields
+
+                    #require_fields
+
+                    #error_check
+
+                    #default
+
+                    _darling::export::Ok(Self {
+                        #(#forwarded_fields,)*
+                        #initializers
+                    }) #post_transform
+                }
+            },
+            tokens,
+        );
+    }
+}
+
+impl<'a> OuterFromImpl<'a> for FromSignatureImpl<'a> {
+    fn trait_path(&self) -> syn::Path {
+        path!(_darling::FromSignature)
+    }
+
+    fn trait_bound(&self) -> syn::Path {
+        path!(_darling::FromMeta)
+    }
+
+    fn base(&'a self) -> &'a TraitImpl<'a> {
+        &self.base
+    }
+}
@@ -21,19 +21,46 @@ pub fn create(ident_field: &ForwardedField, input: &TokenStream) -> FieldValue {
     create_inner(ident_field, input, false)
 }
 
+/// Creates a field literal: `field: T,` where the `syn::Ident` is read from `source`
+/// rather than from the `ident` member of the input.
+///
+/// This is used by inputs such as `syn::LifetimeParam` and `syn::GenericParam`, which
+/// don't have an `ident` member of their own.
+pub fn create_from_source(ident_field: &ForwardedField, source: &TokenStream) -> FieldValue {
+    create_with_source(ident_field, source, false)
+}
+
+/// Creates a field literal: `field: Option<T>,` where the `Option<syn::Ident>` is read from
+/// `source` rather than from the `ident` member of the input.
+pub fn create_optional_from_source(
+    ident_field: &ForwardedField,
+    source: &TokenStream,
+) -> FieldValue {
+    create_with_source(ident_field, source, true)
+}
+
 fn create_inner(
     ident_field: &ForwardedField,
     input: &TokenStream,
     is_option_ident: bool,
+) -> FieldValue {
+    let source = quote_spanned!(ident_field.ident.span()=> #input.ident);
+    create_with_source(ident_field, &source, is_option_ident)
+}
+
+fn create_with_source(
+    ident_field: &ForwardedField,
+    source: &TokenStream,
+    is_option_ident: bool,
 ) -> FieldValue {
     let ident = &ident_field.ident;
 
     // If the error has a type mismatch, point to the identifier in the field.
     //
-    // Adding parentheses around the expression `#input.ident` will make the error
+    // Adding parentheses around the expression `#source` will make the error
     // point to field name of the user's type. Without them, the error is at `Span::call_site()`
     let input = quote_spanned! {
-        ident_field.ident.span() => _darling::export::Clone::clone(&(#input.ident))
+        ident_field.ident.span() => _darling::export::Clone::clone(&(#source))
     };
 
     if let Some(callable) = &ident_field.with {
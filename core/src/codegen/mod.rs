@@ -4,15 +4,26 @@ mod default_expr;
 mod error;
 mod field;
 mod from_attributes_impl;
+mod from_const_param;
 mod from_derive_impl;
 mod from_field;
+mod from_fn;
+mod from_fn_arg;
+mod from_generic_param;
+mod from_impl_block;
+mod from_lifetime_param;
 mod from_meta_impl;
 mod from_none;
+mod from_signature;
+mod from_trait_def;
 mod from_type_param;
 mod from_variant_impl;
 pub mod ident_field;
 mod outer_from_impl;
 mod postfix_transform;
+mod schema_impl;
+mod signature_fields;
+mod to_meta_impl;
 mod trait_impl;
 mod transparent;
 mod variant;
@@ -24,16 +35,27 @@ pub use self::attrs_field::ForwardAttrs;
 pub use self::default_expr::DefaultExpression;
 pub use self::field::Field;
 pub use self::from_attributes_impl::FromAttributesImpl;
+pub use self::from_const_param::FromConstParamImpl;
 pub use self::from_derive_impl::FromDeriveInputImpl;
 pub use self::from_field::FromFieldImpl;
+pub use self::from_fn::{FnKind, FromFnImpl};
+pub use self::from_fn_arg::FromFnArgImpl;
+pub use self::from_generic_param::FromGenericParamImpl;
+pub use self::from_impl_block::FromImplBlockImpl;
+pub use self::from_lifetime_param::FromLifetimeParamImpl;
 pub use self::from_meta_impl::FromMetaImpl;
 pub use self::from_none::from_none_call;
+pub use self::from_signature::FromSignatureImpl;
+pub use self::from_trait_def::FromTraitDefImpl;
 pub use self::from_type_param::FromTypeParamImpl;
 pub use self::from_variant_impl::FromVariantImpl;
 pub use self::outer_from_impl::OuterFromImpl;
 pub use self::postfix_transform::PostfixTransform;
+pub use self::schema_impl::SchemaImpl;
+pub use self::signature_fields::SignatureFields;
+pub use self::to_meta_impl::ToMetaImpl;
 pub use self::trait_impl::TraitImpl;
 pub use self::transparent::extract_transparent;
-pub use self::variant::Variant;
+pub use self::variant::{Tagging, UntaggedMethod, Variant};
 pub use self::variant_data::FieldsGen;
 pub use self::wrap_in_const::wrap_in_const;
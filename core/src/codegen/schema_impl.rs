@@ -0,0 +1,295 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned, ToTokens, TokenStreamExt};
+use syn::{spanned::Spanned, GenericArgument, Member, PathArguments, Type};
+
+use crate::ast::{Data, Fields};
+use crate::codegen::{DefaultExpression, Field, Tagging, TraitImpl, Variant};
+use crate::options::Alias;
+use crate::util::Override;
+
+/// Generates the inherent `schema` function of a container with `#[darling(schema)]`.
+pub struct SchemaImpl<'a> {
+    pub base: &'a TraitImpl<'a>,
+    /// The attributes read by a `FromDeriveInput` or `FromAttributes` receiver.
+    pub attr_names: Vec<String>,
+    /// How the variant is selected when the deriving type is an enum.
+    pub tagging: Tagging<'a>,
+}
+
+impl SchemaImpl<'_> {
+    fn kind(&self) -> TokenStream {
+        let base = self.base;
+        match base.data {
+            Data::Struct(ref vd) if vd.style.is_unit() => {
+                quote!(_darling::schema::SchemaKind::Unit)
+            }
+            Data::Struct(ref vd) => {
+                if let Some((_, field)) = super::extract_transparent(vd, base.transparent) {
+                    let newtype = newtype(field.ty);
+                    quote!(_darling::schema::SchemaKind::Newtype(#newtype))
+                } else {
+                    let fields = fields(vd, base.allow_unknown_fields);
+                    quote!(_darling::schema::SchemaKind::Struct(#fields))
+                }
+            }
+            Data::Enum(ref variants) => {
+                let variants = variants.iter().map(variant);
+                let tagging = match self.tagging {
+                    Tagging::External => quote!(External),
+                    Tagging::Internal(tag) => quote!(Internal(#tag)),
+                    Tagging::Untagged => quote!(Untagged),
+                };
+                quote! {
+                    _darling::schema::SchemaKind::Enum(_darling::schema::Enum::new(
+                        _darling::export::Vec::from([#(#variants),*]),
+                        _darling::schema::Tagging::#tagging,
+                    ))
+                }
+            }
+        }
+    }
+}
+
+impl ToTokens for SchemaImpl<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let base = self.base;
+        let ty_ident = base.ident;
+        let ty_name = ty_ident.to_string();
+        let attr_names = &self.attr_names;
+        let kind = self.kind();
+        let doc = option_str(base.doc);
+
+        // Fields whose types are described must be bounded the same way as in the trait impl.
+        let generics = super::outer_from_impl::compute_impl_bounds(
+            path!(_darling::FromMeta),
+            base.generics.clone(),
+            &base.used_type_params(),
+        );
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let impl_ = quote! {
+            #[automatically_derived]
+            impl #impl_generics #ty_ident #ty_generics #where_clause {
+                /// Describe the attribute syntax read by this type.
+                pub fn schema() -> _darling::schema::Schema {
+                    _darling::schema::Schema::build::<Self, _>(#ty_name, || {
+                        let mut __schema = _darling::schema::Schema::new(#ty_name, #kind);
+                        __schema.attributes = _darling::export::Vec::from([#(#attr_names),*]);
+                        __schema.doc = #doc;
+                        __schema
+                    })
+                }
+            }
+        };
+
+        tokens.append_all(super::wrap_in_const(&impl_, base.krate));
+    }
+}
+
+fn fields(vd: &Fields<Field<'_>>, allow_unknown_fields: bool) -> TokenStream {
+    let fields = vd.fields.iter().map(field);
+    quote! {
+        {
+            let mut __fields = _darling::schema::Fields::new(_darling::export::Vec::from([#(#fields),*]));
+            __fields.allow_unknown_fields = #allow_unknown_fields;
+            __fields
+        }
+    }
+}
+
+fn field(field: &Field<'_>) -> TokenStream {
+    let name = match field.member {
+        Member::Unnamed(ref index) => index.index.to_string(),
+        Member::Named(_) => field.name_in_attr.to_string(),
+    };
+    let aliases = visible_aliases(field.aliases);
+    let ty = source_text(field.ty);
+    let required =
+        if field.multiple || field.flatten || field.skip || field.default_expression.is_some() {
+            quote!(false)
+        } else {
+            let from_none_call = super::from_none_call(field.ty);
+            quote!(#from_none_call.is_none())
+        };
+    let default = match field.default_expression {
+        None => quote!(_darling::export::None),
+        Some(DefaultExpression::Trait { .. }) => {
+            quote!(_darling::export::Some(
+                _darling::schema::FieldDefault::Trait
+            ))
+        }
+        Some(DefaultExpression::Explicit(callable)) => {
+            let callable = source_text(callable);
+            quote!(_darling::export::Some(_darling::schema::FieldDefault::Function(#callable)))
+        }
+        Some(DefaultExpression::Inherit(_)) => {
+            quote!(_darling::export::Some(
+                _darling::schema::FieldDefault::Container
+            ))
+        }
+    };
+    let multiple = field.multiple;
+    let flatten = field.flatten;
+    let skip = field.skip;
+    let positional = field.position.is_some();
+    let one_of_keys = field.one_of_keys;
+    let deprecated = deprecation(field.deprecated);
+    let doc = option_str(field.doc);
+    let nested = if field.skip {
+        quote!(_darling::export::None)
+    } else if field.multiple {
+        item_type(field.ty).map_or_else(|| quote!(_darling::export::None), describe_call)
+    } else {
+        describe_call(field.ty)
+    };
+
+    quote! {
+        {
+            let mut __field = _darling::schema::Field::new(#name, #ty);
+            __field.aliases = _darling::export::Vec::from([#(#aliases),*]);
+            __field.required = #required;
+            __field.default = #default;
+            __field.multiple = #multiple;
+            __field.flatten = #flatten;
+            __field.skip = #skip;
+            __field.positional = #positional;
+            __field.one_of_keys = #one_of_keys;
+            __field.deprecated = #deprecated;
+            __field.nested = #nested;
+            __field.doc = #doc;
+            __field
+        }
+    }
+}
+
+fn variant(variant: &Variant<'_>) -> TokenStream {
+    let name = variant.as_name();
+    let aliases = visible_aliases(variant.aliases);
+    let skip = variant.skip;
+    let other = variant.other;
+    let deprecated = deprecation(variant.deprecated);
+    let doc = option_str(variant.doc);
+    let data = &variant.data;
+    let kind = if data.is_unit() {
+        quote!(_darling::schema::VariantKind::Unit)
+    } else if let Some((_, field)) = super::extract_transparent(data, variant.transparent) {
+        let newtype = newtype(field.ty);
+        quote!(_darling::schema::VariantKind::Newtype(#newtype))
+    } else {
+        let fields = fields(data, variant.allow_unknown_fields);
+        quote!(_darling::schema::VariantKind::Struct(#fields))
+    };
+
+    quote! {
+        {
+            let mut __variant = _darling::schema::Variant::new(#name, #kind);
+            __variant.aliases = _darling::export::Vec::from([#(#aliases),*]);
+            __variant.skip = #skip;
+            __variant.other = #other;
+            __variant.deprecated = #deprecated;
+            __variant.doc = #doc;
+            __variant
+        }
+    }
+}
+
+fn newtype(ty: &Type) -> TokenStream {
+    let name = source_text(ty);
+    let nested = describe_call(ty);
+    quote! {
+        {
+            let mut __newtype = _darling::schema::Newtype::new(#name);
+            __newtype.nested = #nested;
+            __newtype
+        }
+    }
+}
+
+fn visible_aliases(aliases: &[Alias]) -> impl Iterator<Item = &str> {
+    aliases
+        .iter()
+        .filter(|alias| !alias.hidden)
+        .map(|alias| alias.name.as_str())
+}
+
+fn deprecation(deprecated: Option<&Override<String>>) -> TokenStream {
+    match deprecated {
+        None => quote!(_darling::export::None),
+        Some(Override::Inherit) => quote! {
+            _darling::export::Some(_darling::schema::Deprecation::new(_darling::export::None))
+        },
+        Some(Override::Explicit(note)) => quote! {
+            _darling::export::Some(_darling::schema::Deprecation::new(_darling::export::Some(#note)))
+        },
+    }
+}
+
+fn option_str(value: Option<&str>) -> TokenStream {
+    match value {
+        Some(value) => quote!(_darling::export::Some(#value)),
+        None => quote!(_darling::export::None),
+    }
+}
+
+/// Get a type or expression as it would be written in the source, without the spaces
+/// `TokenStream` puts between every token.
+fn source_text<T: ToTokens>(tokens: &T) -> String {
+    let mut name = tokens.to_token_stream().to_string();
+    for (spaced, tight) in [
+        (" :: ", "::"),
+        (":: ", "::"),
+        (" <", "<"),
+        ("< ", "<"),
+        (" >", ">"),
+        (" ,", ","),
+        ("& ", "&"),
+        ("[ ", "["),
+        (" ]", "]"),
+        (" ;", ";"),
+        ("( ", "("),
+        (" )", ")"),
+        (" ()", "()"),
+        (" . ", "."),
+        // The empty parameter list of a closure.
+        ("| |", "||"),
+    ] {
+        name = name.replace(spaced, tight);
+    }
+
+    name
+}
+
+/// Get the item type of a collection such as `Vec<T>`, which is the type read for each
+/// occurrence of a `multiple` field.
+fn item_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(ty) = ty else {
+        return None;
+    };
+    let PathArguments::AngleBracketed(ref args) = ty.path.segments.last()?.arguments else {
+        return None;
+    };
+
+    let mut types = args.args.iter().filter_map(|arg| match arg {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    });
+    match (types.next(), types.next()) {
+        (Some(ty), None) => Some(ty),
+        _ => None,
+    }
+}
+
+/// Calls `<#ty as FromMeta>::describe()` if `#ty` implements `FromMeta`, boxing the result.
+/// If it doesn't, evaluates to `None`.
+fn describe_call(ty: &Type) -> TokenStream {
+    // See `from_none_call` for how this selects an impl.
+    quote_spanned!(ty.span()=> {
+        #[allow(unused)]
+        use _darling::autoref_specialization::{SpecFromMeta as _, SpecFromMetaAll as _};
+
+        (&_darling::export::PhantomData::<#ty>)
+            .tag()
+            .describe()
+            .map(_darling::export::Box::new)
+    })
+}
@@ -0,0 +1,88 @@
+use std::borrow::Cow;
+
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{parse_quote, parse_quote_spanned, spanned::Spanned, FieldValue, Ident};
+
+use crate::options::ForwardedField;
+use crate::util::Callable;
+
+/// Generator for the magic fields read from a `syn::Signature`, shared by the
+/// `FromSignature` and `FromFn` impls.
+pub struct SignatureFields<'a> {
+    pub inputs: Option<&'a ForwardedField>,
+    pub output: Option<&'a Ident>,
+    pub asyncness: Option<&'a Ident>,
+    pub constness: Option<&'a Ident>,
+    pub generics: Option<&'a ForwardedField>,
+}
+
+impl SignatureFields<'_> {
+    /// Declare locals for the fallible magic fields, adding any errors to `__errors`.
+    ///
+    /// `sig` must be an expression that evaluates to a `syn::Signature`.
+    pub fn read_fields(&self, sig: &TokenStream) -> TokenStream {
+        let read_inputs = self.inputs.map(|inputs| {
+            read_forwarded_field(
+                inputs,
+                parse_quote_spanned!(inputs.ty.span()=> _darling::util::parse_fn_args),
+                quote!(&#sig.inputs),
+            )
+        });
+
+        let read_generics = self.generics.map(|generics| {
+            read_forwarded_field(
+                generics,
+                parse_quote_spanned!(generics.ty.span()=> _darling::FromGenerics::from_generics),
+                quote!(&#sig.generics),
+            )
+        });
+
+        quote! {
+            #read_inputs
+            #read_generics
+        }
+    }
+
+    /// Get the field initializers for the magic fields. This assumes `read_fields` has
+    /// already been emitted and that errors have been checked.
+    pub fn field_values(&self, sig: &TokenStream) -> Vec<FieldValue> {
+        vec![
+            self.inputs.map(|f| f.to_field_value()),
+            self.output
+                .as_ref()
+                .map(|i| parse_quote!(#i: #sig.output.clone())),
+            self.asyncness
+                .as_ref()
+                .map(|i| parse_quote!(#i: #sig.asyncness.is_some())),
+            self.constness
+                .as_ref()
+                .map(|i| parse_quote!(#i: #sig.constness.is_some())),
+            self.generics.map(|f| f.to_field_value()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+/// Declare a local with the same name as `field`, holding the result of calling its `with`
+/// function (or `fallback`) on `input`. Errors are added to `__errors`.
+pub(in crate::codegen) fn read_forwarded_field(
+    field: &ForwardedField,
+    fallback: Callable,
+    input: TokenStream,
+) -> TokenStream {
+    let ident = &field.ident;
+    let with = field
+        .with
+        .as_ref()
+        .map(Cow::Borrowed)
+        .unwrap_or(Cow::Owned(fallback));
+
+    // Note: This whole call has to be spanned, since setting the span on the `with` alone is not
+    // sufficient to get rustc to point to the `with` path or magic field type in case of an error.
+    quote_spanned! {with.span()=>
+        let #ident = __errors.handle(#with(#input));
+    }
+}
@@ -0,0 +1,334 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+use crate::ast::Data;
+use crate::codegen::{Field, OuterFromImpl, Tagging, TraitImpl, Variant};
+
+/// Generator for a `ToMeta` impl, which is the inverse of the `FromMeta` impl generated from
+/// the same options.
+pub struct ToMetaImpl<'a> {
+    pub base: TraitImpl<'a>,
+    /// How the variant is written when the deriving type is an enum.
+    pub tagging: Tagging<'a>,
+}
+
+impl ToTokens for ToMetaImpl<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let base = &self.base;
+
+        // Transparent proxies to the sole value it contains
+        if let Some((member, _)) = base
+            .data
+            .as_struct()
+            .and_then(|fields| super::extract_transparent(fields, base.transparent))
+        {
+            let impl_block = quote!(
+                fn to_meta(&self, __path: &_darling::export::syn::Path) -> _darling::export::Option<_darling::export::syn::Meta> {
+                    _darling::ToMeta::to_meta(&self.#member, __path)
+                }
+
+                fn to_nested_meta(&self) -> _darling::export::Vec<_darling::export::NestedMeta> {
+                    _darling::ToMeta::to_nested_meta(&self.#member)
+                }
+            );
+
+            self.wrap(impl_block, tokens);
+            return;
+        }
+
+        let impl_block = match base.data {
+            // Unit structs are only ever written as a word.
+            Data::Struct(ref vd) if vd.style.is_unit() => quote!(
+                fn to_meta(
+                    &self,
+                    __path: &_darling::export::syn::Path,
+                ) -> _darling::export::Option<_darling::export::syn::Meta> {
+                    _darling::export::Some(_darling::export::syn::Meta::Path(
+                        _darling::export::Clone::clone(__path),
+                    ))
+                }
+            ),
+            Data::Struct(ref data) => {
+                // Positional fields must be written before any named ones.
+                let (positional, named): (Vec<_>, Vec<_>) = data
+                    .fields
+                    .iter()
+                    .partition(|field| field.position.is_some());
+                let fields = positional.into_iter().chain(named).map(|field| {
+                    let member = field.member;
+                    FieldToMeta {
+                        field,
+                        value: quote!(&self.#member),
+                    }
+                });
+
+                quote!(
+                    fn to_meta(&self, __path: &_darling::export::syn::Path) -> _darling::export::Option<_darling::export::syn::Meta> {
+                        _darling::export::Some(_darling::util::meta_list(__path, &_darling::ToMeta::to_nested_meta(self)))
+                    }
+
+                    fn to_nested_meta(&self) -> _darling::export::Vec<_darling::export::NestedMeta> {
+                        let mut __items = _darling::export::Vec::new();
+                        #(#fields)*
+                        __items
+                    }
+                )
+            }
+            Data::Enum(ref variants) => {
+                let arms = variants.iter().map(|variant| VariantToMeta {
+                    variant,
+                    tagging: self.tagging,
+                });
+
+                quote!(
+                    fn to_meta(&self, __path: &_darling::export::syn::Path) -> _darling::export::Option<_darling::export::syn::Meta> {
+                        match *self {
+                            #(#arms)*
+                        }
+                    }
+                )
+            }
+        };
+
+        self.wrap(impl_block, tokens);
+    }
+}
+
+impl<'a> OuterFromImpl<'a> for ToMetaImpl<'a> {
+    fn trait_path(&self) -> syn::Path {
+        path!(_darling::ToMeta)
+    }
+
+    fn base(&'a self) -> &'a TraitImpl<'a> {
+        &self.base
+    }
+}
+
+/// Pushes the meta items for a single field onto `__items`.
+///
+/// `value` must evaluate to a reference to the field's value.
+struct FieldToMeta<'a> {
+    field: &'a Field<'a>,
+    value: TokenStream,
+}
+
+impl ToTokens for FieldToMeta<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let field = self.field;
+        let value = &self.value;
+
+        if field.skip {
+            return;
+        }
+
+        if field.flatten {
+            tokens.extend(quote! {
+                __items.extend(_darling::ToMeta::to_nested_meta(#value));
+            });
+            return;
+        }
+
+        if field.one_of_keys {
+            let name_in_attr = &field.name_in_attr;
+            tokens.extend(quote! {
+                if let _darling::export::Some(__meta) = _darling::ToMeta::to_meta(#value, &_darling::util::path_from_string(#name_in_attr)) {
+                    __items.extend(_darling::util::variant_items(__meta));
+                }
+            });
+            return;
+        }
+
+        if field.position.is_some() {
+            // The name is dropped from the written item, so any valid path will do.
+            tokens.extend(quote! {
+                if let _darling::export::Some(__meta) = _darling::ToMeta::to_meta(#value, &_darling::util::path_from_string("value")) {
+                    __items.push(_darling::util::positional_item(__meta));
+                }
+            });
+            return;
+        }
+
+        let name_in_attr = &field.name_in_attr;
+        let push = quote! {
+            __items.extend(
+                _darling::ToMeta::to_meta(__value, &__field_path)
+                    .map(_darling::export::NestedMeta::Meta)
+            );
+        };
+
+        tokens.extend(if field.multiple {
+            quote! {
+                let __field_path = _darling::util::path_from_string(#name_in_attr);
+                for __value in #value {
+                    #push
+                }
+            }
+        } else {
+            quote! {
+                let __field_path = _darling::util::path_from_string(#name_in_attr);
+                let __value = #value;
+                #push
+            }
+        });
+    }
+}
+
+/// Match arm producing the meta item for one variant of the enum.
+struct VariantToMeta<'a> {
+    variant: &'a Variant<'a>,
+    tagging: Tagging<'a>,
+}
+
+impl ToTokens for VariantToMeta<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let variant = self.variant;
+        let ty_ident = variant.ty_ident;
+        let variant_ident = variant.variant_ident;
+        let name_in_attr = &variant.name_in_attr;
+
+        // Skipped variants can't be produced by `FromMeta`, so there is nothing to emit.
+        if variant.skip {
+            tokens.extend(quote!(#ty_ident::#variant_ident { .. } => _darling::export::None,));
+            return;
+        }
+
+        let transparent = super::extract_transparent(&variant.data, variant.transparent);
+
+        // The `other` variant has no name of its own. A unit `other` variant has lost the
+        // name it was parsed from, so it is omitted; a newtype writes the name it holds.
+        if variant.other {
+            tokens.extend(match (transparent, self.tagging) {
+                (Some((member, _)), Tagging::Internal(tag)) => quote! {
+                    #ty_ident::#variant_ident { #member: ref __inner } => _darling::export::Some(
+                        _darling::util::meta_list(
+                            __path,
+                            &_darling::ToMeta::to_meta(__inner, &_darling::util::path_from_string(#tag))
+                                .map(_darling::export::NestedMeta::Meta)
+                                .into_iter()
+                                .collect::<_darling::export::Vec<_>>(),
+                        ),
+                    ),
+                },
+                (Some((member, _)), _) => quote! {
+                    #ty_ident::#variant_ident { #member: ref __inner } => _darling::ToMeta::to_meta(__inner, __path),
+                },
+                (None, _) => quote!(#ty_ident::#variant_ident { .. } => _darling::export::None,),
+            });
+            return;
+        }
+
+        // Unit variants are written as a string value, e.g. `volume = "whisper"`, except in
+        // untagged enums where they are written as a word.
+        if variant.data.is_unit() {
+            tokens.extend(if let Tagging::Untagged = self.tagging {
+                quote! {
+                    #ty_ident::#variant_ident => _darling::export::Some(
+                        _darling::export::syn::Meta::Path(_darling::export::Clone::clone(__path)),
+                    ),
+                }
+            } else {
+                quote! {
+                    #ty_ident::#variant_ident => _darling::ToMeta::to_meta(
+                        &_darling::export::String::from(#name_in_attr),
+                        __path,
+                    ),
+                }
+            });
+            return;
+        }
+
+        let (pattern, fields) = if let Some((member, _)) = transparent {
+            (
+                quote!(#ty_ident::#variant_ident { #member: ref __inner }),
+                None,
+            )
+        } else if variant.data.is_struct() {
+            let idents = variant
+                .data
+                .fields
+                .iter()
+                .filter(|f| !f.skip)
+                .map(|f| f.ident);
+            let fields = variant.data.fields.iter().map(|field| {
+                let ident = field.ident;
+                FieldToMeta {
+                    field,
+                    value: quote!(#ident),
+                }
+            });
+
+            (
+                quote!(#ty_ident::#variant_ident { #(ref #idents,)* .. }),
+                Some(quote! {
+                    let mut __items = _darling::export::Vec::new();
+                    #(#fields)*
+                }),
+            )
+        } else {
+            panic!("Match arms aren't supported for tuple variants yet");
+        };
+
+        let body = match self.tagging {
+            Tagging::External => {
+                let nested = if let Some(fields) = fields {
+                    quote! {{
+                        #fields
+                        _darling::util::meta_list(
+                            &_darling::util::path_from_string(#name_in_attr),
+                            &__items,
+                        )
+                    }}
+                } else {
+                    quote! {{
+                        let __variant_path = _darling::util::path_from_string(#name_in_attr);
+                        _darling::ToMeta::to_meta(__inner, &__variant_path)
+                            .unwrap_or(_darling::export::syn::Meta::Path(__variant_path))
+                    }}
+                };
+
+                quote! {
+                    _darling::export::Some(_darling::util::meta_list(
+                        __path,
+                        &[_darling::export::NestedMeta::Meta(#nested)],
+                    ))
+                }
+            }
+            Tagging::Internal(tag) => {
+                let fields = fields.unwrap_or_else(|| {
+                    quote! {
+                        let mut __items = _darling::export::Vec::new();
+                        __items.extend(_darling::ToMeta::to_nested_meta(__inner));
+                    }
+                });
+
+                quote! {{
+                    let mut __tagged = _darling::export::Vec::new();
+                    __tagged.extend(
+                        _darling::ToMeta::to_meta(
+                            &_darling::export::String::from(#name_in_attr),
+                            &_darling::util::path_from_string(#tag),
+                        )
+                        .map(_darling::export::NestedMeta::Meta),
+                    );
+                    #fields
+                    __tagged.extend(__items);
+                    _darling::export::Some(_darling::util::meta_list(__path, &__tagged))
+                }}
+            }
+            Tagging::Untagged => {
+                if let Some(fields) = fields {
+                    quote! {{
+                        #fields
+                        _darling::export::Some(_darling::util::meta_list(__path, &__items))
+                    }}
+                } else {
+                    quote!(_darling::ToMeta::to_meta(__inner, __path))
+                }
+            }
+        };
+
+        tokens.extend(quote! {
+            #pattern => #body,
+        });
+    }
+}
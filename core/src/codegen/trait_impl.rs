@@ -1,13 +1,14 @@
 use proc_macro2::TokenStream;
-use quote::quote;
-use syn::{Generics, Ident};
+use quote::{quote, quote_spanned};
+use syn::{spanned::Spanned, Generics, Ident};
 
 use crate::ast::{Data, Fields};
 use crate::codegen::{
     error::{ErrorCheck, ErrorDeclaration},
-    DefaultExpression, Field, FieldsGen, PostfixTransform, Variant,
+    field, DefaultExpression, Field, FieldsGen, PostfixTransform, Variant,
 };
 use crate::usage::{CollectTypeParams, IdentSet, Purpose};
+use crate::util::Callable;
 
 #[derive(Debug)]
 pub struct TraitImpl<'a> {
@@ -16,9 +17,13 @@ pub struct TraitImpl<'a> {
     pub data: Data<Variant<'a>, Field<'a>>,
     pub default: Option<DefaultExpression<'a>>,
     pub post_transform: Option<&'a PostfixTransform>,
+    /// A function which checks the finished value, pushing any problems to an accumulator.
+    pub validate: Option<&'a Callable>,
     pub allow_unknown_fields: bool,
     pub transparent: bool,
     pub krate: Option<&'a syn::Path>,
+    /// The doc comment of the type, if any.
+    pub doc: Option<&'a str>,
 }
 
 impl<'a> TraitImpl<'a> {
@@ -99,8 +104,30 @@ impl<'a> TraitImpl<'a> {
         }
     }
 
+    /// Generate the calls applied to `darling::Result<Self>` once the value has been built:
+    /// the `map` or `and_then` transform, followed by the `validate` hook.
     pub(in crate::codegen) fn post_transform_call(&self) -> Option<TokenStream> {
-        self.post_transform.map(|pt| quote!(#pt))
+        let post_transform = self.post_transform.map(|pt| quote!(#pt));
+        let validate = self.validate_call();
+        if post_transform.is_none() && validate.is_none() {
+            return None;
+        }
+
+        Some(quote!(#post_transform #validate))
+    }
+
+    /// Generate a call which runs the `validate` hook on `darling::Result<Self>`, returning
+    /// the value only if the hook did not report any errors.
+    pub(in crate::codegen) fn validate_call(&self) -> Option<TokenStream> {
+        self.validate.map(|validate| {
+            quote_spanned!(validate.span()=>
+                .and_then(|__value| {
+                    let mut __errors = _darling::Error::accumulator();
+                    _darling::export::identity::<fn(&Self, &mut _darling::error::Accumulator)>(#validate)(&__value, &mut __errors);
+                    __errors.finish_with(__value)
+                })
+            )
+        })
     }
 
     /// Generate local variable declaration and initialization for instance from which missing fields will be taken.
@@ -114,15 +141,33 @@ impl<'a> TraitImpl<'a> {
             let check_nones = vd.as_ref().map(Field::as_presence_check);
             let checks = check_nones.fields.as_slice();
 
-            // If a field was marked `flatten`, now is the time to process any unclaimed meta items
-            // and mark the field as having been seen.
-            let flatten_field_init = vd.fields.iter().find(|f| f.flatten).map(|v| {
-                v.as_flatten_initializer(vd.fields.iter().filter_map(Field::as_name).collect())
-            });
+            // If fields were marked `flatten`, now is the time to process the meta items they were
+            // given and mark the fields as having been seen.
+            let flatten_field_init =
+                FieldsGen::new(vd, self.allow_unknown_fields).flatten_initializers();
+
+            // Missing fields of a tuple struct are reported together as too few items.
+            let check_positional = if vd.style.is_tuple() {
+                Some(quote! {
+                    if let _darling::export::Some(__required) = __missing_positional {
+                        __errors.push(_darling::Error::too_few_items(__required));
+                    }
+                })
+            } else {
+                None
+            };
+            let declare_positional = check_positional
+                .as_ref()
+                .map(|_| quote!(let mut __missing_positional = _darling::export::None;));
+
+            let relation_checks = field::relation_checks(&vd.fields);
 
             quote! {
                 #flatten_field_init
+                #declare_positional
                 #(#checks)*
+                #check_positional
+                #relation_checks
             }
         } else {
             quote!()
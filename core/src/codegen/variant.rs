@@ -4,10 +4,26 @@ use proc_macro2::TokenStream;
 use quote::{quote, ToTokens, TokenStreamExt};
 use syn::Ident;
 
-use crate::ast::Fields;
+use crate::ast::{Fields, Style};
 use crate::codegen::error::{ErrorCheck, ErrorDeclaration};
+use crate::codegen::field::deprecation_warning;
 use crate::codegen::{from_none_call, Field, FieldsGen};
+use crate::options::Alias;
 use crate::usage::{self, IdentRefSet, IdentSet, UsesTypeParams};
+use crate::util::Override;
+
+/// How the variant of an enum deriving `FromMeta` is selected from the input.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Tagging<'a> {
+    /// The variant is named by the path of the only nested item, e.g. `cache(lru(size = 10))`.
+    #[default]
+    External,
+    /// The variant is named by the value of a key, and the remaining keys are parsed into
+    /// the variant's fields, e.g. `cache(kind = "lru", size = 10)`.
+    Internal(&'a str),
+    /// Each variant is tried in declaration order until one succeeds.
+    Untagged,
+}
 
 /// A variant of the enum which is deriving `FromMeta`.
 #[derive(Debug, Clone)]
@@ -15,6 +31,9 @@ pub struct Variant<'a> {
     /// The name which will appear in code passed to the `FromMeta` input.
     pub name_in_attr: Cow<'a, str>,
 
+    /// Additional names which will be accepted in place of `name_in_attr`.
+    pub aliases: &'a [Alias],
+
     /// The name of the variant which will be returned for a given `name_in_attr`.
     pub variant_ident: &'a Ident,
 
@@ -29,6 +48,16 @@ pub struct Variant<'a> {
     pub allow_unknown_fields: bool,
 
     pub transparent: bool,
+
+    /// If set, matching this variant records a deprecation warning with an optional note.
+    pub deprecated: Option<&'a Override<String>>,
+
+    /// Whether or not this variant is produced for names which don't match any other variant.
+    /// Such a variant can't be selected by its own name.
+    pub other: bool,
+
+    /// The doc comment of the variant, if any.
+    pub doc: Option<&'a str>,
 }
 
 impl<'a> Variant<'a> {
@@ -36,13 +65,80 @@ impl<'a> Variant<'a> {
         &self.name_in_attr
     }
 
-    pub fn as_unit_match_arm(&'a self) -> UnitMatchArm<'a> {
-        UnitMatchArm(self)
+    /// Get the names that should be offered as alternates in "did you mean" errors: the
+    /// canonical name and any aliases which are not hidden.
+    ///
+    /// This will be empty for the `other` variant, which can't be selected by name.
+    pub fn as_alts(&'a self) -> impl Iterator<Item = &'a str> {
+        let (name, aliases) = if self.other {
+            (None, &[][..])
+        } else {
+            (Some(self.as_name()), self.aliases)
+        };
+
+        name.into_iter().chain(
+            aliases
+                .iter()
+                .filter(|alias| !alias.hidden)
+                .map(|alias| alias.name.as_str()),
+        )
+    }
+
+    /// Generate the expression which produces this variant from the unrecognized name `__other`.
+    ///
+    /// If `lit` is provided, it must evaluate to a `&syn::Lit` holding the name, which is used
+    /// to parse the inner value of a newtype variant so that its span is preserved. Otherwise
+    /// the inner value is parsed with `FromMeta::from_string`.
+    pub fn as_other_body(&self, lit: Option<TokenStream>) -> TokenStream {
+        let ty_ident = self.ty_ident;
+        let variant_ident = self.variant_ident;
+
+        if let Some((member, _)) = super::extract_transparent(&self.data, self.transparent) {
+            let parse = match lit {
+                Some(lit) => quote!(_darling::FromMeta::from_value(#lit)),
+                None => quote!(_darling::FromMeta::from_string(__other)),
+            };
+
+            quote! {
+                #parse.map(|__value| #ty_ident::#variant_ident { #member: __value })
+            }
+        } else {
+            quote!(_darling::export::Ok(#ty_ident::#variant_ident))
+        }
+    }
+
+    /// Get the pattern that matches the canonical name or any alias of this variant.
+    pub fn as_name_pattern(&self) -> TokenStream {
+        let name_in_attr = &self.name_in_attr;
+        let aliases = self.aliases.iter().map(|alias| &alias.name);
+        quote!(#name_in_attr #(| #aliases)*)
+    }
+
+    /// Get the match arm for this variant's name. If `node` is provided, it must evaluate to
+    /// the value being matched, and deprecation warnings are spanned to it.
+    pub fn as_unit_match_arm(&'a self, node: Option<TokenStream>) -> UnitMatchArm<'a> {
+        UnitMatchArm(self, node)
     }
 
     pub fn as_data_match_arm(&'a self) -> DataMatchArm<'a> {
         DataMatchArm(self)
     }
+
+    pub fn as_tagged_match_arm(&'a self) -> TaggedMatchArm<'a> {
+        TaggedMatchArm(self)
+    }
+
+    pub fn as_untagged_attempt(
+        &'a self,
+        method: UntaggedMethod,
+        validate: Option<&'a TokenStream>,
+    ) -> UntaggedAttempt<'a> {
+        UntaggedAttempt {
+            variant: self,
+            method,
+            validate,
+        }
+    }
 }
 
 impl UsesTypeParams for Variant<'_> {
@@ -58,17 +154,21 @@ impl UsesTypeParams for Variant<'_> {
 /// Code generator for an enum variant in a unit match position.
 /// This is placed in generated `from_string` calls for the parent enum.
 /// Value-carrying variants wrapped in this type will emit code to produce an "unsupported format" error.
-pub struct UnitMatchArm<'a>(&'a Variant<'a>);
+pub struct UnitMatchArm<'a>(&'a Variant<'a>, Option<TokenStream>);
 
 impl ToTokens for UnitMatchArm<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let val: &Variant<'_> = self.0;
 
-        if val.skip {
+        if val.skip || val.other {
             return;
         }
 
-        let name_in_attr = &val.name_in_attr;
+        let pattern = val.as_name_pattern();
+        // Without a node, as in `from_string`, the warning falls back to the call site.
+        let warn_deprecated = val
+            .deprecated
+            .map(|note| deprecation_warning(&val.name_in_attr, note, self.1.clone()));
 
         let unsupported_format_error = || {
             quote!(_darling::export::Err(_darling::Error::unsupported_format(
@@ -81,7 +181,10 @@ impl ToTokens for UnitMatchArm<'_> {
             let ty_ident = val.ty_ident;
 
             tokens.append_all(quote!(
-                #name_in_attr => _darling::export::Ok(#ty_ident::#variant_ident),
+                #pattern => {
+                    #warn_deprecated
+                    _darling::export::Ok(#ty_ident::#variant_ident)
+                }
             ));
         } else if let Some((member, field)) = super::extract_transparent(&val.data, val.transparent)
         {
@@ -92,7 +195,8 @@ impl ToTokens for UnitMatchArm<'_> {
             let from_none = from_none_call(field_ty);
 
             tokens.append_all(quote!{
-                #name_in_attr => {
+                #pattern => {
+                    #warn_deprecated
                     match #from_none {
                         _darling::export::Some(__value) => _darling::export::Ok(#ty_ident::#variant_ident { #member: __value }),
                         _darling::export::None => #unsupported_format,
@@ -102,7 +206,7 @@ impl ToTokens for UnitMatchArm<'_> {
         } else {
             let unsupported_format = unsupported_format_error();
             tokens.append_all(quote!(
-                #name_in_attr => #unsupported_format,
+                #pattern => #unsupported_format,
             ));
         }
     }
@@ -117,11 +221,15 @@ impl ToTokens for DataMatchArm<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let val: &Variant<'_> = self.0;
 
-        if val.skip {
+        if val.skip || val.other {
             return;
         }
 
         let name_in_attr = &val.name_in_attr;
+        let pattern = val.as_name_pattern();
+        let warn_deprecated = val
+            .deprecated
+            .map(|note| deprecation_warning(name_in_attr, note, Some(quote!(__nested.path()))));
         let variant_ident = val.variant_ident;
         let ty_ident = val.ty_ident;
 
@@ -129,7 +237,8 @@ impl ToTokens for DataMatchArm<'_> {
             // Allow unit variants to match a list item if it's just a path with no associated
             // value, e.g. `volume(shout)` is allowed.
             tokens.append_all(quote!(
-                #name_in_attr => {
+                #pattern => {
+                    #warn_deprecated
                     if let _darling::export::syn::Meta::Path(_) = *__nested {
                         _darling::export::Ok(#ty_ident::#variant_ident)
                     } else {
@@ -145,7 +254,8 @@ impl ToTokens for DataMatchArm<'_> {
 
         if let Some((member, _)) = super::extract_transparent(&val.data, val.transparent) {
             tokens.append_all(quote!(
-                #name_in_attr => {
+                #pattern => {
+                    #warn_deprecated
                     _darling::export::Ok(
                         #ty_ident::#variant_ident {
                             #member: _darling::FromMeta::from_meta(__nested)
@@ -155,32 +265,16 @@ impl ToTokens for DataMatchArm<'_> {
                 }
             ));
         } else if val.data.is_struct() {
-            let declare_errors = ErrorDeclaration::default();
-            let check_errors = ErrorCheck::with_location(name_in_attr);
-            let require_fields = vdg.require_fields();
-            let decls = vdg.declarations();
-            let core_loop = vdg.core_loop();
-            let inits = vdg.initializers();
+            let body = struct_body(val, &vdg, ErrorCheck::with_location(name_in_attr));
 
             tokens.append_all(quote!(
-                #name_in_attr => {
+                #pattern => {
+                    #warn_deprecated
                     if let _darling::export::syn::Meta::List(ref __data) = *__nested {
                         let __items = _darling::export::NestedMeta::parse_meta_list(__data.tokens.clone())?;
                         let __items = &__items;
 
-                        #declare_errors
-
-                        #decls
-
-                        #core_loop
-
-                        #require_fields
-
-                        #check_errors
-
-                        _darling::export::Ok(#ty_ident::#variant_ident {
-                            #inits
-                        })
+                        #body
                     } else {
                         _darling::export::Err(_darling::Error::unsupported_format("non-list"))
                     }
@@ -191,3 +285,185 @@ impl ToTokens for DataMatchArm<'_> {
         }
     }
 }
+
+/// Generate the code which parses the fields of a struct variant from `__items` and returns
+/// the variant.
+fn struct_body(
+    val: &Variant<'_>,
+    vdg: &FieldsGen<'_>,
+    check_errors: ErrorCheck<'_>,
+) -> TokenStream {
+    let ty_ident = val.ty_ident;
+    let variant_ident = val.variant_ident;
+    let declare_errors = ErrorDeclaration::default();
+    let require_fields = vdg.require_fields();
+    let decls = vdg.declarations();
+    let core_loop = vdg.core_loop();
+    let inits = vdg.initializers();
+
+    quote! {
+        #declare_errors
+
+        #decls
+
+        #core_loop
+
+        #require_fields
+
+        #check_errors
+
+        _darling::export::Ok(#ty_ident::#variant_ident {
+            #inits
+        })
+    }
+}
+
+/// Code generator for an enum variant in an internally-tagged `from_list` call.
+///
+/// The generated arm matches the value of the tag, and parses `__items` - the items other
+/// than the tag - into the variant.
+pub struct TaggedMatchArm<'a>(&'a Variant<'a>);
+
+impl ToTokens for TaggedMatchArm<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let val: &Variant<'_> = self.0;
+
+        if val.skip || val.other {
+            return;
+        }
+
+        let pattern = val.as_name_pattern();
+        let warn_deprecated = val
+            .deprecated
+            .map(|note| deprecation_warning(&val.name_in_attr, note, Some(quote!(__tag_meta))));
+        let variant_ident = val.variant_ident;
+        let ty_ident = val.ty_ident;
+
+        let body = if let Some((member, _)) = super::extract_transparent(&val.data, val.transparent)
+        {
+            quote! {
+                _darling::FromMeta::from_list(__items)
+                    .map(|__value| #ty_ident::#variant_ident { #member: __value })
+            }
+        } else if val.data.is_unit() {
+            // Reuse the struct codegen with no fields, so that any keys besides the tag are
+            // reported as unknown fields.
+            let no_fields = Fields::new(Style::Struct, vec![]);
+            let vdg = FieldsGen::new(&no_fields, val.allow_unknown_fields);
+            let core_loop = vdg.core_loop();
+            let declare_errors = ErrorDeclaration::default();
+
+            quote! {
+                #declare_errors
+                #core_loop
+                __errors.finish_with(#ty_ident::#variant_ident)
+            }
+        } else if val.data.is_struct() {
+            let vdg = FieldsGen::new(&val.data, val.allow_unknown_fields);
+            struct_body(val, &vdg, ErrorCheck::default())
+        } else {
+            panic!("Match arms aren't supported for tuple variants yet");
+        };
+
+        tokens.append_all(quote!(
+            #pattern => {
+                #warn_deprecated
+                #body
+            }
+        ));
+    }
+}
+
+/// The `FromMeta` methods generated for an untagged enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UntaggedMethod {
+    /// `from_word()`, which can produce unit variants and newtype variants.
+    Word,
+    /// `from_list(__items)`, which can produce struct variants and newtype variants.
+    List,
+    /// `from_expr(__expr)`, which can only produce newtype variants.
+    Expr,
+}
+
+/// Code generator for one attempt to parse an untagged enum as a specific variant.
+///
+/// The generated code returns from the enclosing method if the attempt succeeds, and
+/// otherwise pushes its error to `__attempts`. Nothing is generated if the variant can't be
+/// produced by the method.
+pub struct UntaggedAttempt<'a> {
+    variant: &'a Variant<'a>,
+    method: UntaggedMethod,
+    /// The call which validates the value built by a successful attempt.
+    validate: Option<&'a TokenStream>,
+}
+
+impl UntaggedAttempt<'_> {
+    /// Whether or not the variant can be produced by the method.
+    pub fn is_possible(&self) -> bool {
+        let val = self.variant;
+        if val.skip {
+            return false;
+        }
+
+        let transparent = super::extract_transparent(&val.data, val.transparent).is_some();
+        match self.method {
+            UntaggedMethod::Word => transparent || val.data.is_unit(),
+            UntaggedMethod::List => transparent || val.data.is_struct(),
+            UntaggedMethod::Expr => transparent,
+        }
+    }
+}
+
+impl ToTokens for UntaggedAttempt<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if !self.is_possible() {
+            return;
+        }
+
+        let val = self.variant;
+        let validate = self.validate;
+        let name_in_attr = &val.name_in_attr;
+        let ty_ident = val.ty_ident;
+        let variant_ident = val.variant_ident;
+        let warn_deprecated = val
+            .deprecated
+            .map(|note| deprecation_warning(name_in_attr, note, None));
+
+        let body = if let Some((member, _)) = super::extract_transparent(&val.data, val.transparent)
+        {
+            let call = match self.method {
+                UntaggedMethod::Word => quote!(from_word()),
+                UntaggedMethod::List => quote!(from_list(__items)),
+                UntaggedMethod::Expr => quote!(from_expr(__expr)),
+            };
+
+            quote! {
+                _darling::FromMeta::#call
+                    .map(|__value| #ty_ident::#variant_ident { #member: __value })
+            }
+        } else if val.data.is_unit() {
+            quote!(_darling::export::Ok(#ty_ident::#variant_ident))
+        } else {
+            let vdg = FieldsGen::new(&val.data, val.allow_unknown_fields);
+            struct_body(val, &vdg, ErrorCheck::default())
+        };
+
+        // Warnings from a failed attempt are discarded, since they describe input that
+        // wasn't used.
+        tokens.append_all(quote! {
+            let (__result, __warnings) = _darling::Warnings::capture(|| -> _darling::Result<Self> {
+                #body
+            });
+            match __result {
+                _darling::export::Ok(__value) => {
+                    for __warning in __warnings {
+                        _darling::warning::push(__warning);
+                    }
+                    #warn_deprecated
+                    return _darling::export::Ok(__value) #validate;
+                }
+                _darling::export::Err(__err) => __attempts.push(__err.at(#name_in_attr)),
+            }
+        });
+    }
+}
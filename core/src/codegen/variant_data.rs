@@ -1,8 +1,8 @@
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 
 use crate::ast::{Fields, Style};
-use crate::codegen::Field;
+use crate::codegen::{field, Field};
 
 pub struct FieldsGen<'a> {
     fields: &'a Fields<Field<'a>>,
@@ -34,12 +34,17 @@ impl<'a> FieldsGen<'a> {
 
     /// Generate the loop which walks meta items looking for property matches.
     pub(in crate::codegen) fn core_loop(&self) -> TokenStream {
+        if self.fields.style.is_tuple() {
+            return self.positional_loop();
+        }
+
         let arms = self.fields.as_ref().map(Field::as_match);
-        // If there is a flatten field, buffer the unknown field so it can be passed
+        // If there is a catch-all flatten field, buffer the unknown field so it can be passed
         // to the flatten function with all other unknown fields.
-        let handle_unknown = if self.fields.iter().any(|f| f.flatten) {
+        let handle_unknown = if let Some(catch_all) = self.fields.iter().find(|f| f.catch_all) {
+            let flatten_var = catch_all.flatten_var();
             quote! {
-                __flatten.push(__item.clone());
+                #flatten_var.push(_darling::export::Clone::clone(__item));
             }
         }
         // If we're allowing unknown fields, then handling one is a no-op.
@@ -48,33 +53,100 @@ impl<'a> FieldsGen<'a> {
         }
         // Otherwise, we're going to push a new spanned error pointing at the field.
         else {
-            let mut names = self.fields.iter().filter_map(Field::as_name).peekable();
+            let mut names = self.fields.iter().flat_map(Field::as_alts).peekable();
+            let claimed_keys: Vec<_> = self
+                .fields
+                .iter()
+                .filter(|f| f.one_of_keys || f.is_routed())
+                .map(Field::one_of_keys_var)
+                .collect();
             // We can't call `unknown_field_with_alts` with an empty slice, or else it fails to
             // infer the type of the slice item.
-            let err_fn = if names.peek().is_none() {
-                quote!(unknown_field(__other))
+            if names.peek().is_none() && claimed_keys.is_empty() {
+                quote! {
+                    __errors.push(_darling::Error::unknown_field(__other).with_span(__item));
+                }
             } else {
-                quote!(unknown_field_with_alts(__other, &[#(#names),*]))
-            };
-
-            quote! {
-                __errors.push(_darling::Error::#err_fn.with_span(__item));
+                // The keys claimed by the types of fields are only known at runtime.
+                quote! {
+                    let __alts: _darling::export::Vec<&str> = [#(#names),*]
+                        .iter()
+                        .copied()
+                        #(.chain(#claimed_keys.iter().copied()))*
+                        .collect();
+                    __errors.push(_darling::Error::unknown_field_with_alts(__other, &__alts).with_span(__item));
+                }
             }
         };
+        let handle_unknown = self.route_flattened(handle_unknown);
+        // Items which are not claimed by name may belong to a `one_of_keys` field.
+        let one_of_keys_matches = self
+            .fields
+            .iter()
+            .filter(|f| f.one_of_keys)
+            .map(Field::as_one_of_keys_match);
+        let handle_unknown = quote! {
+            #(if #one_of_keys_matches else)* { #handle_unknown }
+        };
         let arms = arms.iter();
 
+        let positional_arms: Vec<_> = self
+            .fields
+            .iter()
+            .filter(|f| f.position.is_some())
+            .map(Field::as_positional_match)
+            .collect();
+
+        // Without positional fields, a literal is never valid. Otherwise, literals are assigned
+        // to the positional fields in order, as long as no named item has been seen yet.
+        let (positional_decl, handle_lit, mark_named) = if positional_arms.is_empty() {
+            (
+                quote!(),
+                quote! {
+                    __errors.push(_darling::Error::unsupported_format("literal")
+                        .with_span(__inner));
+                    continue;
+                },
+                quote!(),
+            )
+        } else {
+            let count = positional_arms.len();
+            (
+                quote! {
+                    let mut __positional_index: usize = 0;
+                    let mut __named_seen = false;
+                },
+                quote! {
+                    if __named_seen {
+                        __errors.push(_darling::Error::custom("Positional arguments must come before named arguments")
+                            .with_span(__inner));
+                    } else {
+                        match __positional_index {
+                            #(#positional_arms)*
+                            _ => {
+                                __errors.push(_darling::Error::too_many_items(#count).with_span(__inner));
+                            }
+                        }
+                        __positional_index += 1;
+                    }
+                    continue;
+                },
+                quote!(__named_seen = true;),
+            )
+        };
+
         quote!(
+            #positional_decl
             for __item in __items {
                 let __path = match *__item {
                     _darling::export::NestedMeta::Meta(ref __inner) => __inner.path(),
                     _darling::export::NestedMeta::NameValueInvalidExpr(ref __inner) => &__inner.path,
                     _darling::export::NestedMeta::Lit(ref __inner) => {
-                        __errors.push(_darling::Error::unsupported_format("literal")
-                            .with_span(__inner));
-                        continue;
+                        #handle_lit
                     }
                 };
                 let __name = _darling::util::path_to_string(__path);
+                #mark_named
 
                 match __name.as_str() {
                     #(#arms)*
@@ -84,6 +156,63 @@ impl<'a> FieldsGen<'a> {
         )
     }
 
+    /// Wrap the handling of an unclaimed item so that items whose names are claimed by the type
+    /// of a flattened field are given to that field instead. A name which is claimed by more
+    /// than one field is an error, since the item can only be given to one of them.
+    fn route_flattened(&self, unclaimed: TokenStream) -> TokenStream {
+        let routed: Vec<_> = self.fields.iter().filter(|f| f.is_routed()).collect();
+        if routed.is_empty() {
+            return unclaimed;
+        }
+
+        let claims: Vec<_> = routed
+            .iter()
+            .map(|f| format_ident!("__claimed_by_{}", f.ident))
+            .collect();
+        let keys = routed.iter().map(|f| f.one_of_keys_var());
+        let names = routed.iter().map(|f| f.ident.to_string());
+        let flatten_vars = routed.iter().map(|f| f.flatten_var());
+
+        quote! {
+            #(let #claims = #keys.iter().any(|__key| *__key == __other);)*
+            let __claimants: _darling::export::Vec<&str> = [#((#claims, #names)),*]
+                .iter()
+                .filter(|(__claimed, _)| *__claimed)
+                .map(|(_, __name)| *__name)
+                .collect();
+            if __claimants.len() > 1 {
+                __errors.push(_darling::Error::custom(format!(
+                    "`{}` is claimed by more than one flattened field: {}",
+                    __other,
+                    __claimants.iter().map(|__name| format!("`{}`", __name)).collect::<_darling::export::Vec<_>>().join(", "),
+                )).with_span(__item));
+            }
+            #(else if #claims {
+                #flatten_vars.push(_darling::export::Clone::clone(__item));
+            })*
+            else {
+                #unclaimed
+            }
+        }
+    }
+
+    /// Generate the loop which assigns meta items to the fields of a tuple struct by position.
+    fn positional_loop(&self) -> TokenStream {
+        let arms = self.fields.iter().map(Field::as_positional_match);
+        let count = self.fields.len();
+
+        quote!(
+            for (__index, __item) in _darling::export::IntoIterator::into_iter(__items).enumerate() {
+                match __index {
+                    #(#arms)*
+                    _ => {
+                        __errors.push(_darling::Error::too_many_items(#count).with_span(__item));
+                    }
+                }
+            }
+        )
+    }
+
     pub fn require_fields(&self) -> TokenStream {
         match *self.fields {
             Fields {
@@ -91,13 +220,28 @@ impl<'a> FieldsGen<'a> {
                 ref fields,
                 ..
             } => {
+                let flatten_inits = self.flatten_initializers();
                 let checks = fields.iter().map(Field::as_presence_check);
-                quote!(#(#checks)*)
+                let relation_checks = field::relation_checks(fields);
+                quote!(#flatten_inits #(#checks)* #relation_checks)
             }
             _ => panic!("FieldsGen doesn't support tuples for requirement checks"),
         }
     }
 
+    /// Generate the code which parses the meta items given to each flattened field, and marks
+    /// the field as having been seen.
+    pub(in crate::codegen) fn flatten_initializers(&self) -> TokenStream {
+        let parent_field_names: Vec<_> = self.fields.iter().flat_map(Field::as_alts).collect();
+        let inits = self
+            .fields
+            .iter()
+            .filter(|f| f.flatten)
+            .map(|f| f.as_flatten_initializer(parent_field_names.clone()));
+
+        quote!(#(#inits)*)
+    }
+
     pub(in crate::codegen) fn initializers(&self) -> TokenStream {
         let inits = self.fields.as_ref().map(Field::as_initializer);
         let inits = inits.iter();
@@ -5,6 +5,7 @@ use proc_macro2::TokenStream;
 use quote::ToTokens;
 use syn::DeriveInput;
 
+use crate::codegen::FnKind;
 use crate::options;
 
 /// Run an expression which returns a `darling::Result`, then either return the tokenized
@@ -46,6 +47,55 @@ pub fn from_field(input: &DeriveInput) -> TokenStream {
     emit_impl_or_error!(options::FromFieldOptions::new(input))
 }
 
+/// Create tokens for a `darling::FromFn` impl from a `DeriveInput`. If
+/// the input cannot produce a valid impl, the returned tokens will contain
+/// compile errors instead.
+pub fn from_fn(input: &DeriveInput) -> TokenStream {
+    emit_impl_or_error!(options::FromFnOptions::new(input, FnKind::Item))
+}
+
+/// Create tokens for a `darling::FromFnArg` impl from a `DeriveInput`. If
+/// the input cannot produce a valid impl, the returned tokens will contain
+/// compile errors instead.
+pub fn from_fn_arg(input: &DeriveInput) -> TokenStream {
+    emit_impl_or_error!(options::FromFnArgOptions::new(input))
+}
+
+/// Create tokens for a `darling::FromSignature` impl from a `DeriveInput`. If
+/// the input cannot produce a valid impl, the returned tokens will contain
+/// compile errors instead.
+pub fn from_signature(input: &DeriveInput) -> TokenStream {
+    emit_impl_or_error!(options::FromSignatureOptions::new(input))
+}
+
+/// Create tokens for a `darling::FromImplBlock` impl from a `DeriveInput`. If
+/// the input cannot produce a valid impl, the returned tokens will contain
+/// compile errors instead.
+pub fn from_impl_block(input: &DeriveInput) -> TokenStream {
+    emit_impl_or_error!(options::FromImplBlockOptions::new(input))
+}
+
+/// Create tokens for a `darling::FromImplItemFn` impl from a `DeriveInput`. If
+/// the input cannot produce a valid impl, the returned tokens will contain
+/// compile errors instead.
+pub fn from_impl_item_fn(input: &DeriveInput) -> TokenStream {
+    emit_impl_or_error!(options::FromFnOptions::new(input, FnKind::ImplItem))
+}
+
+/// Create tokens for a `darling::FromTraitDef` impl from a `DeriveInput`. If
+/// the input cannot produce a valid impl, the returned tokens will contain
+/// compile errors instead.
+pub fn from_trait_def(input: &DeriveInput) -> TokenStream {
+    emit_impl_or_error!(options::FromTraitDefOptions::new(input))
+}
+
+/// Create tokens for a `darling::FromTraitItemFn` impl from a `DeriveInput`. If
+/// the input cannot produce a valid impl, the returned tokens will contain
+/// compile errors instead.
+pub fn from_trait_item_fn(input: &DeriveInput) -> TokenStream {
+    emit_impl_or_error!(options::FromFnOptions::new(input, FnKind::TraitItem))
+}
+
 /// Create tokens for a `darling::FromTypeParam` impl from a `DeriveInput`. If
 /// the input cannot produce a valid impl, the returned tokens will contain
 /// compile errors instead.
@@ -53,6 +103,34 @@ pub fn from_type_param(input: &DeriveInput) -> TokenStream {
     emit_impl_or_error!(options::FromTypeParamOptions::new(input))
 }
 
+/// Create tokens for a `darling::FromLifetimeParam` impl from a `DeriveInput`. If
+/// the input cannot produce a valid impl, the returned tokens will contain
+/// compile errors instead.
+pub fn from_lifetime_param(input: &DeriveInput) -> TokenStream {
+    emit_impl_or_error!(options::FromLifetimeParamOptions::new(input))
+}
+
+/// Create tokens for a `darling::FromConstParam` impl from a `DeriveInput`. If
+/// the input cannot produce a valid impl, the returned tokens will contain
+/// compile errors instead.
+pub fn from_const_param(input: &DeriveInput) -> TokenStream {
+    emit_impl_or_error!(options::FromConstParamOptions::new(input))
+}
+
+/// Create tokens for a `darling::FromGenericParam` impl from a `DeriveInput`. If
+/// the input cannot produce a valid impl, the returned tokens will contain
+/// compile errors instead.
+pub fn from_generic_param(input: &DeriveInput) -> TokenStream {
+    emit_impl_or_error!(options::FromGenericParamOptions::new(input))
+}
+
+/// Create tokens for a `darling::ToMeta` impl from a `DeriveInput`. This reads the same
+/// `#[darling(...)]` options as [`from_meta`], so that the two impls are inverses of each other.
+/// If the input cannot produce a valid impl, the returned tokens will contain compile errors instead.
+pub fn to_meta(input: &DeriveInput) -> TokenStream {
+    emit_impl_or_error!(options::ToMetaOptions::new(input))
+}
+
 /// Create tokens for a `darling::FromVariant` impl from a `DeriveInput`. If
 /// the input cannot produce a valid impl, the returned tokens will contain
 /// compile errors instead.
@@ -0,0 +1,369 @@
+//! Markdown reference documentation for the attribute syntax described by a [`Schema`].
+//!
+//! The output has a section for the described type and one for each nested type with a schema,
+//! each with a table of the accepted keys, their types, defaults and doc comments. Fields marked
+//! `#[darling(flatten)]` contribute their keys to the parent's table.
+//!
+//! Proc-macro crates can't call their own receivers from a build script, so the usual way to keep
+//! the output in the crate is a test which writes it to a file, for the rustdoc to `include_str!`.
+//!
+//! # Example
+//! ```rust,ignore
+//! #[test]
+//! fn attribute_docs() {
+//!     let docs = darling::docs::Markdown::new(&MyReceiver::schema())
+//!         .heading_level(3)
+//!         .to_string();
+//!     std::fs::write(concat!(env!("CARGO_MANIFEST_DIR"), "/docs/attributes.md"), docs).unwrap();
+//! }
+//! ```
+
+use std::fmt::{self, Display, Write};
+
+use crate::schema::{
+    Deprecation, Enum, Field, FieldDefault, Fields, Newtype, Schema, SchemaKind, Tagging,
+    VariantKind,
+};
+
+/// Render `schema` as Markdown, starting with a second-level heading.
+pub fn markdown(schema: &Schema) -> String {
+    Markdown::new(schema).to_string()
+}
+
+/// Markdown reference documentation for a [`Schema`], rendered with [`Display`].
+#[derive(Debug, Clone)]
+pub struct Markdown<'a> {
+    schema: &'a Schema,
+    heading_level: usize,
+}
+
+impl<'a> Markdown<'a> {
+    /// Create documentation for `schema` and the nested types it describes.
+    pub fn new(schema: &'a Schema) -> Self {
+        Markdown {
+            schema,
+            heading_level: 2,
+        }
+    }
+
+    /// Set the level of the heading for each type, such as `3` for `###`. Struct-like variants
+    /// get a heading one level lower. The default is `2`.
+    pub fn heading_level(mut self, level: usize) -> Self {
+        self.heading_level = level.max(1);
+        self
+    }
+
+    /// Get the described type followed by each nested type with a schema, in the order they are
+    /// first reached. Each type appears once.
+    fn sections(&self) -> Vec<&'a Schema> {
+        let mut sections = vec![self.schema];
+        let mut next = 0;
+        while next < sections.len() {
+            let mut nested = Vec::new();
+            collect_nested(sections[next], &mut nested);
+            // A recursive schema refers to a type which already has a section.
+            for schema in nested {
+                if schema.kind != SchemaKind::Recursive
+                    && !sections.iter().any(|seen| seen.ty == schema.ty)
+                {
+                    sections.push(schema);
+                }
+            }
+            next += 1;
+        }
+
+        sections
+    }
+
+    fn write_section(&self, f: &mut fmt::Formatter<'_>, schema: &Schema) -> fmt::Result {
+        writeln!(f, "{} `{}`", "#".repeat(self.heading_level), schema.ty)?;
+        if let Some(doc) = schema.doc {
+            write!(f, "\n{}\n", doc)?;
+        }
+
+        if !schema.attributes.is_empty() {
+            let attributes = schema
+                .attributes
+                .iter()
+                .map(|name| format!("`#[{}(...)]`", name))
+                .collect::<Vec<_>>();
+            write!(f, "\nRead from {}.\n", attributes.join(" or "))?;
+        }
+
+        match schema.kind {
+            SchemaKind::Unit => f.write_str("\nWritten as a bare word.\n"),
+            SchemaKind::Newtype(ref newtype) => {
+                write!(f, "\nRead the same way as `{}`.\n", newtype.ty)
+            }
+            SchemaKind::Struct(ref fields) => write_fields(f, fields),
+            SchemaKind::Enum(ref body) => self.write_variants(f, body),
+            SchemaKind::Recursive => Ok(()),
+        }
+    }
+
+    fn write_variants(&self, f: &mut fmt::Formatter<'_>, body: &Enum) -> fmt::Result {
+        match body.tagging {
+            Tagging::External => f.write_str("\nWritten as one of the following variants.\n")?,
+            Tagging::Internal(tag) => write!(
+                f,
+                "\nThe variant is named by `{} = \"...\"`, and its keys are given alongside.\n",
+                tag
+            )?,
+            Tagging::Untagged => {
+                f.write_str("\nEach of the following variants is tried in order.\n")?
+            }
+        }
+
+        let variants: Vec<_> = body.variants.iter().filter(|v| !v.skip).collect();
+        f.write_str("\n| Variant | Value | Description |\n|---|---|---|\n")?;
+        for variant in &variants {
+            let name = if variant.other {
+                "*any other name*".to_string()
+            } else {
+                with_aliases(variant.name, &variant.aliases)
+            };
+            let value = match variant.kind {
+                VariantKind::Unit => String::new(),
+                VariantKind::Newtype(ref newtype) => {
+                    type_cell(newtype.ty, newtype.nested.as_deref())
+                }
+                VariantKind::Struct(_) => "keys below".to_string(),
+            };
+            let description = description(variant.doc, variant.deprecated.as_ref());
+            write_row(f, &[name, value, description])?;
+        }
+
+        for variant in variants {
+            if let VariantKind::Struct(ref fields) = variant.kind {
+                write!(
+                    f,
+                    "\n{} `{}`\n",
+                    "#".repeat(self.heading_level + 1),
+                    variant.name
+                )?;
+                write_fields(f, fields)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Markdown<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, schema) in self.sections().into_iter().enumerate() {
+            if index > 0 {
+                f.write_char('\n')?;
+            }
+
+            self.write_section(f, schema)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Collect the schemas of the types used by `schema` which get their own section.
+/// Flattened struct fields don't, but the types they use do.
+fn collect_nested<'a>(schema: &'a Schema, out: &mut Vec<&'a Schema>) {
+    fn collect_fields<'a>(fields: &'a Fields, out: &mut Vec<&'a Schema>) {
+        for field in fields.fields.iter().filter(|f| !f.skip) {
+            match field.nested.as_deref() {
+                Some(Schema {
+                    kind: SchemaKind::Struct(ref inner),
+                    ..
+                }) if field.flatten => collect_fields(inner, out),
+                // Enums whose variants are all words are listed in the type column instead.
+                Some(nested) if unit_variants(nested).is_none() => out.push(nested),
+                _ => {}
+            }
+        }
+    }
+
+    fn collect_newtype<'a>(newtype: &'a Newtype, out: &mut Vec<&'a Schema>) {
+        if let Some(nested) = newtype.nested.as_deref() {
+            out.push(nested);
+        }
+    }
+
+    match schema.kind {
+        SchemaKind::Unit | SchemaKind::Recursive => {}
+        SchemaKind::Newtype(ref newtype) => collect_newtype(newtype, out),
+        SchemaKind::Struct(ref fields) => collect_fields(fields, out),
+        SchemaKind::Enum(ref body) => {
+            for variant in body.variants.iter().filter(|v| !v.skip) {
+                match variant.kind {
+                    VariantKind::Unit => {}
+                    VariantKind::Newtype(ref newtype) => collect_newtype(newtype, out),
+                    VariantKind::Struct(ref fields) => collect_fields(fields, out),
+                }
+            }
+        }
+    }
+}
+
+fn write_fields(f: &mut fmt::Formatter<'_>, fields: &Fields) -> fmt::Result {
+    let mut rows = Vec::new();
+    field_rows(fields, &mut rows);
+
+    if rows.is_empty() {
+        f.write_str("\nNo keys are read.\n")?;
+    } else {
+        f.write_str("\n| Key | Type | Default | Description |\n|---|---|---|---|\n")?;
+        for row in rows {
+            write_row(f, &row)?;
+        }
+    }
+
+    if fields.allow_unknown_fields {
+        f.write_str("\nUnknown keys are ignored.\n")?;
+    }
+
+    Ok(())
+}
+
+/// Write one table row, escaping the pipes in each cell so they don't end it early.
+fn write_row(f: &mut fmt::Formatter<'_>, cells: &[String]) -> fmt::Result {
+    f.write_char('|')?;
+    for cell in cells {
+        write!(f, " {} |", cell.replace('|', "\\|"))?;
+    }
+    f.write_char('\n')
+}
+
+/// Build the table rows for `fields`, inlining the keys of flattened struct fields.
+fn field_rows(fields: &Fields, rows: &mut Vec<[String; 4]>) {
+    for field in fields.fields.iter().filter(|f| !f.skip) {
+        if field.flatten {
+            match field.nested.as_deref() {
+                Some(Schema {
+                    kind: SchemaKind::Struct(ref inner),
+                    ..
+                }) => field_rows(inner, rows),
+                _ => rows.push([
+                    "*other keys*".to_string(),
+                    format!("`{}`", field.ty),
+                    String::new(),
+                    description(field.doc, field.deprecated.as_ref()),
+                ]),
+            }
+
+            continue;
+        }
+
+        rows.push([
+            key_cell(field),
+            type_cell(field.ty, field.nested.as_deref()),
+            default_cell(field),
+            description(field.doc, field.deprecated.as_ref()),
+        ]);
+    }
+}
+
+fn key_cell(field: &Field) -> String {
+    let mut key = if field.one_of_keys {
+        match field.nested.as_deref().and_then(Schema::variants) {
+            Some(variants) => {
+                let names = variants
+                    .iter()
+                    .filter(|v| !v.skip && !v.other)
+                    .map(|v| format!("`{}`", v.name))
+                    .collect::<Vec<_>>();
+                format!("one of {}", names.join(", "))
+            }
+            None => format!("*a key of `{}`*", field.ty),
+        }
+    } else if field.name.parse::<usize>().is_ok() {
+        format!("position {}", field.name)
+    } else {
+        with_aliases(field.name, &field.aliases)
+    };
+
+    if field.positional && !key.starts_with("position") {
+        key.push_str(" (positional)");
+    }
+
+    if field.multiple {
+        key.push_str(" (repeatable)");
+    }
+
+    key
+}
+
+fn type_cell(ty: &str, nested: Option<&Schema>) -> String {
+    match nested.and_then(unit_variants) {
+        Some(names) => format!("one of {}", names.join(", ")),
+        None => format!("`{}`", ty),
+    }
+}
+
+fn default_cell(field: &Field) -> String {
+    match field.default {
+        Some(FieldDefault::Trait) => "`Default::default()`".to_string(),
+        Some(FieldDefault::Function(function)) if function.contains('|') => {
+            format!("`{}`", function)
+        }
+        Some(FieldDefault::Function(function)) => format!("`{}()`", function),
+        Some(FieldDefault::Container) => "from the container default".to_string(),
+        None if field.required => "required".to_string(),
+        None if field.multiple => "none".to_string(),
+        None => "optional".to_string(),
+    }
+}
+
+/// Get the quoted names of an enum's variants, if every variant is written as a bare word.
+fn unit_variants(schema: &Schema) -> Option<Vec<String>> {
+    let SchemaKind::Enum(ref body) = schema.kind else {
+        return None;
+    };
+
+    let variants: Vec<_> = body.variants.iter().filter(|v| !v.skip).collect();
+    if variants.is_empty()
+        || variants
+            .iter()
+            .any(|v| v.other || v.kind != VariantKind::Unit)
+    {
+        return None;
+    }
+
+    Some(variants.iter().map(|v| format!("`{}`", v.name)).collect())
+}
+
+fn with_aliases(name: &str, aliases: &[&str]) -> String {
+    let aliases = aliases
+        .iter()
+        .map(|alias| format!("`{}`", alias))
+        .collect::<Vec<_>>();
+    match aliases.len() {
+        0 => format!("`{}`", name),
+        1 => format!("`{}` (alias {})", name, aliases[0]),
+        _ => format!("`{}` (aliases {})", name, aliases.join(", ")),
+    }
+}
+
+/// Build a table cell from a doc comment, which can't contain line breaks.
+fn description(doc: Option<&str>, deprecated: Option<&Deprecation>) -> String {
+    let mut cell = String::new();
+    if let Some(deprecated) = deprecated {
+        cell.push_str("**Deprecated**");
+        if let Some(note) = deprecated.note {
+            cell.push_str(": ");
+            cell.push_str(note);
+        }
+        cell.push('.');
+    }
+
+    if let Some(doc) = doc {
+        let paragraphs = doc
+            .split("\n\n")
+            .map(|paragraph| paragraph.split_whitespace().collect::<Vec<_>>().join(" "))
+            .filter(|paragraph| !paragraph.is_empty())
+            .collect::<Vec<_>>();
+        if !cell.is_empty() && !paragraphs.is_empty() {
+            cell.push(' ');
+        }
+        cell.push_str(&paragraphs.join("<br><br>"));
+    }
+
+    cell
+}
@@ -80,3 +80,26 @@ impl ChildDiagnostic {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ChildDiagnostic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let level = match self.level {
+            Level::Error => "error",
+            Level::Warning => "warning",
+            Level::Note => "note",
+            Level::Help => "help",
+        };
+
+        let mut state = serializer.serialize_struct("ChildDiagnostic", 3)?;
+        state.serialize_field("level", level)?;
+        state.serialize_field("message", &self.message)?;
+        state.serialize_field("span", &self.span.map(super::serde::SpanLocation))?;
+        state.end()
+    }
+}
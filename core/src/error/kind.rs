@@ -23,11 +23,38 @@ pub(in crate::error) enum ErrorKind {
         expected: Option<String>,
     },
     UnknownField(Box<ErrorUnknownValue>),
-    UnexpectedFormat(MetaFormat),
-    UnexpectedType(String),
+    UnexpectedFormat {
+        format: MetaFormat,
+        /// A description of what the input should have been, from [`FromMeta::expected`](crate::FromMeta::expected).
+        expected: Option<String>,
+    },
+    UnexpectedType {
+        ty: String,
+        /// A description of what the input should have been, from [`FromMeta::expected`](crate::FromMeta::expected).
+        expected: Option<String>,
+    },
     UnknownValue(Box<ErrorUnknownValue>),
+    UnknownAttribute(Box<ErrorUnknownValue>),
+    /// None of the expected attributes were present.
+    MissingAttribute(Vec<String>),
     TooFewItems(usize),
     TooManyItems(usize),
+    /// A value was outside the range allowed by a `range` constraint.
+    OutOfRange {
+        value: String,
+        expected: String,
+    },
+    /// A value's length was outside the range allowed by a `len` constraint.
+    InvalidLength {
+        len: usize,
+        expected: String,
+    },
+    /// A value was empty, but a `non_empty` constraint required at least one item.
+    EmptyValue,
+    /// None of a set of mutually exclusive keys were present.
+    MissingOneOf(Vec<String>),
+    /// More than one of a set of mutually exclusive keys were present.
+    ConflictingKeys(Vec<String>),
     /// A set of errors.
     Multiple(Vec<Error>),
 
@@ -67,11 +94,63 @@ impl fmt::Display for ErrorKind {
 
                 Ok(())
             }
-            UnexpectedFormat(ref format) => write!(f, "Unexpected meta-item format `{}`", format),
-            UnexpectedType(ref ty) => write!(f, "Unexpected type `{}`", ty),
+            UnexpectedFormat {
+                ref format,
+                ref expected,
+            } => {
+                write!(f, "Unexpected meta-item format `{}`", format)?;
+                if let Some(expected) = expected {
+                    write!(f, ", expected {}", expected)?;
+                }
+
+                Ok(())
+            }
+            UnexpectedType {
+                ref ty,
+                ref expected,
+            } => {
+                write!(f, "Unexpected type `{}`", ty)?;
+                if let Some(expected) = expected {
+                    write!(f, ", expected {}", expected)?;
+                }
+
+                Ok(())
+            }
             UnknownValue(ref val) => val.fmt(f),
+            UnknownAttribute(ref val) => val.fmt(f),
+            MissingAttribute(ref expected) => {
+                write!(f, "Missing attribute: expected one of ")?;
+                write_delimited(
+                    f,
+                    expected.iter().map(|name| format!("`#[{}]`", name)),
+                    ", ",
+                )
+            }
             TooFewItems(ref min) => write!(f, "Too few items: Expected at least {}", min),
             TooManyItems(ref max) => write!(f, "Too many items: Expected no more than {}", max),
+            OutOfRange {
+                ref value,
+                ref expected,
+            } => write!(
+                f,
+                "Value out of range: `{}` is not in `{}`",
+                value, expected
+            ),
+            InvalidLength { len, ref expected } => write!(
+                f,
+                "Invalid length: expected a length in `{}`, found {}",
+                expected, len
+            ),
+            EmptyValue => write!(f, "Value must not be empty"),
+            MissingOneOf(ref keys) => {
+                write!(f, "Missing field: expected one of ")?;
+                write_delimited(f, keys.iter().map(Quoted::backticks), ", ")
+            }
+            ConflictingKeys(ref keys) => {
+                write!(f, "Only one of ")?;
+                write_delimited(f, keys.iter().map(Quoted::backticks), ", ")?;
+                write!(f, " can be given")
+            }
             Multiple(ref items) if items.len() == 1 => items[0].fmt(f),
             Multiple(ref items) => {
                 write!(f, "Multiple errors: (")?;
@@ -83,10 +162,218 @@ impl fmt::Display for ErrorKind {
     }
 }
 
+impl ErrorKind {
+    /// Get the public view of this error kind.
+    pub fn as_kind_ref(&self) -> ErrorKindRef<'_> {
+        use self::ErrorKind::*;
+
+        match *self {
+            Custom(ref message) => ErrorKindRef::Custom(message),
+            DuplicateField(ref field) => ErrorKindRef::DuplicateField(field),
+            MissingField(ref field) => ErrorKindRef::MissingField(field),
+            UnsupportedShape {
+                ref observed,
+                ref expected,
+            } => ErrorKindRef::UnsupportedShape {
+                observed,
+                expected: expected.as_deref(),
+            },
+            UnknownField(ref value) => ErrorKindRef::UnknownField(UnknownValueRef(value)),
+            UnexpectedFormat {
+                ref format,
+                ref expected,
+            } => ErrorKindRef::UnexpectedFormat {
+                format,
+                expected: expected.as_deref(),
+            },
+            UnexpectedType {
+                ref ty,
+                ref expected,
+            } => ErrorKindRef::UnexpectedType {
+                ty,
+                expected: expected.as_deref(),
+            },
+            UnknownValue(ref value) => ErrorKindRef::UnknownValue(UnknownValueRef(value)),
+            UnknownAttribute(ref value) => ErrorKindRef::UnknownAttribute(UnknownValueRef(value)),
+            MissingAttribute(ref expected) => ErrorKindRef::MissingAttribute(expected),
+            TooFewItems(min) => ErrorKindRef::TooFewItems(min),
+            TooManyItems(max) => ErrorKindRef::TooManyItems(max),
+            OutOfRange {
+                ref value,
+                ref expected,
+            } => ErrorKindRef::OutOfRange { value, expected },
+            InvalidLength { len, ref expected } => ErrorKindRef::InvalidLength { len, expected },
+            EmptyValue => ErrorKindRef::EmptyValue,
+            MissingOneOf(ref keys) => ErrorKindRef::MissingOneOf(keys),
+            ConflictingKeys(ref keys) => ErrorKindRef::ConflictingKeys(keys),
+            Multiple(ref errors) => ErrorKindRef::Multiple(errors),
+            __NonExhaustive => unreachable!(),
+        }
+    }
+}
+
+/// A read-only view of what went wrong in an [`Error`], returned by [`Error::kind`].
+///
+/// New variants may be added in minor releases, so matches must have a wildcard arm.
+/// Each variant other than `Multiple` has a stable [code](Self::code), which doesn't
+/// change when the wording of the error's message does.
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum ErrorKindRef<'a> {
+    /// An error created with [`Error::custom`] or converted from a `syn::Error`.
+    Custom(&'a str),
+    /// A field was given more than once.
+    DuplicateField(&'a str),
+    /// A required field was not given.
+    MissingField(&'a str),
+    /// The deriving item has a shape the receiver doesn't support, such as a tuple struct.
+    UnsupportedShape {
+        observed: &'a str,
+        expected: Option<&'a str>,
+    },
+    UnknownField(UnknownValueRef<'a>),
+    /// The meta item was a word, list or name-value pair when the type doesn't accept that.
+    UnexpectedFormat {
+        format: &'a str,
+        /// A description of what the input should have been, from [`FromMeta::expected`](crate::FromMeta::expected).
+        expected: Option<&'a str>,
+    },
+    /// The literal or expression was of a type the receiving type doesn't accept.
+    UnexpectedType {
+        ty: &'a str,
+        /// A description of what the input should have been, from [`FromMeta::expected`](crate::FromMeta::expected).
+        expected: Option<&'a str>,
+    },
+    UnknownValue(UnknownValueRef<'a>),
+    UnknownAttribute(UnknownValueRef<'a>),
+    /// None of these attributes were present.
+    MissingAttribute(&'a [String]),
+    /// A list had fewer items than this minimum.
+    TooFewItems(usize),
+    /// A list had more items than this maximum.
+    TooManyItems(usize),
+    /// A value was outside the range allowed by a `range` constraint.
+    OutOfRange {
+        value: &'a str,
+        expected: &'a str,
+    },
+    /// A value's length was outside the range allowed by a `len` constraint.
+    InvalidLength {
+        len: usize,
+        expected: &'a str,
+    },
+    /// A value was empty, but a `non_empty` constraint required at least one item.
+    EmptyValue,
+    /// None of a set of mutually exclusive keys were present.
+    MissingOneOf(&'a [String]),
+    /// More than one of a set of mutually exclusive keys were present.
+    ConflictingKeys(&'a [String]),
+    /// A set of errors.
+    Multiple(&'a [Error]),
+}
+
+impl ErrorKindRef<'_> {
+    /// Get the stable code of this kind of error, or `None` for `Multiple`.
+    ///
+    /// | Code | Kind |
+    /// |---|---|
+    /// | `D0001` | `Custom` |
+    /// | `D0002` | `DuplicateField` |
+    /// | `D0003` | `MissingField` |
+    /// | `D0004` | `UnsupportedShape` |
+    /// | `D0005` | `UnknownField` |
+    /// | `D0006` | `UnexpectedFormat` |
+    /// | `D0007` | `UnexpectedType` |
+    /// | `D0008` | `UnknownValue` |
+    /// | `D0009` | `UnknownAttribute` |
+    /// | `D0010` | `MissingAttribute` |
+    /// | `D0011` | `TooFewItems` |
+    /// | `D0012` | `TooManyItems` |
+    /// | `D0013` | `OutOfRange` |
+    /// | `D0014` | `InvalidLength` |
+    /// | `D0015` | `EmptyValue` |
+    /// | `D0016` | `MissingOneOf` |
+    /// | `D0017` | `ConflictingKeys` |
+    ///
+    /// Codes are never reused or reassigned; new kinds get the next free code.
+    pub fn code(&self) -> Option<&'static str> {
+        use self::ErrorKindRef::*;
+
+        Some(match *self {
+            Custom(_) => "D0001",
+            DuplicateField(_) => "D0002",
+            MissingField(_) => "D0003",
+            UnsupportedShape { .. } => "D0004",
+            UnknownField(_) => "D0005",
+            UnexpectedFormat { .. } => "D0006",
+            UnexpectedType { .. } => "D0007",
+            UnknownValue(_) => "D0008",
+            UnknownAttribute(_) => "D0009",
+            MissingAttribute(_) => "D0010",
+            TooFewItems(_) => "D0011",
+            TooManyItems(_) => "D0012",
+            OutOfRange { .. } => "D0013",
+            InvalidLength { .. } => "D0014",
+            EmptyValue => "D0015",
+            MissingOneOf(_) => "D0016",
+            ConflictingKeys(_) => "D0017",
+            Multiple(_) => return None,
+        })
+    }
+
+    /// Get the name of this variant, such as `"MissingField"`.
+    pub fn name(&self) -> &'static str {
+        use self::ErrorKindRef::*;
+
+        match *self {
+            Custom(_) => "Custom",
+            DuplicateField(_) => "DuplicateField",
+            MissingField(_) => "MissingField",
+            UnsupportedShape { .. } => "UnsupportedShape",
+            UnknownField(_) => "UnknownField",
+            UnexpectedFormat { .. } => "UnexpectedFormat",
+            UnexpectedType { .. } => "UnexpectedType",
+            UnknownValue(_) => "UnknownValue",
+            UnknownAttribute(_) => "UnknownAttribute",
+            MissingAttribute(_) => "MissingAttribute",
+            TooFewItems(_) => "TooFewItems",
+            TooManyItems(_) => "TooManyItems",
+            OutOfRange { .. } => "OutOfRange",
+            InvalidLength { .. } => "InvalidLength",
+            EmptyValue => "EmptyValue",
+            MissingOneOf(_) => "MissingOneOf",
+            ConflictingKeys(_) => "ConflictingKeys",
+            Multiple(_) => "Multiple",
+        }
+    }
+}
+
+/// A read-only view of an unknown field, value or attribute error.
+#[derive(Debug, Clone, Copy)]
+pub struct UnknownValueRef<'a>(&'a ErrorUnknownValue);
+
+impl<'a> UnknownValueRef<'a> {
+    /// Get the name or value which was not recognized.
+    pub fn value(&self) -> &'a str {
+        &self.0.value
+    }
+
+    /// Get the known name most similar to the unrecognized one, if there is a close match.
+    pub fn did_you_mean(&self) -> Option<&'a str> {
+        self.0.did_you_mean.as_ref().map(|(_, name)| name.as_str())
+    }
+
+    /// Get the names which would have been accepted, in alphabetical order.
+    pub fn alternates(&self) -> impl Iterator<Item = &'a str> {
+        self.0.alts.iter().map(String::as_str)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(in crate::error) enum UnknownValuePosition {
     Field,
     Value,
+    Attribute,
 }
 
 impl AsRef<str> for UnknownValuePosition {
@@ -94,6 +381,7 @@ impl AsRef<str> for UnknownValuePosition {
         match self {
             UnknownValuePosition::Field => "field",
             UnknownValuePosition::Value => "value",
+            UnknownValuePosition::Attribute => "attribute",
         }
     }
 }
@@ -109,6 +397,7 @@ impl From<ErrorUnknownValue> for ErrorKind {
         match value.noun {
             UnknownValuePosition::Field => Self::UnknownField(Box::new(value)),
             UnknownValuePosition::Value => Self::UnknownValue(Box::new(value)),
+            UnknownValuePosition::Attribute => Self::UnknownAttribute(Box::new(value)),
         }
     }
 }
@@ -162,6 +451,11 @@ impl ErrorUnknownValue {
         }
     }
 
+    /// Whether a similar alternate was found for the unknown value.
+    pub fn has_suggestion(&self) -> bool {
+        self.did_you_mean.is_some()
+    }
+
     /// Add more alternate values to the error, updating the `did_you_mean` suggestion
     /// if a closer match to the unknown value is found.
     pub fn add_alts<'a, T, I>(&mut self, alternates: I)
@@ -18,11 +18,14 @@ use syn::{Expr, Lit, LitStr, Path};
 #[cfg(feature = "diagnostics")]
 mod child;
 mod kind;
+#[cfg(feature = "serde")]
+mod serde;
 mod util;
 
 use crate::util::path_to_string;
 
 use self::kind::{ErrorKind, ErrorUnknownValue, UnknownValuePosition};
+pub use self::kind::{ErrorKindRef, UnknownValueRef};
 
 /// An alias of `Result` specific to attribute parsing.
 pub type Result<T> = ::std::result::Result<T, Error>;
@@ -30,7 +33,8 @@ pub type Result<T> = ::std::result::Result<T, Error>;
 /// An error encountered during attribute parsing.
 ///
 /// Given that most errors darling encounters represent code bugs in dependent crates,
-/// the internal structure of the error is deliberately opaque.
+/// the internal structure of the error is deliberately opaque. [`Error::kind`] gives a
+/// read-only view of it, and [`Error::code`] a stable code, for tests and tooling.
 ///
 /// # Usage
 /// Proc-macro expansion happens very infrequently compared to runtime tasks such as
@@ -162,12 +166,18 @@ impl Error {
     }
 
     pub fn unsupported_format(format: &str) -> Self {
-        Error::new(ErrorKind::UnexpectedFormat(format.into()))
+        Error::new(ErrorKind::UnexpectedFormat {
+            format: format.into(),
+            expected: None,
+        })
     }
 
     /// Creates a new error for a field which has an unexpected literal type.
     pub fn unexpected_type(ty: &str) -> Self {
-        Error::new(ErrorKind::UnexpectedType(ty.into()))
+        Error::new(ErrorKind::UnexpectedType {
+            ty: ty.into(),
+            expected: None,
+        })
     }
 
     pub fn unexpected_expr_type(expr: &Expr) -> Self {
@@ -277,6 +287,35 @@ impl Error {
         )
     }
 
+    /// Creates a new error for an item which requires one of a set of attributes, but has none of them.
+    ///
+    /// If one of `attrs` looks like a misspelling of an expected attribute name, the error
+    /// points to that attribute and suggests the expected name instead.
+    pub fn missing_attribute_with_alts<'a, T, I>(attrs: &[syn::Attribute], alternates: I) -> Self
+    where
+        T: AsRef<str> + 'a,
+        I: IntoIterator<Item = &'a T>,
+    {
+        let alternates = alternates
+            .into_iter()
+            .map(|alt| alt.as_ref().to_string())
+            .collect::<Vec<_>>();
+
+        for attr in attrs {
+            let unknown = ErrorUnknownValue::with_alts(
+                UnknownValuePosition::Attribute,
+                &path_to_string(attr.path()),
+                &alternates,
+            );
+
+            if unknown.has_suggestion() {
+                return Error::new(unknown.into()).with_span(attr);
+            }
+        }
+
+        Error::new(ErrorKind::MissingAttribute(alternates))
+    }
+
     /// Creates a new error for a list which did not get enough items to proceed.
     pub fn too_few_items(min: usize) -> Self {
         Error::new(ErrorKind::TooFewItems(min))
@@ -288,6 +327,56 @@ impl Error {
         Error::new(ErrorKind::TooManyItems(max))
     }
 
+    /// Creates a new error for a value outside the allowed `range`.
+    ///
+    /// The range is written using its `Debug` representation, e.g. `1..=10`.
+    pub fn out_of_range<V: fmt::Display, R: fmt::Debug>(value: &V, range: &R) -> Self {
+        Error::new(ErrorKind::OutOfRange {
+            value: value.to_string(),
+            expected: format!("{:?}", range),
+        })
+    }
+
+    /// Creates a new error for a value whose length `len` is outside the allowed `range`.
+    pub fn invalid_length<R: fmt::Debug>(len: usize, range: &R) -> Self {
+        Error::new(ErrorKind::InvalidLength {
+            len,
+            expected: format!("{:?}", range),
+        })
+    }
+
+    /// Creates a new error for a value which was required to be non-empty.
+    pub fn empty_value() -> Self {
+        Error::new(ErrorKind::EmptyValue)
+    }
+
+    /// Creates a new error for a set of mutually exclusive keys, none of which were present.
+    pub fn missing_one_of<'a, T, I>(keys: I) -> Self
+    where
+        T: AsRef<str> + 'a,
+        I: IntoIterator<Item = &'a T>,
+    {
+        Error::new(ErrorKind::MissingOneOf(
+            keys.into_iter()
+                .map(|key| key.as_ref().to_string())
+                .collect(),
+        ))
+    }
+
+    /// Creates a new error for a set of mutually exclusive keys, more than one of which were
+    /// present. `keys` are the keys which were given.
+    pub fn conflicting_keys<'a, T, I>(keys: I) -> Self
+    where
+        T: AsRef<str> + 'a,
+        I: IntoIterator<Item = &'a T>,
+    {
+        Error::new(ErrorKind::ConflictingKeys(
+            keys.into_iter()
+                .map(|key| key.as_ref().to_string())
+                .collect(),
+        ))
+    }
+
     /// Bundle a set of multiple errors into a single `Error` instance.
     ///
     /// Usually it will be more convenient to use an [`error::Accumulator`](Accumulator).
@@ -324,6 +413,23 @@ impl Error {
 /// Error instance methods
 #[allow(clippy::len_without_is_empty)] // Error can never be empty
 impl Error {
+    /// Get what went wrong, including details such as the field name, the accepted alternates,
+    /// the expected shape or the item count. Errors created with [`Error::multiple`] or
+    /// collected by an [`Accumulator`] are `ErrorKindRef::Multiple`; use [`Error::flatten`] or
+    /// iterate over the error to visit each one.
+    ///
+    /// Prefer matching on this or on [`Error::code`] in tests over comparing messages, which
+    /// may be reworded in any release.
+    pub fn kind(&self) -> ErrorKindRef<'_> {
+        self.kind.as_kind_ref()
+    }
+
+    /// Get the stable code of this kind of error, such as `"D0003"` for a missing field,
+    /// or `None` if this contains multiple errors. See [`ErrorKindRef::code`] for the list.
+    pub fn code(&self) -> Option<&'static str> {
+        self.kind().code()
+    }
+
     /// Check if this error is associated with a span in the token stream.
     pub fn has_span(&self) -> bool {
         self.span.is_some()
@@ -489,6 +595,48 @@ impl Error {
         self
     }
 
+    /// Describe what the input should have been, such as `"a string literal"`, on unexpected
+    /// type and format errors which don't have a description yet. Other errors are unchanged.
+    ///
+    /// Like [`Self::add_sibling_alts_for_unknown_field`], this only applies to errors which
+    /// have not yet had a location added with [`Self::at`], since those came from a nested
+    /// item rather than the input currently being parsed.
+    ///
+    /// The default [`FromMeta::from_meta`](crate::FromMeta::from_meta) and derived field
+    /// extraction call this with the description from
+    /// [`FromMeta::expected`](crate::FromMeta::expected).
+    pub fn with_expected<T: fmt::Display>(self, expected: T) -> Self {
+        self.with_expected_internal(&expected.to_string())
+    }
+
+    fn with_expected_internal(mut self, description: &str) -> Self {
+        if !self.locations.is_empty() {
+            return self;
+        }
+
+        match self.kind {
+            ErrorKind::UnexpectedFormat {
+                ref mut expected, ..
+            }
+            | ErrorKind::UnexpectedType {
+                ref mut expected, ..
+            } if expected.is_none() => {
+                *expected = Some(description.to_string());
+            }
+            ErrorKind::Multiple(errors) => {
+                self.kind = ErrorKind::Multiple(
+                    errors
+                        .into_iter()
+                        .map(|err| err.with_expected_internal(description))
+                        .collect(),
+                )
+            }
+            _ => {}
+        }
+
+        self
+    }
+
     /// Adds a location chain to the head of the error's existing locations.
     fn prepend_at(mut self, mut locations: Vec<String>) -> Self {
         if !locations.is_empty() {
@@ -548,6 +696,7 @@ impl Error {
         let diagnostic = match self.kind {
             ErrorKind::UnknownField(euf) => euf.into_diagnostic(self.span),
             ErrorKind::UnknownValue(euv) => euv.into_diagnostic(self.span),
+            ErrorKind::UnknownAttribute(eua) => eua.into_diagnostic(self.span),
             _ => match self.span {
                 Some(span) => span.unwrap().error(self.kind.to_string()),
                 None => Diagnostic::new(Level::Error, self.to_string()),
@@ -0,0 +1,190 @@
+use proc_macro2::Span;
+use serde::ser::{SerializeStruct, Serializer};
+use serde::Serialize;
+
+use crate::error::{Error, ErrorKindRef};
+
+/// Serializes as a struct with these fields:
+///
+/// * `kind`: the [name](ErrorKindRef::name) of the kind of error, such as `"MissingField"`.
+/// * `code`: the stable [code](Error::code) of the error, or `null` for `"Multiple"`.
+/// * `message`: the error message, without the location.
+/// * `locations`: the path of fields and variants to the error, e.g. `["retry", "attempts"]`.
+/// * `span`: the `start` and `end` of the error's span, each with a 1-based `line` and a
+///   0-based `column`, or `null` if the error has no span. Both are `0` unless the
+///   `span-locations` feature is enabled.
+/// * `children`: the notes and help added with the `diagnostics` feature, each with a `level`,
+///   `message` and `span`. This is always empty without that feature.
+/// * `errors`: the errors contained by a `"Multiple"` error. Other kinds don't have this field.
+///
+/// Errors contained by another keep their own locations. To get a flat list in which each error's
+/// locations include those of the errors around it, serialize
+/// `error.flatten().into_iter().collect::<Vec<_>>()`.
+///
+/// Line and column numbers need the `span-locations` feature, which enables the feature of the
+/// same name in `proc-macro2`. That feature makes `proc-macro2` track the location of every
+/// token it parses, so it is left to the crates which want it, usually as a dev-dependency
+/// feature for snapshot tests. The numbers are only meaningful outside of a proc-macro, such as
+/// in a test which parses quoted input; inside one, they are whatever `proc-macro2` reports.
+impl Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let kind = self.kind();
+        let multiple = match kind {
+            ErrorKindRef::Multiple(errors) => Some(errors),
+            _ => None,
+        };
+
+        let mut state =
+            serializer.serialize_struct("Error", 6 + usize::from(multiple.is_some()))?;
+        state.serialize_field("kind", kind.name())?;
+        state.serialize_field("code", &kind.code())?;
+        state.serialize_field("message", &self.kind.to_string())?;
+        state.serialize_field("locations", &self.locations)?;
+        state.serialize_field("span", &self.span.map(SpanLocation))?;
+        #[cfg(feature = "diagnostics")]
+        state.serialize_field("children", &self.children)?;
+        #[cfg(not(feature = "diagnostics"))]
+        state.serialize_field("children", &[(); 0])?;
+        if let Some(errors) = multiple {
+            state.serialize_field("errors", errors)?;
+        }
+        state.end()
+    }
+}
+
+/// The start and end of a span in its source file.
+pub(in crate::error) struct SpanLocation(pub Span);
+
+impl Serialize for SpanLocation {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Span", 2)?;
+        state.serialize_field("start", &LineColumn::start(self.0))?;
+        state.serialize_field("end", &LineColumn::end(self.0))?;
+        state.end()
+    }
+}
+
+/// A line and column, which are both `0` when `proc-macro2` doesn't track locations.
+struct LineColumn {
+    line: usize,
+    column: usize,
+}
+
+impl LineColumn {
+    #[cfg(feature = "span-locations")]
+    fn start(span: Span) -> Self {
+        span.start().into()
+    }
+
+    #[cfg(feature = "span-locations")]
+    fn end(span: Span) -> Self {
+        span.end().into()
+    }
+
+    #[cfg(not(feature = "span-locations"))]
+    fn start(_: Span) -> Self {
+        Self::UNKNOWN
+    }
+
+    #[cfg(not(feature = "span-locations"))]
+    fn end(_: Span) -> Self {
+        Self::UNKNOWN
+    }
+
+    #[cfg(not(feature = "span-locations"))]
+    const UNKNOWN: Self = Self { line: 0, column: 0 };
+}
+
+#[cfg(feature = "span-locations")]
+impl From<proc_macro2::LineColumn> for LineColumn {
+    fn from(location: proc_macro2::LineColumn) -> Self {
+        Self {
+            line: location.line,
+            column: location.column,
+        }
+    }
+}
+
+impl Serialize for LineColumn {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("LineColumn", 2)?;
+        state.serialize_field("line", &self.line)?;
+        state.serialize_field("column", &self.column)?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::Error;
+
+    #[test]
+    fn unspanned() {
+        let error = Error::missing_field("port").at("server");
+        assert_eq!(
+            serde_json::to_value(&error).unwrap(),
+            json!({
+                "kind": "MissingField",
+                "code": "D0003",
+                "message": "Missing field `port`",
+                "locations": ["server"],
+                "span": null,
+                "children": [],
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "span-locations")]
+    fn spanned() {
+        let path: syn::Path = syn::parse_str("backoff").unwrap();
+        let error = Error::unknown_field_path(&path).with_span(&path);
+        assert_eq!(
+            serde_json::to_value(&error).unwrap()["span"],
+            json!({
+                "start": { "line": 1, "column": 0 },
+                "end": { "line": 1, "column": 7 },
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "span-locations"))]
+    fn spanned_without_locations() {
+        let path: syn::Path = syn::parse_str("backoff").unwrap();
+        let error = Error::unknown_field_path(&path).with_span(&path);
+        assert_eq!(
+            serde_json::to_value(&error).unwrap()["span"],
+            json!({
+                "start": { "line": 0, "column": 0 },
+                "end": { "line": 0, "column": 0 },
+            })
+        );
+    }
+
+    #[test]
+    fn multiple() {
+        let error =
+            Error::multiple(vec![Error::too_few_items(1), Error::custom("oops")]).at("list");
+        let value = serde_json::to_value(&error).unwrap();
+        assert_eq!(value["kind"], "Multiple");
+        assert_eq!(value["code"], json!(null));
+        assert_eq!(value["errors"][0]["code"], "D0011");
+        assert_eq!(value["errors"][1]["message"], "oops");
+
+        // Flattening applies the outer locations to each error.
+        let flat = serde_json::to_value(error.flatten().into_iter().collect::<Vec<_>>()).unwrap();
+        assert_eq!(flat[1]["locations"], json!(["list"]));
+    }
+}
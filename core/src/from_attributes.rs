@@ -4,8 +4,24 @@ use crate::Result;
 
 /// Create an instance by parsing a list of attributes.
 ///
-/// This trait is useful when dealing with items such as traits on traits and impl blocks,
-/// for which `darling` does not provide dedicated traits.
+/// This trait is useful when dealing with items for which `darling` does not provide
+/// dedicated traits, such as associated consts or modules. Trait definitions and impl
+/// blocks can use [`FromTraitDef`](crate::FromTraitDef) and [`FromImplBlock`](crate::FromImplBlock) instead.
+///
+/// # Deriving on enums
+/// When derived on an enum, each variant consumes the attribute whose path matches the
+/// variant's name, after applying `rename` and `rename_all`. Exactly one variant must match;
+/// other attributes are ignored.
+///
+/// ```rust,ignore
+/// #[derive(FromAttributes)]
+/// enum Route {
+///     // #[get(path = "/")]
+///     Get { path: String },
+///     // #[post(path = "/", body)]
+///     Post { path: String, body: Flag },
+/// }
+/// ```
 pub trait FromAttributes: Sized {
     /// Create an instance by parsing a list of attributes.
     ///
@@ -1,6 +1,6 @@
 use syn::DeriveInput;
 
-use crate::Result;
+use crate::{Result, Warnings};
 
 /// Creates an instance by parsing an entire proc-macro `derive` input,
 /// including the, identity, generics, and visibility of the type.
@@ -10,7 +10,19 @@ use crate::Result;
 /// that these implementations will be reusable across crates.
 pub trait FromDeriveInput: Sized {
     /// Create an instance from `syn::DeriveInput`, or return an error.
+    ///
+    /// Warnings recorded while parsing, such as for keys marked `#[darling(deprecated)]`, are
+    /// discarded unless this is called inside [`Warnings::capture`]. Use
+    /// [`from_derive_input_with_warnings`](FromDeriveInput::from_derive_input_with_warnings)
+    /// to get them back.
     fn from_derive_input(input: &DeriveInput) -> Result<Self>;
+
+    /// Create an instance from `syn::DeriveInput` using
+    /// [`from_derive_input`](FromDeriveInput::from_derive_input), and return the warnings
+    /// recorded while parsing alongside the result.
+    fn from_derive_input_with_warnings(input: &DeriveInput) -> (Result<Self>, Warnings) {
+        Warnings::capture(|| Self::from_derive_input(input))
+    }
 }
 
 impl FromDeriveInput for () {
@@ -0,0 +1,40 @@
+use syn::ItemFn;
+
+use crate::Result;
+
+/// Creates an instance by parsing a free-standing function and its attributes.
+///
+/// This is intended for use in attribute macros which are applied to functions.
+pub trait FromFn: Sized {
+    fn from_fn(item: &ItemFn) -> Result<Self>;
+}
+
+impl FromFn for () {
+    fn from_fn(_: &ItemFn) -> Result<Self> {
+        Ok(())
+    }
+}
+
+impl FromFn for ItemFn {
+    fn from_fn(item: &ItemFn) -> Result<Self> {
+        Ok(item.clone())
+    }
+}
+
+impl FromFn for syn::Signature {
+    fn from_fn(item: &ItemFn) -> Result<Self> {
+        Ok(item.sig.clone())
+    }
+}
+
+impl FromFn for syn::Ident {
+    fn from_fn(item: &ItemFn) -> Result<Self> {
+        Ok(item.sig.ident.clone())
+    }
+}
+
+impl FromFn for Vec<syn::Attribute> {
+    fn from_fn(item: &ItemFn) -> Result<Self> {
+        Ok(item.attrs.clone())
+    }
+}
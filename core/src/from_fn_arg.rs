@@ -0,0 +1,40 @@
+use syn::FnArg;
+
+use crate::Result;
+
+/// Creates an instance by parsing an individual function argument and its attributes.
+///
+/// This is implemented for both typed arguments and `self` receivers.
+pub trait FromFnArg: Sized {
+    fn from_fn_arg(arg: &FnArg) -> Result<Self>;
+}
+
+impl FromFnArg for () {
+    fn from_fn_arg(_: &FnArg) -> Result<Self> {
+        Ok(())
+    }
+}
+
+impl FromFnArg for FnArg {
+    fn from_fn_arg(arg: &FnArg) -> Result<Self> {
+        Ok(arg.clone())
+    }
+}
+
+impl FromFnArg for syn::Type {
+    fn from_fn_arg(arg: &FnArg) -> Result<Self> {
+        Ok(match *arg {
+            FnArg::Receiver(ref receiver) => (*receiver.ty).clone(),
+            FnArg::Typed(ref typed) => (*typed.ty).clone(),
+        })
+    }
+}
+
+impl FromFnArg for Vec<syn::Attribute> {
+    fn from_fn_arg(arg: &FnArg) -> Result<Self> {
+        Ok(match *arg {
+            FnArg::Receiver(ref receiver) => receiver.attrs.clone(),
+            FnArg::Typed(ref typed) => typed.attrs.clone(),
+        })
+    }
+}
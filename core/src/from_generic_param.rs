@@ -17,3 +17,23 @@ impl FromGenericParam for syn::GenericParam {
         Ok(param.clone())
     }
 }
+
+impl FromGenericParam for Vec<syn::Attribute> {
+    fn from_generic_param(param: &syn::GenericParam) -> Result<Self> {
+        Ok(match *param {
+            syn::GenericParam::Type(ref ty) => ty.attrs.clone(),
+            syn::GenericParam::Lifetime(ref lt) => lt.attrs.clone(),
+            syn::GenericParam::Const(ref c) => c.attrs.clone(),
+        })
+    }
+}
+
+impl FromGenericParam for syn::Ident {
+    fn from_generic_param(param: &syn::GenericParam) -> Result<Self> {
+        Ok(match *param {
+            syn::GenericParam::Type(ref ty) => ty.ident.clone(),
+            syn::GenericParam::Lifetime(ref lt) => lt.lifetime.ident.clone(),
+            syn::GenericParam::Const(ref c) => c.ident.clone(),
+        })
+    }
+}
@@ -0,0 +1,28 @@
+use syn::ItemImpl;
+
+use crate::Result;
+
+/// Creates an instance by parsing an impl block and its attributes.
+///
+/// Derived implementations can read each method of the block using [`FromImplItemFn`](crate::FromImplItemFn).
+pub trait FromImplBlock: Sized {
+    fn from_impl_block(item: &ItemImpl) -> Result<Self>;
+}
+
+impl FromImplBlock for () {
+    fn from_impl_block(_: &ItemImpl) -> Result<Self> {
+        Ok(())
+    }
+}
+
+impl FromImplBlock for ItemImpl {
+    fn from_impl_block(item: &ItemImpl) -> Result<Self> {
+        Ok(item.clone())
+    }
+}
+
+impl FromImplBlock for Vec<syn::Attribute> {
+    fn from_impl_block(item: &ItemImpl) -> Result<Self> {
+        Ok(item.attrs.clone())
+    }
+}
@@ -0,0 +1,38 @@
+use syn::ImplItemFn;
+
+use crate::Result;
+
+/// Creates an instance by parsing a method inside an impl block, along with its attributes.
+pub trait FromImplItemFn: Sized {
+    fn from_impl_item_fn(item: &ImplItemFn) -> Result<Self>;
+}
+
+impl FromImplItemFn for () {
+    fn from_impl_item_fn(_: &ImplItemFn) -> Result<Self> {
+        Ok(())
+    }
+}
+
+impl FromImplItemFn for ImplItemFn {
+    fn from_impl_item_fn(item: &ImplItemFn) -> Result<Self> {
+        Ok(item.clone())
+    }
+}
+
+impl FromImplItemFn for syn::Signature {
+    fn from_impl_item_fn(item: &ImplItemFn) -> Result<Self> {
+        Ok(item.sig.clone())
+    }
+}
+
+impl FromImplItemFn for syn::Ident {
+    fn from_impl_item_fn(item: &ImplItemFn) -> Result<Self> {
+        Ok(item.sig.ident.clone())
+    }
+}
+
+impl FromImplItemFn for Vec<syn::Attribute> {
+    fn from_impl_item_fn(item: &ImplItemFn) -> Result<Self> {
+        Ok(item.attrs.clone())
+    }
+}
@@ -0,0 +1,38 @@
+use syn::LifetimeParam;
+
+use crate::Result;
+
+/// Creates an instance by parsing an individual lifetime param and its attributes.
+pub trait FromLifetimeParam: Sized {
+    fn from_lifetime_param(lifetime_param: &LifetimeParam) -> Result<Self>;
+}
+
+impl FromLifetimeParam for () {
+    fn from_lifetime_param(_: &LifetimeParam) -> Result<Self> {
+        Ok(())
+    }
+}
+
+impl FromLifetimeParam for LifetimeParam {
+    fn from_lifetime_param(lifetime_param: &LifetimeParam) -> Result<Self> {
+        Ok(lifetime_param.clone())
+    }
+}
+
+impl FromLifetimeParam for Vec<syn::Attribute> {
+    fn from_lifetime_param(lifetime_param: &LifetimeParam) -> Result<Self> {
+        Ok(lifetime_param.attrs.clone())
+    }
+}
+
+impl FromLifetimeParam for syn::Lifetime {
+    fn from_lifetime_param(lifetime_param: &LifetimeParam) -> Result<Self> {
+        Ok(lifetime_param.lifetime.clone())
+    }
+}
+
+impl FromLifetimeParam for syn::Ident {
+    fn from_lifetime_param(lifetime_param: &LifetimeParam) -> Result<Self> {
+        Ok(lifetime_param.lifetime.ident.clone())
+    }
+}
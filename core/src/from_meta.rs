@@ -15,7 +15,7 @@ use syn::{Expr, Ident, Lit, Meta, Path};
 
 use crate::ast::{MetaNameValueInvalidExpr, NestedMeta};
 use crate::util::path_to_string;
-use crate::{Error, Result};
+use crate::{Error, Result, Warnings};
 
 /// Create an instance from an item in an attribute declaration.
 ///
@@ -59,7 +59,7 @@ pub trait FromMeta: Sized {
             NestedMeta::Meta(ref mi) => Self::from_meta(mi),
             NestedMeta::NameValueInvalidExpr(ref meta) => Self::from_invalid_expr(meta),
         })
-        .map_err(|e| e.with_span(item))
+        .map_err(|e| with_expected::<Self>(e.with_span(item)))
     }
 
     /// Create an instance from a `syn::Meta` by dispatching to the format-appropriate
@@ -70,6 +70,11 @@ pub trait FromMeta: Sized {
     /// other `from_meta` calls, the override must call `with_span` on the error using the
     /// `item` to make sure that the emitted diagnostic points to the correct location in
     /// source code.
+    ///
+    /// # Warnings
+    /// Warnings recorded while parsing, such as for keys marked `#[darling(deprecated)]`, are
+    /// discarded unless this is called inside [`Warnings::capture`](crate::Warnings::capture).
+    /// Use [`from_meta_with_warnings`](FromMeta::from_meta_with_warnings) to get them back.
     fn from_meta(item: &Meta) -> Result<Self> {
         (match *item {
             Meta::Path(_) => Self::from_word(),
@@ -78,7 +83,13 @@ pub trait FromMeta: Sized {
             }
             Meta::NameValue(ref value) => Self::from_expr(&value.value),
         })
-        .map_err(|e| e.with_span(item))
+        .map_err(|e| with_expected::<Self>(e.with_span(item)))
+    }
+
+    /// Create an instance from a `syn::Meta` using [`from_meta`](FromMeta::from_meta), and
+    /// return the warnings recorded while parsing alongside the result.
+    fn from_meta_with_warnings(item: &Meta) -> (Result<Self>, Warnings) {
+        Warnings::capture(|| Self::from_meta(item))
     }
 
     /// When a field is omitted from a parent meta-item, `from_none` is used to attempt
@@ -95,6 +106,41 @@ pub trait FromMeta: Sized {
         None
     }
 
+    /// The names of the items this type reads when it is parsed from a list of nested
+    /// meta items, or `None` if the set of names is not known.
+    ///
+    /// Derived impls return the names of a struct's fields or an enum's variants, including
+    /// aliases. A parent struct uses this to decide which of its items belong to a
+    /// `#[darling(one_of_keys)]` field.
+    ///
+    /// Wrapper types such as `Option<T>` should forward to their inner type.
+    fn known_keys() -> Option<Vec<&'static str>> {
+        None
+    }
+
+    /// A description of the attribute syntax this type accepts, or `None` if there is none.
+    ///
+    /// Derived impls return the type's schema when the container has `#[darling(schema)]`,
+    /// which lets a parent's schema include the types of its fields.
+    ///
+    /// Wrapper types such as `Option<T>` should forward to their inner type.
+    fn describe() -> Option<crate::schema::Schema> {
+        None
+    }
+
+    /// A short description of the input this type accepts, such as `"a string literal"` or
+    /// ``"one of `fast`, `safe`"``, or `None` if there is nothing useful to say.
+    ///
+    /// The default `from_meta` and `from_nested_meta` add this to the unexpected type and
+    /// format errors they return, so that they read like
+    /// ``Unexpected type `bool`, expected a string literal``. Derived impls describe the
+    /// struct or enum being derived.
+    ///
+    /// Wrapper types such as `Option<T>` should forward to their inner type.
+    fn expected() -> Option<Cow<'static, str>> {
+        None
+    }
+
     /// Create an instance from the presence of the word in the attribute with no
     /// additional options specified.
     fn from_word() -> Result<Self> {
@@ -238,6 +284,22 @@ pub trait FromMeta: Sized {
     }
 }
 
+/// Add the description from `T::expected` to an unexpected type or format error.
+fn with_expected<T: FromMeta>(error: Error) -> Error {
+    match T::expected() {
+        Some(expected) => error.with_expected(expected),
+        None => error,
+    }
+}
+
+/// Read a field with [`FromMeta::from_meta`], adding the description from `T::expected` to
+/// its unexpected type and format errors. Derived impls use this for fields without `with`, so
+/// that types which override `from_meta` still describe their input.
+#[doc(hidden)]
+pub fn from_meta_with_expected<T: FromMeta>(item: &Meta) -> Result<T> {
+    T::from_meta(item).map_err(with_expected::<T>)
+}
+
 // FromMeta impls for std and syn types.
 
 impl FromMeta for () {
@@ -245,6 +307,10 @@ impl FromMeta for () {
         Ok(())
     }
 
+    fn expected() -> Option<Cow<'static, str>> {
+        Some(Cow::Borrowed("a word"))
+    }
+
     fn from_list(items: &[NestedMeta]) -> Result<Self> {
         let mut errors = Error::accumulator();
         for item in items {
@@ -279,6 +345,10 @@ impl FromMeta for bool {
         Ok(true)
     }
 
+    fn expected() -> Option<Cow<'static, str>> {
+        Some(Cow::Borrowed("a word or a boolean literal"))
+    }
+
     #[allow(clippy::wrong_self_convention)] // false positive
     fn from_bool(value: bool) -> Result<Self> {
         Ok(value)
@@ -290,6 +360,10 @@ impl FromMeta for bool {
 }
 
 impl FromMeta for AtomicBool {
+    fn expected() -> Option<Cow<'static, str>> {
+        bool::expected()
+    }
+
     fn from_meta(mi: &Meta) -> Result<Self> {
         FromMeta::from_meta(mi)
             .map(AtomicBool::new)
@@ -298,6 +372,10 @@ impl FromMeta for AtomicBool {
 }
 
 impl FromMeta for char {
+    fn expected() -> Option<Cow<'static, str>> {
+        Some(Cow::Borrowed("a character literal"))
+    }
+
     #[allow(clippy::wrong_self_convention)] // false positive
     fn from_char(value: char) -> Result<Self> {
         Ok(value)
@@ -317,12 +395,20 @@ impl FromMeta for char {
 }
 
 impl FromMeta for String {
+    fn expected() -> Option<Cow<'static, str>> {
+        Some(Cow::Borrowed("a string literal"))
+    }
+
     fn from_string(s: &str) -> Result<Self> {
         Ok(s.to_string())
     }
 }
 
 impl FromMeta for std::path::PathBuf {
+    fn expected() -> Option<Cow<'static, str>> {
+        Some(Cow::Borrowed("a string literal"))
+    }
+
     fn from_string(s: &str) -> Result<Self> {
         Ok(s.into())
     }
@@ -333,6 +419,10 @@ impl FromMeta for std::path::PathBuf {
 macro_rules! from_meta_num {
     ($ty:path) => {
         impl FromMeta for $ty {
+            fn expected() -> Option<Cow<'static, str>> {
+                Some(Cow::Borrowed("an integer literal"))
+            }
+
             fn from_string(s: &str) -> Result<Self> {
                 s.parse().map_err(|_| Error::unknown_value(s))
             }
@@ -379,6 +469,10 @@ from_meta_num!(num::NonZeroIsize);
 macro_rules! from_meta_float {
     ($ty:ident) => {
         impl FromMeta for $ty {
+            fn expected() -> Option<Cow<'static, str>> {
+                Some(Cow::Borrowed("a float literal"))
+            }
+
             fn from_string(s: &str) -> Result<Self> {
                 s.parse().map_err(|_| Error::unknown_value(s))
             }
@@ -402,6 +496,10 @@ from_meta_float!(f64);
 /// when available, but also supports parsing strings with the call site as the
 /// emitted span.
 impl<T: syn::parse::Parse, P: syn::parse::Parse> FromMeta for syn::punctuated::Punctuated<T, P> {
+    fn expected() -> Option<Cow<'static, str>> {
+        Some(Cow::Borrowed("a string literal"))
+    }
+
     fn from_value(value: &Lit) -> Result<Self> {
         if let Lit::Str(ref ident) = *value {
             ident
@@ -422,6 +520,10 @@ impl<T: syn::parse::Parse, P: syn::parse::Parse> FromMeta for syn::punctuated::P
 /// See [`util::parse_expr`](crate::util::parse_expr) for functions to provide
 /// alternate parsing modes for this type.
 impl FromMeta for syn::Expr {
+    fn expected() -> Option<Cow<'static, str>> {
+        Some(Cow::Borrowed("an expression"))
+    }
+
     fn from_expr(expr: &Expr) -> Result<Self> {
         match expr {
             Expr::Lit(syn::ExprLit {
@@ -449,6 +551,10 @@ impl FromMeta for syn::Expr {
 
 /// Parser for paths that supports both quote-wrapped and bare values.
 impl FromMeta for syn::Path {
+    fn expected() -> Option<Cow<'static, str>> {
+        Some(Cow::Borrowed("a path"))
+    }
+
     fn from_string(value: &str) -> Result<Self> {
         syn::parse_str(value).map_err(|_| Error::unknown_value(value))
     }
@@ -472,6 +578,10 @@ impl FromMeta for syn::Path {
 }
 
 impl FromMeta for syn::Ident {
+    fn expected() -> Option<Cow<'static, str>> {
+        Some(Cow::Borrowed("an identifier"))
+    }
+
     fn from_string(value: &str) -> Result<Self> {
         syn::parse_str(value).map_err(|_| Error::unknown_value(value))
     }
@@ -511,8 +621,12 @@ impl FromMeta for syn::Ident {
 /// item as an expression of the right type and the passed-in expression is
 /// a string literal, it will fall back to parsing the string contents.
 macro_rules! from_syn_expr_type {
-    ($ty:path, $variant:ident) => {
+    ($ty:path, $variant:ident, $expected:literal) => {
         impl FromMeta for $ty {
+            fn expected() -> Option<Cow<'static, str>> {
+                Some(Cow::Borrowed($expected))
+            }
+
             fn from_expr(expr: &syn::Expr) -> Result<Self> {
                 match expr {
                     syn::Expr::$variant(body) => Ok(body.clone()),
@@ -534,9 +648,9 @@ macro_rules! from_syn_expr_type {
     };
 }
 
-from_syn_expr_type!(syn::ExprArray, Array);
-from_syn_expr_type!(syn::ExprPath, Path);
-from_syn_expr_type!(syn::ExprRange, Range);
+from_syn_expr_type!(syn::ExprArray, Array, "an array expression");
+from_syn_expr_type!(syn::ExprPath, Path, "a path");
+from_syn_expr_type!(syn::ExprRange, Range, "a range expression");
 
 /// Adapter from `syn::parse::Parse` to `FromMeta` for items that cannot
 /// be expressed in a [`syn::MetaNameValue`].
@@ -544,8 +658,12 @@ from_syn_expr_type!(syn::ExprRange, Range);
 /// This cannot be a blanket impl, due to the `syn::Lit` family's need to handle non-string values.
 /// Therefore, we use a macro and a lot of impls.
 macro_rules! from_syn_parse {
-    ($ty:path) => {
+    ($ty:path, $expected:literal) => {
         impl FromMeta for $ty {
+            fn expected() -> Option<Cow<'static, str>> {
+                Some(Cow::Borrowed($expected))
+            }
+
             fn from_string(value: &str) -> Result<Self> {
                 syn::parse_str(value).map_err(|_| Error::unknown_value(value))
             }
@@ -584,25 +702,29 @@ macro_rules! from_syn_parse {
     };
 }
 
-from_syn_parse!(syn::Type);
-from_syn_parse!(syn::TypeArray);
-from_syn_parse!(syn::TypeBareFn);
-from_syn_parse!(syn::TypeGroup);
-from_syn_parse!(syn::TypeImplTrait);
-from_syn_parse!(syn::TypeInfer);
-from_syn_parse!(syn::TypeMacro);
-from_syn_parse!(syn::TypeNever);
-from_syn_parse!(syn::TypeParam);
-from_syn_parse!(syn::TypeParen);
-from_syn_parse!(syn::TypePtr);
-from_syn_parse!(syn::TypeReference);
-from_syn_parse!(syn::TypeSlice);
-from_syn_parse!(syn::TypeTraitObject);
-from_syn_parse!(syn::TypeTuple);
-from_syn_parse!(syn::Visibility);
-from_syn_parse!(syn::WhereClause);
+from_syn_parse!(syn::Type, "a type");
+from_syn_parse!(syn::TypeArray, "an array type");
+from_syn_parse!(syn::TypeBareFn, "a function pointer type");
+from_syn_parse!(syn::TypeGroup, "a type");
+from_syn_parse!(syn::TypeImplTrait, "an `impl Trait` type");
+from_syn_parse!(syn::TypeInfer, "`_`");
+from_syn_parse!(syn::TypeMacro, "a macro type");
+from_syn_parse!(syn::TypeNever, "`!`");
+from_syn_parse!(syn::TypeParam, "a type parameter");
+from_syn_parse!(syn::TypeParen, "a parenthesized type");
+from_syn_parse!(syn::TypePtr, "a raw pointer type");
+from_syn_parse!(syn::TypeReference, "a reference type");
+from_syn_parse!(syn::TypeSlice, "a slice type");
+from_syn_parse!(syn::TypeTraitObject, "a trait object type");
+from_syn_parse!(syn::TypeTuple, "a tuple type");
+from_syn_parse!(syn::Visibility, "a visibility");
+from_syn_parse!(syn::WhereClause, "a where clause");
 
 impl FromMeta for syn::TypePath {
+    fn expected() -> Option<Cow<'static, str>> {
+        Some(Cow::Borrowed("a type path"))
+    }
+
     /// Supports both quote-wrapped and bare values.
     fn from_expr(expr: &Expr) -> Result<Self> {
         match expr {
@@ -639,6 +761,10 @@ macro_rules! from_numeric_array {
     ($ty:ident) => {
         /// Parsing an unsigned integer array, i.e. `example = "[1, 2, 3, 4]"`.
         impl FromMeta for Vec<$ty> {
+            fn expected() -> Option<Cow<'static, str>> {
+                Some(Cow::Borrowed("an array of integer literals"))
+            }
+
             fn from_expr(expr: &syn::Expr) -> Result<Self> {
                 match expr {
                     syn::Expr::Array(expr_array) => expr_array
@@ -679,14 +805,22 @@ from_numeric_array!(u64);
 from_numeric_array!(usize);
 
 impl FromMeta for syn::Lit {
+    fn expected() -> Option<Cow<'static, str>> {
+        Some(Cow::Borrowed("a literal"))
+    }
+
     fn from_value(value: &Lit) -> Result<Self> {
         Ok(value.clone())
     }
 }
 
 macro_rules! from_meta_lit {
-    ($impl_ty:path, $lit_variant:path) => {
+    ($impl_ty:path, $lit_variant:path, $expected:literal, $expected_list:literal) => {
         impl FromMeta for $impl_ty {
+            fn expected() -> Option<Cow<'static, str>> {
+                Some(Cow::Borrowed($expected))
+            }
+
             fn from_value(value: &Lit) -> Result<Self> {
                 if let $lit_variant(ref value) = *value {
                     Ok(value.clone())
@@ -697,6 +831,10 @@ macro_rules! from_meta_lit {
         }
 
         impl FromMeta for Vec<$impl_ty> {
+            fn expected() -> Option<Cow<'static, str>> {
+                Some(Cow::Borrowed($expected_list))
+            }
+
             fn from_list(items: &[NestedMeta]) -> Result<Self> {
                 items
                     .iter()
@@ -725,22 +863,70 @@ macro_rules! from_meta_lit {
     };
 }
 
-from_meta_lit!(syn::LitInt, Lit::Int);
-from_meta_lit!(syn::LitFloat, Lit::Float);
-from_meta_lit!(syn::LitStr, Lit::Str);
-from_meta_lit!(syn::LitByte, Lit::Byte);
-from_meta_lit!(syn::LitByteStr, Lit::ByteStr);
-from_meta_lit!(syn::LitChar, Lit::Char);
-from_meta_lit!(syn::LitBool, Lit::Bool);
-from_meta_lit!(proc_macro2::Literal, Lit::Verbatim);
+from_meta_lit!(
+    syn::LitInt,
+    Lit::Int,
+    "an integer literal",
+    "a list or array of integer literals"
+);
+from_meta_lit!(
+    syn::LitFloat,
+    Lit::Float,
+    "a float literal",
+    "a list or array of float literals"
+);
+from_meta_lit!(
+    syn::LitStr,
+    Lit::Str,
+    "a string literal",
+    "a list or array of string literals"
+);
+from_meta_lit!(
+    syn::LitByte,
+    Lit::Byte,
+    "a byte literal",
+    "a list or array of byte literals"
+);
+from_meta_lit!(
+    syn::LitByteStr,
+    Lit::ByteStr,
+    "a byte string literal",
+    "a list or array of byte string literals"
+);
+from_meta_lit!(
+    syn::LitChar,
+    Lit::Char,
+    "a character literal",
+    "a list or array of character literals"
+);
+from_meta_lit!(
+    syn::LitBool,
+    Lit::Bool,
+    "a boolean literal",
+    "a list or array of boolean literals"
+);
+from_meta_lit!(
+    proc_macro2::Literal,
+    Lit::Verbatim,
+    "a literal",
+    "a list or array of literals"
+);
 
 impl FromMeta for syn::Meta {
+    fn expected() -> Option<Cow<'static, str>> {
+        Some(Cow::Borrowed("a meta item"))
+    }
+
     fn from_meta(value: &syn::Meta) -> Result<Self> {
         Ok(value.clone())
     }
 }
 
 impl FromMeta for Vec<syn::WherePredicate> {
+    fn expected() -> Option<Cow<'static, str>> {
+        Some(Cow::Borrowed("a string literal of where predicates"))
+    }
+
     fn from_string(value: &str) -> Result<Self> {
         syn::WhereClause::from_string(&format!("where {}", value))
             .map(|c| c.predicates.into_iter().collect())
@@ -760,6 +946,12 @@ impl FromMeta for Vec<syn::WherePredicate> {
 }
 
 impl FromMeta for ident_case::RenameRule {
+    fn expected() -> Option<Cow<'static, str>> {
+        Some(Cow::Borrowed(
+            "a string literal naming a case, such as `\"snake_case\"`",
+        ))
+    }
+
     fn from_string(value: &str) -> Result<Self> {
         value.parse().map_err(|_| Error::unknown_value(value))
     }
@@ -770,6 +962,18 @@ impl<T: FromMeta> FromMeta for Option<T> {
         Some(None)
     }
 
+    fn known_keys() -> Option<Vec<&'static str>> {
+        T::known_keys()
+    }
+
+    fn describe() -> Option<crate::schema::Schema> {
+        T::describe()
+    }
+
+    fn expected() -> Option<Cow<'static, str>> {
+        T::expected()
+    }
+
     fn from_meta(item: &Meta) -> Result<Self> {
         FromMeta::from_meta(item).map(Some)
     }
@@ -780,6 +984,18 @@ impl<T: FromMeta> FromMeta for Result<T> {
         T::from_none().map(Ok)
     }
 
+    fn known_keys() -> Option<Vec<&'static str>> {
+        T::known_keys()
+    }
+
+    fn describe() -> Option<crate::schema::Schema> {
+        T::describe()
+    }
+
+    fn expected() -> Option<Cow<'static, str>> {
+        T::expected()
+    }
+
     // `#[darling(flatten)]` forwards directly to this method, so it's
     // necessary to declare it to avoid getting an unsupported format
     // error if it's invoked directly.
@@ -800,6 +1016,18 @@ macro_rules! smart_pointer_t {
                 T::from_none().map($map_fn)
             }
 
+            fn known_keys() -> Option<Vec<&'static str>> {
+                T::known_keys()
+            }
+
+            fn describe() -> Option<crate::schema::Schema> {
+                T::describe()
+            }
+
+            fn expected() -> Option<Cow<'static, str>> {
+                T::expected()
+            }
+
             // `#[darling(flatten)]` forwards directly to this method, so it's
             // necessary to declare it to avoid getting an unsupported format
             // error if it's invoked directly.
@@ -822,6 +1050,10 @@ smart_pointer_t!(RefCell<T>, RefCell::new);
 /// Parses the meta-item, and in case of error preserves a copy of the input for
 /// later analysis.
 impl<T: FromMeta> FromMeta for ::std::result::Result<T, Meta> {
+    fn expected() -> Option<Cow<'static, str>> {
+        T::expected()
+    }
+
     fn from_meta(item: &Meta) -> Result<Self> {
         T::from_meta(item)
             .map(Ok)
@@ -890,6 +1122,10 @@ macro_rules! map {
     };
 
     ($new:expr, $key:ty, $nested:ident) => {
+        fn expected() -> Option<Cow<'static, str>> {
+            Some(Cow::Borrowed("a list like `name(key = value)`"))
+        }
+
         fn from_list($nested: &[NestedMeta]) -> Result<Self> {
             // Convert the nested meta items into a sequence of (path, value result) result tuples.
             // An outer Err means no (key, value) structured could be found, while an Err in the
@@ -1022,6 +1258,7 @@ map!(btree_map, syn::Ident, nested);
 #[allow(clippy::wrong_self_convention)]
 pub mod autoref_specialization {
     use super::FromMeta;
+    use crate::schema::Schema;
     use std::marker::PhantomData;
 
     pub struct FromMetaTag<T>(PhantomData<T>);
@@ -1031,12 +1268,20 @@ pub mod autoref_specialization {
         pub fn from_none(self) -> Option<T> {
             T::from_none()
         }
+
+        pub fn describe(self) -> Option<Schema> {
+            T::describe()
+        }
     }
 
     impl<T> FromMetaTagAll<T> {
         pub fn from_none(self) -> Option<T> {
             None
         }
+
+        pub fn describe(self) -> Option<Schema> {
+            None
+        }
     }
 
     pub trait SpecFromMeta<T>: Sized {
@@ -1060,6 +1305,10 @@ pub mod autoref_specialization {
 }
 
 impl FromMeta for Vec<Ident> {
+    fn expected() -> Option<Cow<'static, str>> {
+        Some(Cow::Borrowed("a list of identifiers"))
+    }
+
     fn from_list(nested: &[NestedMeta]) -> Result<Self> {
         let items = nested.iter().map(|item| match *item {
             NestedMeta::Meta(ref inner) => Ok(inner.require_path_only()?.require_ident()?),
@@ -1080,6 +1329,10 @@ impl FromMeta for Vec<Ident> {
 }
 
 impl FromMeta for Vec<Path> {
+    fn expected() -> Option<Cow<'static, str>> {
+        Some(Cow::Borrowed("a list of paths"))
+    }
+
     fn from_list(nested: &[NestedMeta]) -> Result<Self> {
         let items = nested.iter().map(|item| match *item {
             NestedMeta::Meta(ref inner) => Ok(inner.require_path_only()?),
@@ -1100,6 +1353,10 @@ impl FromMeta for Vec<Path> {
 }
 
 impl FromMeta for HashSet<Ident> {
+    fn expected() -> Option<Cow<'static, str>> {
+        Some(Cow::Borrowed("a list of identifiers"))
+    }
+
     fn from_list(nested: &[NestedMeta]) -> Result<Self> {
         let items = nested.iter().map(|item| match *item {
             NestedMeta::Meta(ref inner) => Ok(inner.require_path_only()?.require_ident()?),
@@ -1125,6 +1382,10 @@ impl FromMeta for HashSet<Ident> {
 }
 
 impl FromMeta for HashSet<Path> {
+    fn expected() -> Option<Cow<'static, str>> {
+        Some(Cow::Borrowed("a list of paths"))
+    }
+
     fn from_list(nested: &[NestedMeta]) -> Result<Self> {
         let items = nested.iter().map(|item| match *item {
             NestedMeta::Meta(ref inner) => Ok(inner.require_path_only()?),
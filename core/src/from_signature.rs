@@ -0,0 +1,29 @@
+use syn::Signature;
+
+use crate::Result;
+
+/// Creates an instance by parsing a function signature.
+///
+/// Signatures do not carry attributes of their own, so implementations typically read the
+/// identifier, arguments, return type, and qualifiers of the function.
+pub trait FromSignature: Sized {
+    fn from_signature(sig: &Signature) -> Result<Self>;
+}
+
+impl FromSignature for () {
+    fn from_signature(_: &Signature) -> Result<Self> {
+        Ok(())
+    }
+}
+
+impl FromSignature for Signature {
+    fn from_signature(sig: &Signature) -> Result<Self> {
+        Ok(sig.clone())
+    }
+}
+
+impl FromSignature for syn::Ident {
+    fn from_signature(sig: &Signature) -> Result<Self> {
+        Ok(sig.ident.clone())
+    }
+}
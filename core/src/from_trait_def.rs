@@ -0,0 +1,34 @@
+use syn::ItemTrait;
+
+use crate::Result;
+
+/// Creates an instance by parsing a trait definition and its attributes.
+///
+/// Derived implementations can read each method of the trait using [`FromTraitItemFn`](crate::FromTraitItemFn).
+pub trait FromTraitDef: Sized {
+    fn from_trait_def(item: &ItemTrait) -> Result<Self>;
+}
+
+impl FromTraitDef for () {
+    fn from_trait_def(_: &ItemTrait) -> Result<Self> {
+        Ok(())
+    }
+}
+
+impl FromTraitDef for ItemTrait {
+    fn from_trait_def(item: &ItemTrait) -> Result<Self> {
+        Ok(item.clone())
+    }
+}
+
+impl FromTraitDef for syn::Ident {
+    fn from_trait_def(item: &ItemTrait) -> Result<Self> {
+        Ok(item.ident.clone())
+    }
+}
+
+impl FromTraitDef for Vec<syn::Attribute> {
+    fn from_trait_def(item: &ItemTrait) -> Result<Self> {
+        Ok(item.attrs.clone())
+    }
+}
@@ -0,0 +1,38 @@
+use syn::TraitItemFn;
+
+use crate::Result;
+
+/// Creates an instance by parsing a method declared in a trait definition, along with its attributes.
+pub trait FromTraitItemFn: Sized {
+    fn from_trait_item_fn(item: &TraitItemFn) -> Result<Self>;
+}
+
+impl FromTraitItemFn for () {
+    fn from_trait_item_fn(_: &TraitItemFn) -> Result<Self> {
+        Ok(())
+    }
+}
+
+impl FromTraitItemFn for TraitItemFn {
+    fn from_trait_item_fn(item: &TraitItemFn) -> Result<Self> {
+        Ok(item.clone())
+    }
+}
+
+impl FromTraitItemFn for syn::Signature {
+    fn from_trait_item_fn(item: &TraitItemFn) -> Result<Self> {
+        Ok(item.sig.clone())
+    }
+}
+
+impl FromTraitItemFn for syn::Ident {
+    fn from_trait_item_fn(item: &TraitItemFn) -> Result<Self> {
+        Ok(item.sig.ident.clone())
+    }
+}
+
+impl FromTraitItemFn for Vec<syn::Attribute> {
+    fn from_trait_item_fn(item: &TraitItemFn) -> Result<Self> {
+        Ok(item.attrs.clone())
+    }
+}
@@ -13,31 +13,59 @@ mod macros_public;
 pub mod ast;
 pub(crate) mod codegen;
 pub mod derive;
+pub mod docs;
 pub mod error;
 mod from_attributes;
+mod from_const_param;
 mod from_derive_input;
 mod from_field;
+mod from_fn;
+mod from_fn_arg;
 mod from_generic_param;
 mod from_generics;
+mod from_impl_block;
+mod from_impl_item_fn;
+mod from_lifetime_param;
 mod from_meta;
+mod from_signature;
+mod from_trait_def;
+mod from_trait_item_fn;
 mod from_type_param;
 mod from_variant;
 pub(crate) mod options;
+pub mod schema;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod to_meta;
 pub mod usage;
 pub mod util;
+pub mod warning;
 
 pub use self::error::{Error, Result};
 pub use self::from_attributes::FromAttributes;
+pub use self::from_const_param::FromConstParam;
 pub use self::from_derive_input::FromDeriveInput;
 pub use self::from_field::FromField;
+pub use self::from_fn::FromFn;
+pub use self::from_fn_arg::FromFnArg;
 pub use self::from_generic_param::FromGenericParam;
 pub use self::from_generics::FromGenerics;
+pub use self::from_impl_block::FromImplBlock;
+pub use self::from_impl_item_fn::FromImplItemFn;
+pub use self::from_lifetime_param::FromLifetimeParam;
 pub use self::from_meta::FromMeta;
+pub use self::from_signature::FromSignature;
+pub use self::from_trait_def::FromTraitDef;
+pub use self::from_trait_item_fn::FromTraitItemFn;
 pub use self::from_type_param::FromTypeParam;
 pub use self::from_variant::FromVariant;
+pub use self::to_meta::ToMeta;
+pub use self::warning::{Warning, Warnings};
 
 #[doc(hidden)]
 pub use self::from_meta::autoref_specialization;
+#[doc(hidden)]
+pub use self::from_meta::from_meta_with_expected;
 
 // Re-exports
 #[doc(hidden)]
@@ -94,3 +94,54 @@ macro_rules! uses_lifetimes {
         }
     };
 }
+
+/// Read a `FromDeriveInput` receiver from the tokens after the type, panicking with every error
+/// if that fails. Returns the parsed receiver.
+///
+/// See [`testing`](crate::testing) for how spans are handled.
+///
+/// ```rust,ignore
+/// let opts = assert_parses!(Opts, #[my(a = 1)] struct S;);
+/// ```
+#[cfg(feature = "testing")]
+#[macro_export]
+macro_rules! assert_parses {
+    ($receiver:ty, $($input:tt)*) => {
+        $crate::testing::assert_parses::<$receiver>(stringify!($($input)*))
+    };
+}
+
+/// Check that a `FromDeriveInput` receiver fails to read the braced input, with the listed
+/// errors in order. Each error is given as a braced list of the properties to check, which
+/// are any of `kind`, `code`, `message` and `snippet`; see [`ExpectedError`](crate::testing::ExpectedError).
+///
+/// ```rust,ignore
+/// assert_errors!(Opts, { #[my(b = 1)] struct S; }, [
+///     { kind: "UnknownField", snippet: "b" },
+///     { code: "D0003", message: "Missing field `a`" },
+/// ]);
+/// ```
+#[cfg(feature = "testing")]
+#[macro_export]
+macro_rules! assert_errors {
+    ($receiver:ty, { $($input:tt)* }, [$({ $($property:ident : $value:expr),* $(,)? }),* $(,)?]) => {
+        $crate::testing::assert_errors::<$receiver>(
+            stringify!($($input)*),
+            &[$($crate::testing::ExpectedError::new()$(.$property($value))*),*],
+        )
+    };
+}
+
+/// Run a derive function on the tokens after it, and check that it generates a valid Rust file
+/// without any `compile_error!` invocations. Returns the parsed file.
+///
+/// ```rust,ignore
+/// assert_expands!(my_macro::expand, #[my(a = 1)] struct S;);
+/// ```
+#[cfg(feature = "testing")]
+#[macro_export]
+macro_rules! assert_expands {
+    ($derive:expr, $($input:tt)*) => {
+        $crate::testing::assert_expands($derive, stringify!($($input)*))
+    };
+}
@@ -0,0 +1,64 @@
+use crate::ast::NestedMeta;
+use crate::util::Flag;
+use crate::{Error, FromMeta, Result};
+
+/// An additional name that is accepted for a field or variant, declared with
+/// `#[darling(alias = "old_name")]` or `#[darling(alias(name = "old_name", hidden))]`.
+#[derive(Debug, Clone)]
+pub struct Alias {
+    pub name: String,
+    /// If set, the alias is accepted but is not suggested in "did you mean" errors.
+    pub hidden: bool,
+}
+
+impl FromMeta for Alias {
+    fn from_string(value: &str) -> Result<Self> {
+        Ok(Alias {
+            name: value.to_string(),
+            hidden: false,
+        })
+    }
+
+    fn from_list(items: &[NestedMeta]) -> Result<Self> {
+        let mut errors = Error::accumulator();
+        let mut name: Option<String> = None;
+        let mut hidden = Flag::default();
+
+        for item in items {
+            if let NestedMeta::Meta(ref mi) = *item {
+                let path = mi.path();
+                if path.is_ident("name") {
+                    if name.is_some() {
+                        errors.push(Error::duplicate_field("name").with_span(mi));
+                    } else {
+                        name = errors.handle(FromMeta::from_meta(mi));
+                    }
+                } else if path.is_ident("hidden") {
+                    if hidden.is_present() {
+                        errors.push(Error::duplicate_field("hidden").with_span(mi));
+                    } else {
+                        hidden = errors.handle(FromMeta::from_meta(mi)).unwrap_or_default();
+                    }
+                } else {
+                    errors.push(
+                        Error::unknown_field_path_with_alts(path, &["name", "hidden"])
+                            .with_span(mi),
+                    );
+                }
+            } else {
+                errors.push(Error::unsupported_format("literal").with_span(item));
+            }
+        }
+
+        if name.is_none() {
+            errors.push(Error::missing_field("name"));
+        }
+
+        errors.finish()?;
+
+        Ok(Alias {
+            name: name.expect("Missing name was already checked"),
+            hidden: hidden.is_present(),
+        })
+    }
+}
@@ -0,0 +1,27 @@
+use syn::{ExprRange, LitStr};
+
+use crate::util::Flag;
+
+/// Declarative checks on the parsed value of a field, declared with `#[darling(range = ...)]`,
+/// `#[darling(len = ...)]`, `#[darling(non_empty)]` and `#[darling(one_of(...))]`.
+#[derive(Debug, Clone, Default)]
+pub struct Constraints {
+    /// The range the value must be within.
+    pub range: Option<ExprRange>,
+    /// The range the length of the value must be within.
+    pub len: Option<ExprRange>,
+    /// Whether the value must have a length of at least one.
+    pub non_empty: Flag,
+    /// The strings which are allowed as the value.
+    pub one_of: Option<Vec<LitStr>>,
+}
+
+impl Constraints {
+    /// Check if no constraints were declared.
+    pub fn is_empty(&self) -> bool {
+        self.range.is_none()
+            && self.len.is_none()
+            && !self.non_empty.is_present()
+            && self.one_of.is_none()
+    }
+}
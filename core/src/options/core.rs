@@ -1,11 +1,17 @@
+use std::borrow::Cow;
+
 use ident_case::RenameRule;
+use quote::{format_ident, ToTokens};
+use syn::spanned::Spanned;
 
 use crate::ast::{Data, Fields, Style};
 use crate::codegen;
 use crate::codegen::PostfixTransform;
 use crate::error::Accumulator;
-use crate::options::{DefaultExpression, InputField, InputVariant, ParseAttribute, ParseData};
-use crate::util::Flag;
+use crate::options::{
+    doc_comment, relations, DefaultExpression, InputField, InputVariant, ParseAttribute, ParseData,
+};
+use crate::util::{Callable, Flag};
 use crate::{Error, FromMeta, Result};
 
 /// A struct or enum which should have `FromMeta` or `FromDeriveInput` implementations
@@ -37,6 +43,10 @@ pub struct Core {
     /// which the two are applied.
     pub post_transform: Option<codegen::PostfixTransform>,
 
+    /// A function which receives the finished value and an error accumulator, used for
+    /// checks that span several fields. It runs after `map` or `and_then`.
+    pub validate: Option<Callable>,
+
     /// The body of the _deriving_ type.
     pub data: Data<InputVariant, InputField>,
 
@@ -48,6 +58,9 @@ pub struct Core {
 
     /// Use implementation of the inner type
     pub transparent: Flag,
+
+    /// The doc comment of the type, if any.
+    pub doc: Option<String>,
 }
 
 impl Core {
@@ -67,9 +80,11 @@ impl Core {
                 Default::default()
             },
             post_transform: Default::default(),
+            validate: Default::default(),
             bound: Default::default(),
             allow_unknown_fields: Default::default(),
             transparent: Default::default(),
+            doc: doc_comment(&di.attrs),
         })
     }
 
@@ -121,6 +136,12 @@ impl ParseAttribute for Core {
 
             self.post_transform =
                 Some(PostfixTransform::new(transformer, FromMeta::from_meta(mi)?));
+        } else if path.is_ident("validate") {
+            if self.validate.is_some() {
+                return Err(Error::duplicate_field("validate").with_span(mi));
+            }
+
+            self.validate = Some(FromMeta::from_meta(mi)?);
         } else if path.is_ident("bound") {
             self.bound = FromMeta::from_meta(mi)?;
         } else if path.is_ident("allow_unknown_fields") {
@@ -163,13 +184,26 @@ impl ParseData for Core {
     }
 
     fn parse_field(&mut self, field: &syn::Field) -> Result<()> {
-        let f = InputField::from_field(field, Some(self))?;
+        let mut f = InputField::from_field(field, Some(self))?;
 
         match self.data {
             Data::Struct(Fields {
                 style: Style::Unit, ..
             }) => panic!("Core::parse_field should not be called on unit"),
             Data::Struct(Fields { ref mut fields, .. }) => {
+                // Fields of tuple structs are always positional, and need distinct names
+                // for the local variables that hold them during parsing.
+                if field.ident.is_none() {
+                    let index = fields.len();
+                    f.ident = format_ident!("__{}", index);
+                    f.member = syn::Member::Unnamed(index.into());
+                    f.attr_name = Some(index.to_string());
+                }
+
+                if field.ident.is_none() || f.positional.is_present() {
+                    f.position = Some(fields.iter().filter(|f| f.position.is_some()).count());
+                }
+
                 fields.push(f);
                 Ok(())
             }
@@ -186,44 +220,217 @@ impl ParseData for Core {
                 );
             }
 
-            let flatten_targets: Vec<_> = fields
-                .iter()
-                .filter_map(|field| {
-                    if field.flatten.is_present() {
-                        Some(field.flatten)
-                    } else {
-                        None
-                    }
-                })
-                .collect();
+            for field in fields.iter().filter(|f| f.position.is_some()) {
+                let span = if field.positional.is_present() {
+                    field.positional.span()
+                } else {
+                    field.ty.span()
+                };
+
+                if fields.style.is_tuple() && field.positional.is_present() {
+                    errors.push(
+                        Error::custom("Fields of tuple structs are always positional")
+                            .with_span(&span),
+                    );
+                }
+
+                if let Some(with) = &field.with {
+                    errors.push(
+                        Error::custom("`with` cannot be used on positional fields").with_span(with),
+                    );
+                }
+
+                if field.flatten.is_present() {
+                    errors.push(
+                        Error::custom("Positional fields cannot be flattened").with_span(&span),
+                    );
+                }
+
+                if field.multiple.unwrap_or_default() {
+                    errors.push(
+                        Error::custom("Positional fields cannot be `multiple`").with_span(&span),
+                    );
+                }
+
+                if field.skip.map(|v| *v).unwrap_or_default() {
+                    errors.push(
+                        Error::custom("Positional fields cannot be skipped").with_span(&span),
+                    );
+                }
+            }
+
+            for field in fields.iter().filter(|f| !f.constraints.is_empty()) {
+                let span = field.ty.span();
+                if field.flatten.is_present() {
+                    errors.push(
+                        Error::custom("Flattened fields cannot have `range`, `len`, `non_empty` or `one_of` constraints")
+                            .with_span(&span),
+                    );
+                }
+
+                if field.skip.map(|v| *v).unwrap_or_default() {
+                    errors.push(
+                        Error::custom("Skipped fields cannot have `range`, `len`, `non_empty` or `one_of` constraints")
+                            .with_span(&span),
+                    );
+                }
+            }
+
+            for field in fields.iter().filter(|f| f.one_of_keys.is_present()) {
+                let span = field.one_of_keys.span();
+                let conflicts = [
+                    ("flatten", field.flatten.is_present()),
+                    ("multiple", field.multiple.unwrap_or_default()),
+                    ("skip", field.skip.map(|v| *v).unwrap_or_default()),
+                    ("positional", field.position.is_some()),
+                    ("with", field.with.is_some()),
+                    ("alias", !field.aliases.is_empty()),
+                ];
 
-            if flatten_targets.len() > 1 {
-                for flatten in flatten_targets {
+                for (option, _) in conflicts.iter().filter(|(_, present)| *present) {
                     errors.push(
-                        Error::custom("`#[darling(flatten)]` can only be applied to one field")
-                            .with_span(&flatten.span()),
+                        Error::custom(format!(
+                            "`one_of_keys` and `{}` cannot be used together",
+                            option
+                        ))
+                        .with_span(&span),
+                    );
+                }
+            }
+        }
+
+        match &self.data {
+            Data::Struct(fields) => {
+                validate_keys(
+                    fields.iter().map(|field| (&field.ident, field.keys())),
+                    "field",
+                    errors,
+                );
+                validate_flatten(fields, errors);
+                relations::validate(fields, errors);
+            }
+            Data::Enum(variants) => {
+                validate_keys(
+                    variants
+                        .iter()
+                        .map(|variant| (&variant.ident, variant.keys())),
+                    "variant",
+                    errors,
+                );
+                for variant in variants {
+                    validate_keys(
+                        variant
+                            .fields()
+                            .iter()
+                            .map(|field| (&field.ident, field.keys())),
+                        "field",
+                        errors,
                     );
+                    validate_flatten(variant.fields(), errors);
+                    relations::validate(variant.fields(), errors);
                 }
             }
         }
     }
 }
 
+/// Check that each name or alias selects only one field or variant. Otherwise, which one
+/// receives the input would depend on the order of the generated match arms.
+fn validate_keys<'a, I>(items: I, kind: &str, errors: &mut Accumulator)
+where
+    I: IntoIterator<Item = (&'a syn::Ident, Vec<Cow<'a, str>>)>,
+{
+    let mut seen: Vec<(Cow<'a, str>, &syn::Ident)> = Vec::new();
+    for (ident, keys) in items {
+        for key in keys {
+            match seen.iter().find(|(other, _)| *other == key) {
+                Some((_, other)) if *other == ident => errors.push(
+                    Error::custom(format!(
+                        "Duplicate name `{}`: `{}` already accepts it",
+                        key, ident
+                    ))
+                    .with_span(ident),
+                ),
+                Some((_, other)) => errors.push(
+                    Error::custom(format!(
+                        "Duplicate name `{}`: it is already accepted by the {} `{}`",
+                        key, kind, other
+                    ))
+                    .with_span(ident),
+                ),
+                None => seen.push((key, ident)),
+            }
+        }
+    }
+}
+
+/// Check the `flatten` and `catch_all` options of the fields of a struct or variant.
+fn validate_flatten(fields: &Fields<InputField>, errors: &mut Accumulator) {
+    for field in fields.iter().filter(|f| f.catch_all.is_present()) {
+        if !field.flatten.is_present() {
+            errors.push(
+                Error::custom("`catch_all` can only be used with `flatten`")
+                    .with_span(&field.catch_all.span()),
+            );
+        }
+    }
+
+    let catch_alls: Vec<_> = fields.iter().filter(|f| f.catch_all.is_present()).collect();
+    if catch_alls.len() > 1 {
+        for field in catch_alls {
+            errors.push(
+                Error::custom("`catch_all` can only be applied to one field")
+                    .with_span(&field.catch_all.span()),
+            );
+        }
+    }
+
+    // Fields of the same type claim the same keys, so any key given for one of them
+    // would be ambiguous. Other overlaps depend on the types' keys, and are reported
+    // when the key is used.
+    let flattened: Vec<_> = fields.iter().filter(|f| f.flatten.is_present()).collect();
+    for (index, field) in flattened.iter().enumerate() {
+        let ty = field.ty.to_token_stream().to_string();
+        if let Some(earlier) = flattened[..index]
+            .iter()
+            .find(|earlier| earlier.ty.to_token_stream().to_string() == ty)
+        {
+            if !field.catch_all.is_present() && !earlier.catch_all.is_present() {
+                errors.push(
+                    Error::custom(format!(
+                        "`{}` has the same type as `{}`, so both would claim the same keys",
+                        field.ident, earlier.ident
+                    ))
+                    .with_span(&field.ty),
+                );
+            }
+        }
+    }
+}
+
 impl<'a> From<&'a Core> for codegen::TraitImpl<'a> {
     fn from(v: &'a Core) -> Self {
+        let mut data = v
+            .data
+            .as_ref()
+            .map_struct_fields(InputField::as_codegen_field)
+            .map_enum_variants(|variant| variant.as_codegen_variant(&v.ident));
+        if let Data::Struct(ref mut fields) = data {
+            codegen::Field::track_related_keys(&mut fields.fields);
+            codegen::Field::resolve_catch_all(&mut fields.fields);
+        }
+
         codegen::TraitImpl {
             ident: &v.ident,
             generics: &v.generics,
-            data: v
-                .data
-                .as_ref()
-                .map_struct_fields(InputField::as_codegen_field)
-                .map_enum_variants(|variant| variant.as_codegen_variant(&v.ident)),
+            data,
             default: v.as_codegen_default(),
             post_transform: v.post_transform.as_ref(),
+            validate: v.validate.as_ref(),
             allow_unknown_fields: v.allow_unknown_fields.unwrap_or_default(),
             transparent: v.transparent.is_present(),
             krate: v.krate.as_ref(),
+            doc: v.doc.as_deref(),
         }
     }
 }
@@ -1,6 +1,6 @@
 use quote::ToTokens;
 
-use crate::{codegen::FromAttributesImpl, Error, Result};
+use crate::{ast::Data, codegen::FromAttributesImpl, util::Flag, Error, FromMeta, Result};
 
 use super::{OuterFrom, ParseAttribute, ParseData};
 
@@ -9,16 +9,23 @@ pub struct FromAttributesOptions {
     // Note: FromAttributes has no behaviors beyond those common
     // to all the `OuterFrom` traits.
     pub base: OuterFrom,
+    /// Whether to generate a `schema` function describing the accepted input.
+    pub schema: Flag,
 }
 
 impl FromAttributesOptions {
     pub fn new(di: &syn::DeriveInput) -> Result<Self> {
         let opts = (Self {
             base: OuterFrom::start(di)?,
+            schema: Flag::default(),
         })
         .parse_attributes(&di.attrs)?
         .parse_body(&di.data)?;
 
+        if opts.base.container.data.is_enum() {
+            return Ok(opts);
+        }
+
         let is_transparent = opts
             .base
             .container
@@ -42,13 +49,25 @@ impl FromAttributesOptions {
 
 impl ParseAttribute for FromAttributesOptions {
     fn parse_nested(&mut self, mi: &syn::Meta) -> Result<()> {
-        self.base.parse_nested(mi)
+        let path = mi.path();
+        if path.is_ident("schema") {
+            if self.schema.is_present() {
+                return Err(Error::duplicate_field_path(path).with_span(path));
+            }
+
+            self.schema = FromMeta::from_meta(mi)?;
+            Ok(())
+        } else {
+            self.base.parse_nested(mi)
+        }
     }
 }
 
 impl ParseData for FromAttributesOptions {
     fn parse_variant(&mut self, variant: &syn::Variant) -> Result<()> {
-        self.base.parse_variant(variant)
+        // Variants of a `FromAttributes` enum are read like those of a `FromMeta` enum;
+        // there are no magic fields to forward.
+        self.base.container.parse_variant(variant)
     }
 
     fn parse_field(&mut self, field: &syn::Field) -> Result<()> {
@@ -57,6 +76,32 @@ impl ParseData for FromAttributesOptions {
 
     fn validate_body(&self, errors: &mut crate::error::Accumulator) {
         self.base.validate_body(errors);
+
+        if let Data::Enum(ref variants) = self.base.container.data {
+            if !self.base.attr_names.is_empty() {
+                errors.push(Error::custom(
+                    "`attributes` cannot be used on enums; each variant consumes the attribute matching its name. Use `#[darling(rename = \"...\")]` on a variant to change that name",
+                ));
+            }
+
+            if self.base.forward_attrs.is_some() {
+                errors.push(Error::custom("`forward_attrs` cannot be used on enums"));
+            }
+
+            for variant in variants {
+                if variant.is_skipped() {
+                    continue;
+                }
+
+                let fields = variant.fields();
+                if fields.style.is_tuple() && fields.len() > 1 {
+                    errors.push(
+                        Error::custom("Tuple variants with more than one field are not supported")
+                            .with_span(&variant.ident),
+                    );
+                }
+            }
+        }
     }
 }
 
@@ -66,6 +111,7 @@ impl<'a> From<&'a FromAttributesOptions> for FromAttributesImpl<'a> {
             base: (&v.base.container).into(),
             attr_names: &v.base.attr_names,
             forward_attrs: v.base.as_forward_attrs(),
+            schema: v.schema.is_present(),
         }
     }
 }
@@ -0,0 +1,76 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::Ident;
+
+use crate::codegen::FromConstParamImpl;
+use crate::options::{OuterFrom, ParseAttribute, ParseData};
+use crate::Result;
+
+#[derive(Debug)]
+pub struct FromConstParamOptions {
+    pub base: OuterFrom,
+    pub ty: Option<Ident>,
+    pub default: Option<Ident>,
+}
+
+impl FromConstParamOptions {
+    pub fn new(di: &syn::DeriveInput) -> Result<Self> {
+        (FromConstParamOptions {
+            base: OuterFrom::start(di)?,
+            ty: None,
+            default: None,
+        })
+        .parse_attributes(&di.attrs)?
+        .parse_body(&di.data)
+    }
+}
+
+impl ParseAttribute for FromConstParamOptions {
+    fn parse_nested(&mut self, mi: &syn::Meta) -> Result<()> {
+        self.base.parse_nested(mi)
+    }
+}
+
+impl ParseData for FromConstParamOptions {
+    fn parse_variant(&mut self, variant: &syn::Variant) -> Result<()> {
+        self.base.parse_variant(variant)
+    }
+
+    fn parse_field(&mut self, field: &syn::Field) -> Result<()> {
+        match field.ident.as_ref().map(|v| v.to_string()).as_deref() {
+            Some("ty") => {
+                self.ty.clone_from(&field.ident);
+                Ok(())
+            }
+            Some("default") => {
+                self.default.clone_from(&field.ident);
+                Ok(())
+            }
+            _ => self.base.parse_field(field),
+        }
+    }
+
+    fn validate_body(&self, errors: &mut crate::error::Accumulator) {
+        self.base.validate_body(errors);
+    }
+}
+
+impl<'a> From<&'a FromConstParamOptions> for FromConstParamImpl<'a> {
+    fn from(v: &'a FromConstParamOptions) -> Self {
+        FromConstParamImpl {
+            base: (&v.base.container).into(),
+            ident: v.base.ident.as_ref(),
+            ty: v.ty.as_ref(),
+            default: v.default.as_ref(),
+            attr_names: &v.base.attr_names,
+            forward_attrs: v.base.as_forward_attrs(),
+            from_ident: v.base.from_ident,
+        }
+    }
+}
+
+impl ToTokens for FromConstParamOptions {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        FromConstParamImpl::from(self).to_tokens(tokens)
+    }
+}
@@ -4,7 +4,8 @@ use syn::Ident;
 
 use crate::codegen::FromDeriveInputImpl;
 use crate::options::{DeriveInputShapeSet, OuterFrom, ParseAttribute, ParseData};
-use crate::{FromField, FromMeta, Result};
+use crate::util::Flag;
+use crate::{Error, FromField, FromMeta, Result};
 
 use super::forwarded_field::ForwardedField;
 
@@ -22,6 +23,9 @@ pub struct FdiOptions {
     pub data: Option<ForwardedField>,
 
     pub supports: Option<DeriveInputShapeSet>,
+
+    /// Whether to generate a `schema` function describing the accepted input.
+    pub schema: Flag,
 }
 
 impl FdiOptions {
@@ -32,6 +36,7 @@ impl FdiOptions {
             generics: Default::default(),
             data: Default::default(),
             supports: Default::default(),
+            schema: Flag::default(),
         })
         .parse_attributes(&di.attrs)?
         .parse_body(&di.data)
@@ -40,9 +45,17 @@ impl FdiOptions {
 
 impl ParseAttribute for FdiOptions {
     fn parse_nested(&mut self, mi: &syn::Meta) -> Result<()> {
-        if mi.path().is_ident("supports") {
+        let path = mi.path();
+        if path.is_ident("supports") {
             self.supports = FromMeta::from_meta(mi)?;
             Ok(())
+        } else if path.is_ident("schema") {
+            if self.schema.is_present() {
+                return Err(Error::duplicate_field_path(path).with_span(path));
+            }
+
+            self.schema = FromMeta::from_meta(mi)?;
+            Ok(())
         } else {
             self.base.parse_nested(mi)
         }
@@ -89,6 +102,7 @@ impl<'a> From<&'a FdiOptions> for FromDeriveInputImpl<'a> {
             generics: v.generics.as_ref(),
             forward_attrs: v.base.as_forward_attrs(),
             supports: v.supports.as_ref(),
+            schema: v.schema.is_present(),
         }
     }
 }
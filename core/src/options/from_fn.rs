@@ -0,0 +1,102 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::Ident;
+
+use crate::codegen::{FnKind, FromFnImpl};
+use crate::options::{ForwardedField, OuterFrom, ParseAttribute, ParseData, SignatureFields};
+use crate::{FromField, Result};
+
+/// Options for deriving `FromFn`, `FromImplItemFn`, or `FromTraitItemFn`.
+#[derive(Debug)]
+pub struct FromFnOptions {
+    pub kind: FnKind,
+
+    pub base: OuterFrom,
+
+    /// The field on the target struct which should receive the function visibility, if any.
+    pub vis: Option<Ident>,
+
+    /// The field on the target struct which should receive the parsed signature, if any.
+    pub sig: Option<ForwardedField>,
+
+    /// The field on the target struct which should receive the function body, if any.
+    pub block: Option<Ident>,
+
+    pub sig_fields: SignatureFields,
+}
+
+impl FromFnOptions {
+    pub fn new(di: &syn::DeriveInput, kind: FnKind) -> Result<Self> {
+        (FromFnOptions {
+            kind,
+            base: OuterFrom::start(di)?,
+            vis: None,
+            sig: None,
+            block: None,
+            sig_fields: Default::default(),
+        })
+        .parse_attributes(&di.attrs)?
+        .parse_body(&di.data)
+    }
+}
+
+impl ParseAttribute for FromFnOptions {
+    fn parse_nested(&mut self, mi: &syn::Meta) -> Result<()> {
+        self.base.parse_nested(mi)
+    }
+}
+
+impl ParseData for FromFnOptions {
+    fn parse_variant(&mut self, variant: &syn::Variant) -> Result<()> {
+        self.base.parse_variant(variant)
+    }
+
+    fn parse_field(&mut self, field: &syn::Field) -> Result<()> {
+        if self.sig_fields.parse_field(field)? {
+            return Ok(());
+        }
+
+        match field.ident.as_ref().map(|v| v.to_string()).as_deref() {
+            Some("vis") if self.kind.has_vis() => {
+                self.vis.clone_from(&field.ident);
+                Ok(())
+            }
+            Some("sig") => {
+                self.sig = ForwardedField::from_field(field).map(Some)?;
+                Ok(())
+            }
+            Some("block") => {
+                self.block.clone_from(&field.ident);
+                Ok(())
+            }
+            _ => self.base.parse_field(field),
+        }
+    }
+
+    fn validate_body(&self, errors: &mut crate::error::Accumulator) {
+        self.base.validate_body(errors);
+    }
+}
+
+impl<'a> From<&'a FromFnOptions> for FromFnImpl<'a> {
+    fn from(v: &'a FromFnOptions) -> Self {
+        FromFnImpl {
+            kind: v.kind,
+            base: (&v.base.container).into(),
+            ident: v.base.ident.as_ref(),
+            vis: v.vis.as_ref(),
+            sig: v.sig.as_ref(),
+            block: v.block.as_ref(),
+            sig_fields: v.sig_fields.as_codegen(),
+            attr_names: &v.base.attr_names,
+            forward_attrs: v.base.as_forward_attrs(),
+            from_ident: v.base.from_ident,
+        }
+    }
+}
+
+impl ToTokens for FromFnOptions {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        FromFnImpl::from(self).to_tokens(tokens)
+    }
+}
@@ -0,0 +1,76 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::Ident;
+
+use crate::codegen::FromFnArgImpl;
+use crate::options::{OuterFrom, ParseAttribute, ParseData};
+use crate::Result;
+
+#[derive(Debug)]
+pub struct FromFnArgOptions {
+    pub base: OuterFrom,
+    pub pat: Option<Ident>,
+    pub ty: Option<Ident>,
+}
+
+impl FromFnArgOptions {
+    pub fn new(di: &syn::DeriveInput) -> Result<Self> {
+        (FromFnArgOptions {
+            base: OuterFrom::start(di)?,
+            pat: None,
+            ty: None,
+        })
+        .parse_attributes(&di.attrs)?
+        .parse_body(&di.data)
+    }
+}
+
+impl ParseAttribute for FromFnArgOptions {
+    fn parse_nested(&mut self, mi: &syn::Meta) -> Result<()> {
+        self.base.parse_nested(mi)
+    }
+}
+
+impl ParseData for FromFnArgOptions {
+    fn parse_variant(&mut self, variant: &syn::Variant) -> Result<()> {
+        self.base.parse_variant(variant)
+    }
+
+    fn parse_field(&mut self, field: &syn::Field) -> Result<()> {
+        match field.ident.as_ref().map(|v| v.to_string()).as_deref() {
+            Some("pat") => {
+                self.pat.clone_from(&field.ident);
+                Ok(())
+            }
+            Some("ty") => {
+                self.ty.clone_from(&field.ident);
+                Ok(())
+            }
+            _ => self.base.parse_field(field),
+        }
+    }
+
+    fn validate_body(&self, errors: &mut crate::error::Accumulator) {
+        self.base.validate_body(errors);
+    }
+}
+
+impl<'a> From<&'a FromFnArgOptions> for FromFnArgImpl<'a> {
+    fn from(v: &'a FromFnArgOptions) -> Self {
+        FromFnArgImpl {
+            base: (&v.base.container).into(),
+            ident: v.base.ident.as_ref(),
+            pat: v.pat.as_ref(),
+            ty: v.ty.as_ref(),
+            attr_names: &v.base.attr_names,
+            forward_attrs: v.base.as_forward_attrs(),
+            from_ident: v.base.from_ident,
+        }
+    }
+}
+
+impl ToTokens for FromFnArgOptions {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        FromFnArgImpl::from(self).to_tokens(tokens)
+    }
+}
@@ -0,0 +1,59 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+
+use crate::codegen::FromGenericParamImpl;
+use crate::options::{OuterFrom, ParseAttribute, ParseData};
+use crate::Result;
+
+#[derive(Debug)]
+pub struct FromGenericParamOptions {
+    pub base: OuterFrom,
+}
+
+impl FromGenericParamOptions {
+    pub fn new(di: &syn::DeriveInput) -> Result<Self> {
+        (FromGenericParamOptions {
+            base: OuterFrom::start(di)?,
+        })
+        .parse_attributes(&di.attrs)?
+        .parse_body(&di.data)
+    }
+}
+
+impl ParseAttribute for FromGenericParamOptions {
+    fn parse_nested(&mut self, mi: &syn::Meta) -> Result<()> {
+        self.base.parse_nested(mi)
+    }
+}
+
+impl ParseData for FromGenericParamOptions {
+    fn parse_variant(&mut self, variant: &syn::Variant) -> Result<()> {
+        self.base.parse_variant(variant)
+    }
+
+    fn parse_field(&mut self, field: &syn::Field) -> Result<()> {
+        self.base.parse_field(field)
+    }
+
+    fn validate_body(&self, errors: &mut crate::error::Accumulator) {
+        self.base.validate_body(errors);
+    }
+}
+
+impl<'a> From<&'a FromGenericParamOptions> for FromGenericParamImpl<'a> {
+    fn from(v: &'a FromGenericParamOptions) -> Self {
+        FromGenericParamImpl {
+            base: (&v.base.container).into(),
+            ident: v.base.ident.as_ref(),
+            attr_names: &v.base.attr_names,
+            forward_attrs: v.base.as_forward_attrs(),
+            from_ident: v.base.from_ident,
+        }
+    }
+}
+
+impl ToTokens for FromGenericParamOptions {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        FromGenericParamImpl::from(self).to_tokens(tokens)
+    }
+}
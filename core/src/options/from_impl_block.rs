@@ -0,0 +1,102 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::Ident;
+
+use crate::codegen::FromImplBlockImpl;
+use crate::options::{ForwardedField, OuterFrom, ParseAttribute, ParseData};
+use crate::{Error, FromField, Result};
+
+#[derive(Debug)]
+pub struct FromImplBlockOptions {
+    pub base: OuterFrom,
+
+    /// The field on the target struct which should receive the implementing type, if any.
+    pub self_ty: Option<Ident>,
+
+    /// The field on the target struct which should receive the path of the implemented trait, if any.
+    pub trait_: Option<Ident>,
+
+    /// The field on the target struct which should receive the impl generics, if any.
+    pub generics: Option<ForwardedField>,
+
+    /// The field on the target struct which should receive the parsed methods, if any.
+    pub items: Option<ForwardedField>,
+}
+
+impl FromImplBlockOptions {
+    pub fn new(di: &syn::DeriveInput) -> Result<Self> {
+        (FromImplBlockOptions {
+            base: OuterFrom::start(di)?,
+            self_ty: None,
+            trait_: None,
+            generics: None,
+            items: None,
+        })
+        .parse_attributes(&di.attrs)?
+        .parse_body(&di.data)
+    }
+}
+
+impl ParseAttribute for FromImplBlockOptions {
+    fn parse_nested(&mut self, mi: &syn::Meta) -> Result<()> {
+        if mi.path().is_ident("from_ident") {
+            Err(Error::custom("impl blocks do not have an identifier").with_span(mi))
+        } else {
+            self.base.parse_nested(mi)
+        }
+    }
+}
+
+impl ParseData for FromImplBlockOptions {
+    fn parse_variant(&mut self, variant: &syn::Variant) -> Result<()> {
+        self.base.parse_variant(variant)
+    }
+
+    fn parse_field(&mut self, field: &syn::Field) -> Result<()> {
+        match field.ident.as_ref().map(|v| v.to_string()).as_deref() {
+            Some("self_ty") => {
+                self.self_ty.clone_from(&field.ident);
+                Ok(())
+            }
+            Some("trait_") => {
+                self.trait_.clone_from(&field.ident);
+                Ok(())
+            }
+            Some("generics") => {
+                self.generics = ForwardedField::from_field(field).map(Some)?;
+                Ok(())
+            }
+            Some("items") => {
+                self.items = ForwardedField::from_field(field).map(Some)?;
+                Ok(())
+            }
+            // Impl blocks have no identifier, so `ident` is not a magic field here.
+            Some("ident") => self.base.container.parse_field(field),
+            _ => self.base.parse_field(field),
+        }
+    }
+
+    fn validate_body(&self, errors: &mut crate::error::Accumulator) {
+        self.base.validate_body(errors);
+    }
+}
+
+impl<'a> From<&'a FromImplBlockOptions> for FromImplBlockImpl<'a> {
+    fn from(v: &'a FromImplBlockOptions) -> Self {
+        FromImplBlockImpl {
+            base: (&v.base.container).into(),
+            self_ty: v.self_ty.as_ref(),
+            trait_: v.trait_.as_ref(),
+            generics: v.generics.as_ref(),
+            items: v.items.as_ref(),
+            attr_names: &v.base.attr_names,
+            forward_attrs: v.base.as_forward_attrs(),
+        }
+    }
+}
+
+impl ToTokens for FromImplBlockOptions {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        FromImplBlockImpl::from(self).to_tokens(tokens)
+    }
+}
@@ -0,0 +1,76 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::Ident;
+
+use crate::codegen::FromLifetimeParamImpl;
+use crate::options::{OuterFrom, ParseAttribute, ParseData};
+use crate::Result;
+
+#[derive(Debug)]
+pub struct FromLifetimeParamOptions {
+    pub base: OuterFrom,
+    pub lifetime: Option<Ident>,
+    pub bounds: Option<Ident>,
+}
+
+impl FromLifetimeParamOptions {
+    pub fn new(di: &syn::DeriveInput) -> Result<Self> {
+        (FromLifetimeParamOptions {
+            base: OuterFrom::start(di)?,
+            lifetime: None,
+            bounds: None,
+        })
+        .parse_attributes(&di.attrs)?
+        .parse_body(&di.data)
+    }
+}
+
+impl ParseAttribute for FromLifetimeParamOptions {
+    fn parse_nested(&mut self, mi: &syn::Meta) -> Result<()> {
+        self.base.parse_nested(mi)
+    }
+}
+
+impl ParseData for FromLifetimeParamOptions {
+    fn parse_variant(&mut self, variant: &syn::Variant) -> Result<()> {
+        self.base.parse_variant(variant)
+    }
+
+    fn parse_field(&mut self, field: &syn::Field) -> Result<()> {
+        match field.ident.as_ref().map(|v| v.to_string()).as_deref() {
+            Some("lifetime") => {
+                self.lifetime.clone_from(&field.ident);
+                Ok(())
+            }
+            Some("bounds") => {
+                self.bounds.clone_from(&field.ident);
+                Ok(())
+            }
+            _ => self.base.parse_field(field),
+        }
+    }
+
+    fn validate_body(&self, errors: &mut crate::error::Accumulator) {
+        self.base.validate_body(errors);
+    }
+}
+
+impl<'a> From<&'a FromLifetimeParamOptions> for FromLifetimeParamImpl<'a> {
+    fn from(v: &'a FromLifetimeParamOptions) -> Self {
+        FromLifetimeParamImpl {
+            base: (&v.base.container).into(),
+            ident: v.base.ident.as_ref(),
+            lifetime: v.lifetime.as_ref(),
+            bounds: v.bounds.as_ref(),
+            attr_names: &v.base.attr_names,
+            forward_attrs: v.base.as_forward_attrs(),
+            from_ident: v.base.from_ident,
+        }
+    }
+}
+
+impl ToTokens for FromLifetimeParamOptions {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        FromLifetimeParamImpl::from(self).to_tokens(tokens)
+    }
+}
@@ -5,10 +5,10 @@ use quote::ToTokens;
 use syn::parse_quote;
 
 use crate::ast::Data;
-use crate::codegen::FromMetaImpl;
+use crate::codegen::{FromMetaImpl, Tagging, ToMetaImpl};
 use crate::error::Accumulator;
-use crate::options::{Core, ParseAttribute, ParseData};
-use crate::util::Callable;
+use crate::options::{Core, InputVariant, ParseAttribute, ParseData};
+use crate::util::{Callable, Flag, SpannedValue};
 use crate::{Error, FromMeta, Result};
 
 pub struct FromMetaOptions {
@@ -21,6 +21,14 @@ pub struct FromMetaOptions {
     from_expr: Option<Callable>,
     /// Whether or not to derive [`syn::parse::Parse`] in addition to deriving [`FromMeta`].
     derive_syn_parse: Option<bool>,
+    /// The key whose value names the variant of an internally-tagged enum.
+    tag: Option<SpannedValue<String>>,
+    /// Whether each variant of the enum should be tried in order, without any tag.
+    untagged: Flag,
+    /// Whether unit variants can also be selected by their integer discriminant.
+    discriminant: Flag,
+    /// Whether to generate a `schema` function describing the accepted input.
+    schema: Flag,
 }
 
 impl FromMetaOptions {
@@ -31,6 +39,10 @@ impl FromMetaOptions {
             from_none: None,
             from_expr: None,
             derive_syn_parse: None,
+            tag: None,
+            untagged: Flag::default(),
+            discriminant: Flag::default(),
+            schema: Flag::default(),
         })
         .parse_attributes(&di.attrs)?
         .parse_body(&di.data)
@@ -63,6 +75,17 @@ impl FromMetaOptions {
             }
         })
     }
+
+    /// Get how the variant of an enum is selected from the input.
+    fn tagging(&self) -> Tagging<'_> {
+        if let Some(tag) = &self.tag {
+            Tagging::Internal(tag.as_str())
+        } else if self.untagged.is_present() {
+            Tagging::Untagged
+        } else {
+            Tagging::External
+        }
+    }
 }
 
 impl ParseAttribute for FromMetaOptions {
@@ -93,6 +116,30 @@ impl ParseAttribute for FromMetaOptions {
             }
 
             self.derive_syn_parse = FromMeta::from_meta(mi).map(Some)?;
+        } else if path.is_ident("tag") {
+            if self.tag.is_some() {
+                return Err(Error::duplicate_field_path(path).with_span(path));
+            }
+
+            self.tag = FromMeta::from_meta(mi).map(Some)?;
+        } else if path.is_ident("untagged") {
+            if self.untagged.is_present() {
+                return Err(Error::duplicate_field_path(path).with_span(path));
+            }
+
+            self.untagged = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("discriminant") {
+            if self.discriminant.is_present() {
+                return Err(Error::duplicate_field_path(path).with_span(path));
+            }
+
+            self.discriminant = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("schema") {
+            if self.schema.is_present() {
+                return Err(Error::duplicate_field_path(path).with_span(path));
+            }
+
+            self.schema = FromMeta::from_meta(mi)?;
         } else {
             self.base.parse_nested(mi)?;
         }
@@ -113,8 +160,35 @@ impl ParseData for FromMetaOptions {
     fn validate_body(&self, errors: &mut Accumulator) {
         self.base.validate_body(errors);
 
+        if let (Some(tag), true) = (&self.tag, self.untagged.is_present()) {
+            errors.push(
+                Error::custom("`tag` and `untagged` cannot be used together")
+                    .with_span(&tag.span()),
+            );
+        }
+
         match self.base.data {
             Data::Struct(ref data) => {
+                if let Some(tag) = &self.tag {
+                    errors.push(
+                        Error::custom("`tag` can only be used on enums").with_span(&tag.span()),
+                    );
+                }
+
+                if self.discriminant.is_present() {
+                    errors.push(
+                        Error::custom("`discriminant` can only be used on enums")
+                            .with_span(&self.discriminant.span()),
+                    );
+                }
+
+                if self.untagged.is_present() {
+                    errors.push(
+                        Error::custom("`untagged` can only be used on enums")
+                            .with_span(&self.untagged.span()),
+                    );
+                }
+
                 if let Some(from_word) = &self.from_word {
                     if data.is_unit() {
                         errors.push(Error::custom("`from_word` cannot be used on unit structs because it conflicts with the generated impl").with_span(from_word));
@@ -160,6 +234,103 @@ impl ParseData for FromMetaOptions {
                     }
                 }
 
+                if self.discriminant.is_present() {
+                    let span = self.discriminant.span();
+                    if data.iter().any(|v| !v.is_unit_variant()) {
+                        errors.push(
+                            Error::custom("`discriminant` can only be used on enums whose variants are all unit variants")
+                                .with_span(&span),
+                        );
+                    }
+
+                    if self.untagged.is_present() {
+                        errors.push(
+                            Error::custom("`discriminant` cannot be used with `untagged` enums")
+                                .with_span(&span),
+                        );
+                    }
+
+                    if let Some(from_expr) = &self.from_expr {
+                        errors.push(
+                            Error::custom("`discriminant` cannot be used with `from_expr`")
+                                .with_span(from_expr),
+                        );
+                    }
+                }
+
+                let other_variants: Vec<_> = data.iter().filter(|v| v.other.is_present()).collect();
+                for variant in &other_variants {
+                    let span = variant.other.span();
+                    if other_variants.len() > 1 {
+                        errors.push(
+                            Error::custom("`#[darling(other)]` can only be applied to one variant")
+                                .with_span(&span),
+                        );
+                    }
+
+                    let fields = variant.fields();
+                    if !(fields.is_unit()
+                        || (fields.len() == 1
+                            && (fields.is_tuple() || variant.transparent.is_present())))
+                    {
+                        errors.push(
+                            Error::custom("`#[darling(other)]` can only be applied to a unit variant or a variant with a single field")
+                                .with_span(&span),
+                        );
+                    }
+
+                    if variant.is_skipped() {
+                        errors.push(
+                            Error::custom("`#[darling(other)]` cannot be used with `skip`")
+                                .with_span(&span),
+                        );
+                    }
+
+                    if self.untagged.is_present() {
+                        errors.push(
+                            Error::custom(
+                                "`#[darling(other)]` cannot be used with `untagged` enums",
+                            )
+                            .with_span(&span),
+                        );
+                    }
+                }
+
+                if self.untagged.is_present() {
+                    if let Some(from_word) = self.from_word() {
+                        errors.push(
+                            Error::custom("`from_word` and `word` cannot be used with `untagged` enums, which try each variant in order")
+                                .with_span(&*from_word),
+                        );
+                    }
+
+                    if let Some(from_expr) = &self.from_expr {
+                        errors.push(
+                            Error::custom("`from_expr` cannot be used with `untagged` enums, which try each variant in order")
+                                .with_span(from_expr),
+                        );
+                    }
+
+                    if data.iter().all(InputVariant::is_skipped) {
+                        errors.push(
+                            Error::custom("`untagged` enums must have at least one variant which is not skipped")
+                                .with_span(&self.untagged.span()),
+                        );
+                    }
+                }
+
+                if let Some(tag) = &self.tag {
+                    if data
+                        .iter()
+                        .any(|v| !v.is_skipped() && v.fields().is_tuple() && v.fields().len() > 1)
+                    {
+                        errors.push(
+                            Error::custom("`tag` cannot be used with multi-field tuple variants")
+                                .with_span(&tag.span()),
+                        );
+                    }
+                }
+
                 if let Some(from_expr) = &self.from_expr {
                     if data.iter().any(|v| v.is_unit_variant() && !v.is_skipped()) {
                         errors.push(
@@ -181,6 +352,18 @@ impl<'a> From<&'a FromMetaOptions> for FromMetaImpl<'a> {
             from_none: v.from_none.as_ref(),
             from_expr: v.from_expr.as_ref(),
             derive_syn_parse: v.derive_syn_parse.unwrap_or_default(),
+            tagging: v.tagging(),
+            discriminant: v.discriminant.is_present(),
+            schema: v.schema.is_present(),
+        }
+    }
+}
+
+impl<'a> From<&'a FromMetaOptions> for ToMetaImpl<'a> {
+    fn from(v: &'a FromMetaOptions) -> Self {
+        ToMetaImpl {
+            base: (&v.base).into(),
+            tagging: v.tagging(),
         }
     }
 }
@@ -0,0 +1,127 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::Ident;
+
+use crate::codegen::{self, FromSignatureImpl};
+use crate::options::{ForwardedField, OuterFrom, ParseAttribute, ParseData};
+use crate::{Error, FromField, Result};
+
+/// Magic fields which are read from a `syn::Signature`. These are shared by
+/// `FromSignature` and `FromFn`.
+#[derive(Debug, Default)]
+pub struct SignatureFields {
+    /// The field on the target struct which should receive the parsed arguments, if any.
+    pub inputs: Option<ForwardedField>,
+    pub output: Option<Ident>,
+    pub asyncness: Option<Ident>,
+    pub constness: Option<Ident>,
+    /// The field on the target struct which should receive the function generics, if any.
+    pub generics: Option<ForwardedField>,
+}
+
+impl SignatureFields {
+    /// Claim `field` if it is one of the signature's magic fields.
+    /// Returns `Ok(false)` if the field should be handled elsewhere.
+    pub fn parse_field(&mut self, field: &syn::Field) -> Result<bool> {
+        match field.ident.as_ref().map(|v| v.to_string()).as_deref() {
+            Some("inputs") => {
+                self.inputs = ForwardedField::from_field(field).map(Some)?;
+            }
+            Some("output") => {
+                self.output.clone_from(&field.ident);
+            }
+            Some("asyncness") => {
+                self.asyncness.clone_from(&field.ident);
+            }
+            Some("constness") => {
+                self.constness.clone_from(&field.ident);
+            }
+            Some("generics") => {
+                self.generics = ForwardedField::from_field(field).map(Some)?;
+            }
+            _ => return Ok(false),
+        }
+
+        Ok(true)
+    }
+
+    pub fn as_codegen(&self) -> codegen::SignatureFields<'_> {
+        codegen::SignatureFields {
+            inputs: self.inputs.as_ref(),
+            output: self.output.as_ref(),
+            asyncness: self.asyncness.as_ref(),
+            constness: self.constness.as_ref(),
+            generics: self.generics.as_ref(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct FromSignatureOptions {
+    pub base: OuterFrom,
+    pub sig: SignatureFields,
+}
+
+impl FromSignatureOptions {
+    pub fn new(di: &syn::DeriveInput) -> Result<Self> {
+        (FromSignatureOptions {
+            base: OuterFrom::start(di)?,
+            sig: Default::default(),
+        })
+        .parse_attributes(&di.attrs)?
+        .parse_body(&di.data)
+    }
+}
+
+impl ParseAttribute for FromSignatureOptions {
+    fn parse_nested(&mut self, mi: &syn::Meta) -> Result<()> {
+        let path = mi.path();
+        if path.is_ident("attributes") || path.is_ident("forward_attrs") {
+            Err(
+                Error::custom("function signatures do not have attributes; use `FromFn` instead")
+                    .with_span(path),
+            )
+        } else {
+            self.base.parse_nested(mi)
+        }
+    }
+}
+
+impl ParseData for FromSignatureOptions {
+    fn parse_variant(&mut self, variant: &syn::Variant) -> Result<()> {
+        self.base.parse_variant(variant)
+    }
+
+    fn parse_field(&mut self, field: &syn::Field) -> Result<()> {
+        if self.sig.parse_field(field)? {
+            return Ok(());
+        }
+
+        match field.ident.as_ref().map(|v| v.to_string()).as_deref() {
+            // Signatures have no attributes, so `attrs` is not a magic field here.
+            Some("attrs") => self.base.container.parse_field(field),
+            _ => self.base.parse_field(field),
+        }
+    }
+
+    fn validate_body(&self, errors: &mut crate::error::Accumulator) {
+        self.base.validate_body(errors);
+    }
+}
+
+impl<'a> From<&'a FromSignatureOptions> for FromSignatureImpl<'a> {
+    fn from(v: &'a FromSignatureOptions) -> Self {
+        FromSignatureImpl {
+            base: (&v.base.container).into(),
+            ident: v.base.ident.as_ref(),
+            sig: v.sig.as_codegen(),
+            from_ident: v.base.from_ident,
+        }
+    }
+}
+
+impl ToTokens for FromSignatureOptions {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        FromSignatureImpl::from(self).to_tokens(tokens)
+    }
+}
@@ -0,0 +1,98 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::Ident;
+
+use crate::codegen::FromTraitDefImpl;
+use crate::options::{ForwardedField, OuterFrom, ParseAttribute, ParseData};
+use crate::{FromField, Result};
+
+#[derive(Debug)]
+pub struct FromTraitDefOptions {
+    pub base: OuterFrom,
+
+    /// The field on the target struct which should receive the trait visibility, if any.
+    pub vis: Option<Ident>,
+
+    /// The field on the target struct which should receive the supertrait bounds, if any.
+    pub supertraits: Option<Ident>,
+
+    /// The field on the target struct which should receive the trait generics, if any.
+    pub generics: Option<ForwardedField>,
+
+    /// The field on the target struct which should receive the parsed methods, if any.
+    pub items: Option<ForwardedField>,
+}
+
+impl FromTraitDefOptions {
+    pub fn new(di: &syn::DeriveInput) -> Result<Self> {
+        (FromTraitDefOptions {
+            base: OuterFrom::start(di)?,
+            vis: None,
+            supertraits: None,
+            generics: None,
+            items: None,
+        })
+        .parse_attributes(&di.attrs)?
+        .parse_body(&di.data)
+    }
+}
+
+impl ParseAttribute for FromTraitDefOptions {
+    fn parse_nested(&mut self, mi: &syn::Meta) -> Result<()> {
+        self.base.parse_nested(mi)
+    }
+}
+
+impl ParseData for FromTraitDefOptions {
+    fn parse_variant(&mut self, variant: &syn::Variant) -> Result<()> {
+        self.base.parse_variant(variant)
+    }
+
+    fn parse_field(&mut self, field: &syn::Field) -> Result<()> {
+        match field.ident.as_ref().map(|v| v.to_string()).as_deref() {
+            Some("vis") => {
+                self.vis.clone_from(&field.ident);
+                Ok(())
+            }
+            Some("supertraits") => {
+                self.supertraits.clone_from(&field.ident);
+                Ok(())
+            }
+            Some("generics") => {
+                self.generics = ForwardedField::from_field(field).map(Some)?;
+                Ok(())
+            }
+            Some("items") => {
+                self.items = ForwardedField::from_field(field).map(Some)?;
+                Ok(())
+            }
+            _ => self.base.parse_field(field),
+        }
+    }
+
+    fn validate_body(&self, errors: &mut crate::error::Accumulator) {
+        self.base.validate_body(errors);
+    }
+}
+
+impl<'a> From<&'a FromTraitDefOptions> for FromTraitDefImpl<'a> {
+    fn from(v: &'a FromTraitDefOptions) -> Self {
+        FromTraitDefImpl {
+            base: (&v.base.container).into(),
+            ident: v.base.ident.as_ref(),
+            vis: v.vis.as_ref(),
+            supertraits: v.supertraits.as_ref(),
+            generics: v.generics.as_ref(),
+            items: v.items.as_ref(),
+            attr_names: &v.base.attr_names,
+            forward_attrs: v.base.as_forward_attrs(),
+            from_ident: v.base.from_ident,
+        }
+    }
+}
+
+impl ToTokens for FromTraitDefOptions {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        FromTraitDefImpl::from(self).to_tokens(tokens)
+    }
+}
@@ -3,14 +3,21 @@ use std::borrow::Cow;
 use syn::{parse_quote_spanned, spanned::Spanned};
 
 use crate::codegen;
-use crate::options::{Core, DefaultExpression, ParseAttribute};
-use crate::util::{Callable, Flag, SpannedValue};
+use crate::options::{
+    doc_comment, Alias, Constraints, Core, DefaultExpression, ParseAttribute, Relations,
+};
+use crate::util::{Callable, Flag, Override, SpannedValue};
 use crate::{Error, FromMeta, Result};
 
 #[derive(Debug, Clone)]
 pub struct InputField {
     pub ident: syn::Ident,
+    /// How the field is named when constructing the struct. This is the same as `ident`
+    /// except in tuple structs, where it is the field's index.
+    pub member: syn::Member,
     pub attr_name: Option<String>,
+    /// Additional names accepted for this field, declared with `#[darling(alias = "...")]`.
+    pub aliases: Vec<Alias>,
     pub ty: syn::Type,
     pub default: Option<DefaultExpression>,
     pub with: Option<Callable>,
@@ -21,6 +28,24 @@ pub struct InputField {
     pub post_transform: Option<codegen::PostfixTransform>,
     pub multiple: Option<bool>,
     pub flatten: Flag,
+    /// If set, this flattened field is given every item which no other field claims.
+    pub catch_all: Flag,
+    /// If set, using this field in the input records a deprecation warning.
+    pub deprecated: Option<Override<String>>,
+    /// If set, this field can be provided as a positional argument before any named ones.
+    pub positional: Flag,
+    /// The index of this field among the positional fields of the struct. This is set for
+    /// `positional` fields and for all fields of tuple structs.
+    pub position: Option<usize>,
+    /// Checks which the parsed value must pass.
+    pub constraints: Constraints,
+    /// Other fields which must or must not be present alongside this one.
+    pub relations: Relations,
+    /// If set, the field is provided by exactly one of the keys known to its type, such as
+    /// the variant names of an enum, rather than by its own name.
+    pub one_of_keys: Flag,
+    /// The doc comment of the field, if any.
+    pub doc: Option<String>,
 }
 
 impl InputField {
@@ -28,23 +53,60 @@ impl InputField {
     pub fn as_codegen_field(&self) -> codegen::Field<'_> {
         codegen::Field {
             ident: &self.ident,
+            member: &self.member,
+            position: self.position,
             name_in_attr: self
                 .attr_name
                 .as_deref()
                 .map_or_else(|| Cow::Owned(self.ident.to_string()), Cow::Borrowed),
+            aliases: &self.aliases,
             ty: &self.ty,
             default_expression: self.as_codegen_default(),
             with_callable: self.with.as_ref().map(|w| w.as_ref()).map_or_else(
-                || Cow::Owned(parse_quote_spanned!(self.ty.span()=> _darling::FromMeta::from_meta)),
+                || {
+                    Cow::Owned(
+                        parse_quote_spanned!(self.ty.span()=> _darling::from_meta_with_expected),
+                    )
+                },
                 Cow::Borrowed,
             ),
             skip: *self.skip.unwrap_or_default(),
             post_transform: self.post_transform.as_ref(),
             multiple: self.multiple.unwrap_or_default(),
             flatten: self.flatten.is_present(),
+            catch_all: self.catch_all.is_present(),
+            deprecated: self.deprecated.as_ref(),
+            constraints: &self.constraints,
+            relations: &self.relations,
+            track_key: false,
+            one_of_keys: self.one_of_keys.is_present(),
+            doc: self.doc.as_deref(),
         }
     }
 
+    /// The keys which select this field in the input: its name, followed by its aliases.
+    /// Skipped and flattened fields, and those using `one_of_keys`, have no keys of their own.
+    pub fn keys(&self) -> Vec<Cow<'_, str>> {
+        if *self.skip.unwrap_or_default()
+            || self.flatten.is_present()
+            || self.one_of_keys.is_present()
+        {
+            return Vec::new();
+        }
+
+        let name = self
+            .attr_name
+            .as_deref()
+            .map_or_else(|| Cow::Owned(self.ident.to_string()), Cow::Borrowed);
+        std::iter::once(name)
+            .chain(
+                self.aliases
+                    .iter()
+                    .map(|alias| Cow::Borrowed(alias.name.as_str())),
+            )
+            .collect()
+    }
+
     /// Generate a codegen::DefaultExpression for this field. This requires the field name
     /// in the `Inherit` case.
     fn as_codegen_default(&self) -> Option<codegen::DefaultExpression<'_>> {
@@ -52,22 +114,32 @@ impl InputField {
             DefaultExpression::Explicit(ref callable) => {
                 codegen::DefaultExpression::Explicit(callable)
             }
-            DefaultExpression::Inherit => codegen::DefaultExpression::Inherit(&self.ident),
+            DefaultExpression::Inherit => codegen::DefaultExpression::Inherit(&self.member),
             DefaultExpression::Trait { span } => codegen::DefaultExpression::Trait { span },
         })
     }
 
     fn new(ident: syn::Ident, ty: syn::Type) -> Self {
         InputField {
+            member: syn::Member::Named(ident.clone()),
             ident,
             ty,
             attr_name: None,
+            aliases: Vec::new(),
             default: None,
             with: None,
             skip: None,
             post_transform: Default::default(),
             multiple: None,
             flatten: Default::default(),
+            catch_all: Flag::default(),
+            deprecated: None,
+            positional: Flag::default(),
+            position: None,
+            constraints: Constraints::default(),
+            relations: Relations::default(),
+            one_of_keys: Flag::default(),
+            doc: None,
         }
     }
 
@@ -77,7 +149,8 @@ impl InputField {
             .clone()
             .unwrap_or_else(|| syn::Ident::new("__unnamed", ::proc_macro2::Span::call_site()));
         let ty = f.ty.clone();
-        let base = Self::new(ident, ty).parse_attributes(&f.attrs)?;
+        let mut base = Self::new(ident, ty).parse_attributes(&f.attrs)?;
+        base.doc = doc_comment(&f.attrs);
 
         Ok(if let Some(container) = parent {
             base.with_inherited(container)
@@ -137,6 +210,20 @@ impl ParseAttribute for InputField {
                     Error::custom("`flatten` and `rename` cannot be used together").with_span(mi),
                 );
             }
+        } else if path.is_ident("alias") {
+            self.aliases.push(FromMeta::from_meta(mi)?);
+
+            if self.flatten.is_present() {
+                return Err(
+                    Error::custom("`flatten` and `alias` cannot be used together").with_span(mi),
+                );
+            }
+        } else if path.is_ident("deprecated") {
+            if self.deprecated.is_some() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.deprecated = Some(FromMeta::from_meta(mi)?);
         } else if path.is_ident("default") {
             if self.default.is_some() {
                 return Err(Error::duplicate_field_path(path).with_span(mi));
@@ -196,6 +283,59 @@ impl ParseAttribute for InputField {
                     Error::custom("`flatten` and `multiple` cannot be used together").with_span(mi),
                 );
             }
+        } else if path.is_ident("positional") {
+            if self.positional.is_present() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.positional = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("range") {
+            if self.constraints.range.is_some() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.constraints.range = Some(FromMeta::from_meta(mi)?);
+        } else if path.is_ident("len") {
+            if self.constraints.len.is_some() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.constraints.len = Some(FromMeta::from_meta(mi)?);
+        } else if path.is_ident("non_empty") {
+            if self.constraints.non_empty.is_present() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.constraints.non_empty = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("one_of") {
+            if self.constraints.one_of.is_some() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            let values: Vec<syn::LitStr> = FromMeta::from_meta(mi)?;
+            if values.is_empty() {
+                return Err(Error::custom("`one_of` requires at least one value").with_span(mi));
+            }
+
+            self.constraints.one_of = Some(values);
+        } else if path.is_ident("conflicts_with") {
+            self.relations.conflicts_with.push(FromMeta::from_meta(mi)?);
+        } else if path.is_ident("requires") {
+            self.relations.requires.push(FromMeta::from_meta(mi)?);
+        } else if path.is_ident("required_if") {
+            self.relations.required_if.push(FromMeta::from_meta(mi)?);
+        } else if path.is_ident("one_of_keys") {
+            if self.one_of_keys.is_present() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.one_of_keys = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("catch_all") {
+            if self.catch_all.is_present() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.catch_all = FromMeta::from_meta(mi)?;
         } else if path.is_ident("flatten") {
             if self.flatten.is_present() {
                 return Err(Error::duplicate_field_path(path).with_span(mi));
@@ -217,6 +357,12 @@ impl ParseAttribute for InputField {
                 );
             }
 
+            if !self.aliases.is_empty() {
+                conflicts.push(
+                    Error::custom("`flatten` and `alias` cannot be used together").with_span(mi),
+                );
+            }
+
             if self.with.is_some() {
                 conflicts.push(
                     Error::custom("`flatten` and `with` cannot be used together").with_span(mi),
@@ -2,22 +2,31 @@ use std::borrow::Cow;
 
 use crate::ast::Fields;
 use crate::codegen;
-use crate::options::{Core, InputField, ParseAttribute};
-use crate::util::{Flag, SpannedValue};
+use crate::options::{doc_comment, Alias, Core, InputField, ParseAttribute};
+use crate::util::{Flag, Override, SpannedValue};
 use crate::{Error, FromMeta, Result};
 
 #[derive(Debug, Clone)]
 pub struct InputVariant {
     pub ident: syn::Ident,
     attr_name: Option<String>,
+    /// Additional names accepted for this variant, declared with `#[darling(alias = "...")]`.
+    aliases: Vec<Alias>,
     data: Fields<InputField>,
     skip: Option<bool>,
     /// Whether or not the variant should be used to create an instance for
     /// `FromMeta::from_word`.
     pub word: Option<SpannedValue<bool>>,
-    /// Whether or not unknown fields are acceptable in this
+    /// Whether or not the variant should be produced for any name which doesn't match another variant.
+    pub other: Flag,
+    /// Whether or not unknown fields are acceptable in this variant. This is inherited from the
+    /// enum unless set on the variant.
     allow_unknown_fields: Option<bool>,
     pub transparent: Flag,
+    /// If set, using this variant in the input records a deprecation warning.
+    deprecated: Option<Override<String>>,
+    /// The doc comment of the variant, if any.
+    doc: Option<String>,
 }
 
 impl InputVariant {
@@ -29,7 +38,35 @@ impl InputVariant {
         self.skip.unwrap_or_default()
     }
 
+    /// The names which select this variant in the input: its name, followed by its aliases.
+    /// Skipped variants have none.
+    pub fn keys(&self) -> Vec<Cow<'_, str>> {
+        if self.is_skipped() {
+            return Vec::new();
+        }
+
+        let name = self
+            .attr_name
+            .as_deref()
+            .map_or_else(|| Cow::Owned(self.ident.to_string()), Cow::Borrowed);
+        std::iter::once(name)
+            .chain(
+                self.aliases
+                    .iter()
+                    .map(|alias| Cow::Borrowed(alias.name.as_str())),
+            )
+            .collect()
+    }
+
+    pub fn fields(&self) -> &Fields<InputField> {
+        &self.data
+    }
+
     pub fn as_codegen_variant<'a>(&'a self, ty_ident: &'a syn::Ident) -> codegen::Variant<'a> {
+        let mut data = self.data.as_ref().map(InputField::as_codegen_field);
+        codegen::Field::track_related_keys(&mut data.fields);
+        codegen::Field::resolve_catch_all(&mut data.fields);
+
         codegen::Variant {
             ty_ident,
             variant_ident: &self.ident,
@@ -37,10 +74,14 @@ impl InputVariant {
                 .attr_name
                 .as_deref()
                 .map_or_else(|| Cow::Owned(self.ident.to_string()), Cow::Borrowed),
-            data: self.data.as_ref().map(InputField::as_codegen_field),
+            aliases: &self.aliases,
+            data,
             skip: self.is_skipped(),
             allow_unknown_fields: self.allow_unknown_fields.unwrap_or_default(),
             transparent: self.transparent.is_present(),
+            deprecated: self.deprecated.as_ref(),
+            other: self.other.is_present(),
+            doc: self.doc.as_deref(),
         }
     }
 
@@ -48,11 +89,15 @@ impl InputVariant {
         let mut starter = (InputVariant {
             ident: v.ident.clone(),
             attr_name: Default::default(),
+            aliases: Vec::new(),
             data: Fields::empty_from(&v.fields),
             skip: Default::default(),
             word: Default::default(),
+            other: Flag::default(),
             allow_unknown_fields: None,
             transparent: Flag::default(),
+            deprecated: None,
+            doc: doc_comment(&v.attrs),
         })
         .parse_attributes(&v.attrs)?;
 
@@ -105,6 +150,14 @@ impl ParseAttribute for InputVariant {
             }
 
             self.attr_name = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("alias") {
+            self.aliases.push(FromMeta::from_meta(mi)?);
+        } else if path.is_ident("deprecated") {
+            if self.deprecated.is_some() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.deprecated = Some(FromMeta::from_meta(mi)?);
         } else if path.is_ident("skip") {
             if self.skip.is_some() {
                 return Err(Error::duplicate_field_path(path).with_span(mi));
@@ -133,6 +186,18 @@ impl ParseAttribute for InputVariant {
             }
 
             self.word = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("allow_unknown_fields") {
+            if self.allow_unknown_fields.is_some() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.allow_unknown_fields = FromMeta::from_meta(mi)?;
+        } else if path.is_ident("other") {
+            if self.other.is_present() {
+                return Err(Error::duplicate_field_path(path).with_span(mi));
+            }
+
+            self.other = FromMeta::from_meta(mi)?;
         } else {
             return Err(Error::unknown_field_path(path).with_span(mi));
         }
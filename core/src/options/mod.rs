@@ -6,36 +6,63 @@ use crate::error::Accumulator;
 use crate::util::Callable;
 use crate::{Error, FromMeta, Result};
 
+mod alias;
+mod constraints;
 mod core;
 mod forward_attrs;
 mod forwarded_field;
 mod from_attributes;
+mod from_const_param;
 mod from_derive;
 mod from_field;
+mod from_fn;
+mod from_fn_arg;
+mod from_generic_param;
+mod from_impl_block;
+mod from_lifetime_param;
 mod from_meta;
+mod from_signature;
+mod from_trait_def;
 mod from_type_param;
 mod from_variant;
 mod input_field;
 mod input_variant;
 mod outer_from;
+mod relations;
 mod shape;
+mod to_meta;
 
+pub use self::alias::Alias;
+pub use self::constraints::Constraints;
 pub use self::core::Core;
 pub use self::forward_attrs::ForwardAttrsFilter;
 pub use self::forwarded_field::ForwardedField;
 pub use self::from_attributes::FromAttributesOptions;
+pub use self::from_const_param::FromConstParamOptions;
 pub use self::from_derive::FdiOptions;
 pub use self::from_field::FromFieldOptions;
+pub use self::from_fn::FromFnOptions;
+pub use self::from_fn_arg::FromFnArgOptions;
+pub use self::from_generic_param::FromGenericParamOptions;
+pub use self::from_impl_block::FromImplBlockOptions;
+pub use self::from_lifetime_param::FromLifetimeParamOptions;
 pub use self::from_meta::FromMetaOptions;
+pub use self::from_signature::{FromSignatureOptions, SignatureFields};
+pub use self::from_trait_def::FromTraitDefOptions;
 pub use self::from_type_param::FromTypeParamOptions;
 pub use self::from_variant::FromVariantOptions;
 pub use self::input_field::InputField;
 pub use self::input_variant::InputVariant;
 pub use self::outer_from::OuterFrom;
+pub use self::relations::Relations;
 pub use self::shape::{DataShape, DeriveInputShapeSet};
+pub use self::to_meta::ToMetaOptions;
 
 /// A default/fallback expression encountered in attributes during parsing.
 #[derive(Debug, Clone)]
+// `Callable` holds a `syn::Expr`, which grows past the lint's threshold when
+// `proc-macro2/span-locations` is enabled. This is only built once per field.
+#[allow(clippy::large_enum_variant)]
 pub enum DefaultExpression {
     /// The value should be taken from the `default` instance of the containing struct.
     /// This is not valid in container options.
@@ -69,6 +96,44 @@ impl FromMeta for DefaultExpression {
     }
 }
 
+/// Read the doc comment of an item from its `#[doc = "..."]` attributes, removing the space
+/// which follows `///` on each line. Returns `None` if the item has no doc comment.
+pub fn doc_comment(attrs: &[syn::Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                value:
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(doc),
+                        ..
+                    }),
+                ..
+            }) => Some(doc.value()),
+            _ => None,
+        })
+        .flat_map(|doc| {
+            doc.lines()
+                .map(|line| {
+                    line.strip_prefix(' ')
+                        .unwrap_or(line)
+                        .trim_end()
+                        .to_string()
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    let doc = lines.join("\n");
+    let doc = doc.trim_matches('\n');
+    if doc.is_empty() {
+        None
+    } else {
+        Some(doc.to_string())
+    }
+}
+
 /// Middleware for extracting attribute values. Implementers are expected to override
 /// `parse_nested` so they can apply individual items to themselves, while `parse_attributes`
 /// is responsible for looping through distinct outer attributes and collecting errors.
@@ -0,0 +1,121 @@
+use syn::{Expr, Ident, Meta};
+
+use crate::ast::{Fields, NestedMeta};
+use crate::error::Accumulator;
+use crate::options::InputField;
+use crate::util::SpannedValue;
+use crate::{Error, FromMeta, Result};
+
+/// Rules about which other fields of the same struct must or must not be present alongside
+/// a field, declared with `#[darling(conflicts_with = "...")]`, `#[darling(requires = "...")]`
+/// and `#[darling(required_if(other = value))]`.
+///
+/// Other fields are referred to by their Rust identifier.
+#[derive(Debug, Clone, Default)]
+pub struct Relations {
+    /// Fields which cannot be present if this field is.
+    pub conflicts_with: Vec<SpannedValue<String>>,
+    /// Fields which must be present if this field is.
+    pub requires: Vec<SpannedValue<String>>,
+    /// Conditions under which this field must be present.
+    pub required_if: Vec<RequiredIf>,
+}
+
+impl Relations {
+    /// Check if no relations were declared.
+    pub fn is_empty(&self) -> bool {
+        self.conflicts_with.is_empty() && self.requires.is_empty() && self.required_if.is_empty()
+    }
+
+    /// Get the identifiers of all the fields this field refers to, with a span for errors.
+    pub fn targets(&self) -> impl Iterator<Item = (String, proc_macro2::Span)> + '_ {
+        self.conflicts_with
+            .iter()
+            .chain(&self.requires)
+            .map(|name| (name.to_string(), name.span()))
+            .chain(
+                self.required_if
+                    .iter()
+                    .map(|cond| (cond.field.to_string(), cond.field.span())),
+            )
+    }
+}
+
+/// `#[darling(required_if(other = value))]`: the field is required when `other` was
+/// given and its parsed value is equal to `value`.
+#[derive(Debug, Clone)]
+pub struct RequiredIf {
+    pub field: Ident,
+    pub value: Expr,
+}
+
+impl FromMeta for RequiredIf {
+    fn from_list(items: &[NestedMeta]) -> Result<Self> {
+        match items {
+            [NestedMeta::Meta(Meta::NameValue(nv))] => {
+                let field = nv
+                    .path
+                    .get_ident()
+                    .ok_or_else(|| Error::custom("Expected a field name").with_span(&nv.path))?;
+                Ok(RequiredIf {
+                    field: field.clone(),
+                    value: nv.value.clone(),
+                })
+            }
+            [] => Err(Error::too_few_items(1)),
+            [_] => Err(Error::custom("Expected `field = value`").with_span(&items[0])),
+            _ => Err(Error::too_many_items(1).with_span(&items[1])),
+        }
+    }
+}
+
+/// Check that the relations of each field refer to other fields in `fields` which can be
+/// present in the input.
+pub(crate) fn validate(fields: &Fields<InputField>, errors: &mut Accumulator) {
+    let names: Vec<String> = fields.iter().map(|f| f.ident.to_string()).collect();
+
+    for field in fields.iter().filter(|f| !f.relations.is_empty()) {
+        if field.skip.map(|v| *v).unwrap_or_default() || field.flatten.is_present() {
+            errors.push(
+                Error::custom("Skipped and flattened fields cannot have `conflicts_with`, `requires` or `required_if`")
+                    .with_span(&field.ident),
+            );
+        }
+
+        for (name, span) in field.relations.targets() {
+            let target = match fields.iter().find(|f| f.ident == name) {
+                Some(target) => target,
+                None => {
+                    errors.push(Error::unknown_value_with_alts(&name, &names).with_span(&span));
+                    continue;
+                }
+            };
+
+            if target.ident == field.ident {
+                errors.push(Error::custom("A field cannot refer to itself").with_span(&span));
+            } else if target.skip.map(|v| *v).unwrap_or_default() || target.flatten.is_present() {
+                errors.push(
+                    Error::custom(format!(
+                        "`{}` is skipped or flattened, so it never appears in the input",
+                        name
+                    ))
+                    .with_span(&span),
+                );
+            }
+        }
+
+        for cond in &field.relations.required_if {
+            let multiple = fields
+                .iter()
+                .find(|f| f.ident == cond.field)
+                .and_then(|f| f.multiple)
+                .unwrap_or_default();
+            if multiple {
+                errors.push(
+                    Error::custom("`required_if` cannot refer to a `multiple` field")
+                        .with_span(&cond.field),
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+
+use crate::codegen::ToMetaImpl;
+use crate::options::FromMetaOptions;
+use crate::Result;
+
+/// Options for deriving `ToMeta`. These are the same `#[darling(...)]` options read when
+/// deriving `FromMeta`, so that the two impls are inverses of each other.
+pub struct ToMetaOptions(FromMetaOptions);
+
+impl ToMetaOptions {
+    pub fn new(di: &syn::DeriveInput) -> Result<Self> {
+        FromMetaOptions::new(di).map(ToMetaOptions)
+    }
+}
+
+impl ToTokens for ToMetaOptions {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        ToMetaImpl::from(&self.0).to_tokens(tokens)
+    }
+}
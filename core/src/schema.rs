@@ -0,0 +1,321 @@
+//! Descriptions of the attribute syntax accepted by derived receivers.
+//!
+//! Adding `#[darling(schema)]` to a type deriving `FromMeta`, `FromDeriveInput` or `FromAttributes`
+//! generates an associated `fn schema() -> darling::schema::Schema`. The returned value describes
+//! the keys the type reads, their types, defaults and aliases, and the schemas of nested types
+//! which also opted in. Macro crates can use it to document, lint or test their attribute surface.
+//!
+//! Nested schemas are found through [`FromMeta::describe`](crate::FromMeta::describe), which
+//! derived impls override when the container opts in. A type which contains itself, such as a
+//! tree of options, is described once: where it appears inside its own schema, its nested schema
+//! has the kind [`SchemaKind::Recursive`].
+//!
+//! The types in this module are `#[non_exhaustive]`, so that more properties and kinds can be
+//! described later. Hand-written [`describe`](crate::FromMeta::describe) impls create the structs
+//! with their `new` functions and set the other fields afterwards, and code which matches on the
+//! enums needs a wildcard arm.
+//!
+//! # Example
+//! ```rust,ignore
+//! #[derive(FromMeta)]
+//! #[darling(schema)]
+//! struct Retry {
+//!     #[darling(default)]
+//!     attempts: u32,
+//! }
+//!
+//! let schema = Retry::schema();
+//! assert_eq!(schema.ty, "Retry");
+//! assert_eq!(schema.fields().unwrap()[0].name, "attempts");
+//! ```
+
+use std::any;
+use std::cell::RefCell;
+
+/// A description of the attribute syntax read by a type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Schema {
+    /// The name of the type being described.
+    pub ty: &'static str,
+    /// The attributes read by a `FromDeriveInput` or `FromAttributes` receiver, such as
+    /// `serde`. This is empty for `FromMeta` types, which are read wherever their parent
+    /// places them.
+    pub attributes: Vec<&'static str>,
+    /// How the body of the type is read.
+    pub kind: SchemaKind,
+    /// The doc comment of the type, if any.
+    pub doc: Option<&'static str>,
+}
+
+impl Schema {
+    /// Create a schema for the type named `ty`, which isn't read from any attributes and has no
+    /// doc comment.
+    pub fn new(ty: &'static str, kind: SchemaKind) -> Self {
+        Schema {
+            ty,
+            attributes: Vec::new(),
+            kind,
+            doc: None,
+        }
+    }
+
+    /// Build the schema of `T` by calling `build`, unless the schema of `T` is already being
+    /// built on the current thread. In that case `T` contains itself, and the schema named `ty`
+    /// with the kind [`SchemaKind::Recursive`] is returned instead.
+    ///
+    /// Derived `schema` functions call this.
+    #[doc(hidden)]
+    pub fn build<T: ?Sized, F: FnOnce() -> Schema>(ty: &'static str, build: F) -> Self {
+        let name = any::type_name::<T>();
+        if BUILDING.with(|building| building.borrow().contains(&name)) {
+            return Schema::new(ty, SchemaKind::Recursive);
+        }
+
+        BUILDING.with(|building| building.borrow_mut().push(name));
+        let _guard = BuildGuard;
+        build()
+    }
+
+    /// Get the fields read by a struct, or `None` if the type is not a struct with fields.
+    pub fn fields(&self) -> Option<&[Field]> {
+        match self.kind {
+            SchemaKind::Struct(ref fields) => Some(&fields.fields),
+            _ => None,
+        }
+    }
+
+    /// Get the variants of an enum, or `None` if the type is not an enum.
+    pub fn variants(&self) -> Option<&[Variant]> {
+        match self.kind {
+            SchemaKind::Enum(ref body) => Some(&body.variants),
+            _ => None,
+        }
+    }
+}
+
+thread_local! {
+    /// The types whose schemas are being built on this thread, outermost first.
+    static BUILDING: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Marks the end of building a schema, even if building it panicked.
+struct BuildGuard;
+
+impl Drop for BuildGuard {
+    fn drop(&mut self) {
+        BUILDING.with(|building| building.borrow_mut().pop());
+    }
+}
+
+/// The shape of the input read by a type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SchemaKind {
+    /// A unit struct, written as a bare word.
+    Unit,
+    /// A newtype or transparent struct, read the same way as its only field.
+    Newtype(Newtype),
+    /// A struct whose fields are read from a list of nested items.
+    Struct(Fields),
+    /// An enum, whose variant is selected by the input.
+    Enum(Enum),
+    /// A type which is described further up in the schema that contains it.
+    Recursive,
+}
+
+/// A value which is read the same way as the type it wraps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Newtype {
+    /// The wrapped type, as written in the source.
+    pub ty: &'static str,
+    /// The schema of the wrapped type, if it has one.
+    pub nested: Option<Box<Schema>>,
+}
+
+impl Newtype {
+    /// Create a newtype wrapping the type named `ty`, without a nested schema.
+    pub fn new(ty: &'static str) -> Self {
+        Newtype { ty, nested: None }
+    }
+}
+
+/// The fields read from a list of nested items.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Fields {
+    pub fields: Vec<Field>,
+    /// Whether keys which no field claims are ignored rather than reported as errors.
+    pub allow_unknown_fields: bool,
+}
+
+impl Fields {
+    /// Create a list of fields which doesn't allow unknown fields.
+    pub fn new(fields: Vec<Field>) -> Self {
+        Fields {
+            fields,
+            allow_unknown_fields: false,
+        }
+    }
+}
+
+/// A field of a struct or struct-like variant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Field {
+    /// The key which provides this field. For fields of tuple structs, this is the field's index.
+    ///
+    /// Fields which are `flatten`, `skip` or `one_of_keys` are not named in the input, and this
+    /// is the name they would otherwise have.
+    pub name: &'static str,
+    /// Other keys which are accepted in place of `name`. Hidden aliases are not included.
+    pub aliases: Vec<&'static str>,
+    /// The type of the field, as written in the source.
+    pub ty: &'static str,
+    /// Whether parsing fails if the field is not given.
+    pub required: bool,
+    /// The value used when the field is not given, if one is declared.
+    pub default: Option<FieldDefault>,
+    /// Whether the key can appear more than once, with each value collected.
+    pub multiple: bool,
+    /// Whether the keys of the field's type are read directly from the parent's list.
+    pub flatten: bool,
+    /// Whether the field is never read from the input.
+    pub skip: bool,
+    /// Whether the field can be given as a positional item before any named ones.
+    pub positional: bool,
+    /// Whether the field is given by exactly one of the keys its type knows, such as the
+    /// variant names of an enum.
+    pub one_of_keys: bool,
+    /// Set if using the field records a deprecation warning.
+    pub deprecated: Option<Deprecation>,
+    /// The schema of the field's type, or of each item for `multiple` fields, if it has one.
+    pub nested: Option<Box<Schema>>,
+    /// The doc comment of the field, if any.
+    pub doc: Option<&'static str>,
+}
+
+impl Field {
+    /// Create an optional field read from the key `name`, with the type named `ty`, and no other
+    /// properties.
+    pub fn new(name: &'static str, ty: &'static str) -> Self {
+        Field {
+            name,
+            aliases: Vec::new(),
+            ty,
+            required: false,
+            default: None,
+            multiple: false,
+            flatten: false,
+            skip: false,
+            positional: false,
+            one_of_keys: false,
+            deprecated: None,
+            nested: None,
+            doc: None,
+        }
+    }
+}
+
+/// Where the value of a field which is not given comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FieldDefault {
+    /// The field type's `Default` impl.
+    Trait,
+    /// The function or closure named in `#[darling(default = ...)]`, as written in the source.
+    Function(&'static str),
+    /// The matching field of the container's default value.
+    Container,
+}
+
+/// A note that a field or variant is deprecated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Deprecation {
+    /// What to use instead, if given.
+    pub note: Option<&'static str>,
+}
+
+impl Deprecation {
+    /// Create a deprecation with an optional note saying what to use instead.
+    pub fn new(note: Option<&'static str>) -> Self {
+        Deprecation { note }
+    }
+}
+
+/// The variants of an enum.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Enum {
+    pub variants: Vec<Variant>,
+    /// How the variant is selected from the input.
+    pub tagging: Tagging,
+}
+
+impl Enum {
+    /// Create the variants of an enum, selected as described by `tagging`.
+    pub fn new(variants: Vec<Variant>, tagging: Tagging) -> Self {
+        Enum { variants, tagging }
+    }
+}
+
+/// How the variant of an enum is selected from the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Tagging {
+    /// The variant is named by the path of the only nested item, e.g. `cache(lru(size = 10))`.
+    External,
+    /// The variant is named by the value of this key, e.g. `cache(kind = "lru", size = 10)`.
+    Internal(&'static str),
+    /// Each variant is tried in declaration order until one succeeds.
+    Untagged,
+}
+
+/// A variant of an enum.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Variant {
+    /// The name which selects this variant.
+    pub name: &'static str,
+    /// Other names which select this variant. Hidden aliases are not included.
+    pub aliases: Vec<&'static str>,
+    /// Whether the variant can never be produced from the input.
+    pub skip: bool,
+    /// Whether the variant is produced for any name which doesn't match another variant.
+    pub other: bool,
+    /// Set if using the variant records a deprecation warning.
+    pub deprecated: Option<Deprecation>,
+    /// How the body of the variant is read.
+    pub kind: VariantKind,
+    /// The doc comment of the variant, if any.
+    pub doc: Option<&'static str>,
+}
+
+impl Variant {
+    /// Create a variant selected by `name`, without aliases or other properties.
+    pub fn new(name: &'static str, kind: VariantKind) -> Self {
+        Variant {
+            name,
+            aliases: Vec::new(),
+            skip: false,
+            other: false,
+            deprecated: None,
+            kind,
+            doc: None,
+        }
+    }
+}
+
+/// The shape of the input read by a variant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum VariantKind {
+    /// A variant without fields, written as a bare word.
+    Unit,
+    /// A variant with a single unnamed field, or a transparent variant.
+    Newtype(Newtype),
+    /// A variant whose fields are read from a list of nested items.
+    Struct(Fields),
+}
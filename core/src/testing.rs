@@ -0,0 +1,252 @@
+//! Helpers for testing receivers and derives in-process, without a compile-fail test.
+//!
+//! This module and its macros need the `testing` feature, which is usually enabled only for
+//! tests, e.g. `darling = { version = "0.23", features = ["testing"] }` under
+//! `[dev-dependencies]`.
+//!
+//! Compile-fail tests show exactly what users of a macro will see, but they are slow, and they
+//! break whenever rustc changes how it prints diagnostics. The functions here parse quoted input
+//! directly, so tests can check the value a receiver produces, the kind, message and span of each
+//! error it reports, and that a derive generates valid Rust.
+//!
+//! The macros [`assert_parses!`](crate::assert_parses), [`assert_errors!`](crate::assert_errors)
+//! and [`assert_expands!`](crate::assert_expands) take the input as tokens and call the function
+//! of the same name.
+//!
+//! # Spans
+//! Input is parsed from its source text, so the spans of errors point into it and
+//! [`snippet`] can get the text each one covers. This needs the `span-locations` feature of
+//! `proc-macro2`, which is enabled by darling's `span-locations` feature, or by adding
+//! `proc-macro2 = { version = "1", features = ["span-locations"] }` to the dev-dependencies of
+//! the crate under test. Without it, there are no snippets to check.
+//!
+//! The macros turn their input into source text with `stringify!`, which may space tokens
+//! differently than they were written. Expected snippets should use the spacing of `rustfmt`.
+//!
+//! # Example
+//! ```rust,ignore
+//! #[derive(FromDeriveInput)]
+//! #[darling(attributes(my))]
+//! struct Opts {
+//!     a: u8,
+//! }
+//!
+//! #[test]
+//! fn parses() {
+//!     let opts = darling::assert_parses!(Opts, #[my(a = 1)] struct S;);
+//!     assert_eq!(opts.a, 1);
+//! }
+//!
+//! #[test]
+//! fn rejects_strings() {
+//!     darling::assert_errors!(Opts, { #[my(a = "one")] struct S; }, [
+//!         { kind: "UnknownValue", snippet: "\"one\"" },
+//!     ]);
+//! }
+//! ```
+
+use std::fmt::{self, Write};
+
+use proc_macro2::{TokenStream, TokenTree};
+use syn::DeriveInput;
+
+use crate::{Error, FromDeriveInput, Result};
+
+/// Parse `source` as the input of a derive macro.
+///
+/// # Panics
+/// This panics if `source` is not a struct, enum or union.
+#[track_caller]
+pub fn parse_input(source: &str) -> DeriveInput {
+    syn::parse_str(source).unwrap_or_else(|e| panic!("Invalid input `{}`: {}", source, e))
+}
+
+/// Parse `source` as the input of a derive macro, then read `T` from it.
+#[track_caller]
+pub fn from_derive_input<T: FromDeriveInput>(source: &str) -> Result<T> {
+    T::from_derive_input(&parse_input(source))
+}
+
+/// Read `T` from `source`, panicking with every error if that fails.
+#[track_caller]
+pub fn assert_parses<T: FromDeriveInput>(source: &str) -> T {
+    match from_derive_input(source) {
+        Ok(value) => value,
+        Err(error) => panic!(
+            "Expected `{}` to parse, but it failed with:\n{}",
+            source,
+            Report(&flatten(error))
+        ),
+    }
+}
+
+/// Read `T` from `source`, and check that it fails with the errors in `expected`, in order.
+#[track_caller]
+pub fn assert_errors<T: FromDeriveInput>(source: &str, expected: &[ExpectedError]) {
+    let errors = match from_derive_input::<T>(source) {
+        Ok(_) => panic!("Expected `{}` to fail, but it parsed", source),
+        Err(error) => flatten(error),
+    };
+
+    let mismatches = expected
+        .iter()
+        .zip(&errors)
+        .enumerate()
+        .filter_map(|(index, (expected, error))| {
+            expected
+                .mismatch(error)
+                .map(|mismatch| format!("error {}: {}", index + 1, mismatch))
+        })
+        .collect::<Vec<_>>();
+
+    if errors.len() != expected.len() || !mismatches.is_empty() {
+        let mut message = format!(
+            "Expected {} errors from `{}`, got {}:\n{}",
+            expected.len(),
+            source,
+            errors.len(),
+            Report(&errors)
+        );
+        for mismatch in mismatches {
+            write!(message, "\n{}", mismatch).expect("writing to a string never fails");
+        }
+        panic!("{}", message);
+    }
+}
+
+/// Run `derive` on `source`, and check that the generated tokens are a valid Rust file which
+/// doesn't contain any `compile_error!` invocations. This does not type-check the output.
+///
+/// `derive` is usually a function from [`crate::derive`], or a macro's own entry point written
+/// against `proc_macro2`.
+#[track_caller]
+pub fn assert_expands<F>(derive: F, source: &str) -> syn::File
+where
+    F: FnOnce(&DeriveInput) -> TokenStream,
+{
+    let tokens = derive(&parse_input(source));
+    if contains_compile_error(tokens.clone()) {
+        panic!(
+            "Expected `{}` to expand without errors, got:\n{}",
+            source, tokens
+        );
+    }
+
+    syn::parse2(tokens.clone()).unwrap_or_else(|e| {
+        panic!(
+            "Expansion of `{}` is not a valid file: {}\n{}",
+            source, e, tokens
+        )
+    })
+}
+
+/// Get the source text covered by the span of `error`, if it has one and its location is known.
+pub fn snippet(error: &Error) -> Option<String> {
+    error.explicit_span().and_then(|span| span.source_text())
+}
+
+/// The properties one error is expected to have. Properties which are not set are not checked.
+#[derive(Debug, Clone, Default)]
+pub struct ExpectedError {
+    kind: Option<String>,
+    code: Option<String>,
+    message: Option<String>,
+    snippet: Option<String>,
+}
+
+impl ExpectedError {
+    /// Create an expectation which matches any error.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Expect the [name](crate::error::ErrorKindRef::name) of the error's kind, such as
+    /// `"MissingField"`.
+    pub fn kind<T: fmt::Display>(mut self, kind: T) -> Self {
+        self.kind = Some(kind.to_string());
+        self
+    }
+
+    /// Expect the stable [code](Error::code) of the error, such as `"D0003"`.
+    pub fn code<T: fmt::Display>(mut self, code: T) -> Self {
+        self.code = Some(code.to_string());
+        self
+    }
+
+    /// Expect the error's full message, including its location.
+    pub fn message<T: fmt::Display>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+
+    /// Expect the source text covered by the error's span. See the [module docs](self#spans).
+    pub fn snippet<T: fmt::Display>(mut self, snippet: T) -> Self {
+        self.snippet = Some(snippet.to_string());
+        self
+    }
+
+    /// Describe the first way `error` differs from this expectation, if any.
+    fn mismatch(&self, error: &Error) -> Option<String> {
+        let checks = [
+            ("kind", &self.kind, Some(error.kind().name().to_string())),
+            ("code", &self.code, error.code().map(str::to_string)),
+            ("message", &self.message, Some(error.to_string())),
+            ("snippet", &self.snippet, snippet(error)),
+        ];
+
+        checks
+            .into_iter()
+            .find_map(|(property, expected, actual)| match (expected, actual) {
+                (None, _) => None,
+                (Some(expected), Some(actual)) if *expected == actual => None,
+                (Some(expected), actual) => Some(format!(
+                    "expected {} {:?}, got {:?}",
+                    property, expected, actual
+                )),
+            })
+    }
+}
+
+fn flatten(error: Error) -> Vec<Error> {
+    error.flatten().into_iter().collect()
+}
+
+/// Whether `tokens` invoke `compile_error!`, as `Error::write_errors` does.
+fn contains_compile_error(tokens: TokenStream) -> bool {
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Ident(ident) if ident == "compile_error" => {
+                if matches!(tokens.peek(), Some(TokenTree::Punct(punct)) if punct.as_char() == '!')
+                {
+                    return true;
+                }
+            }
+            TokenTree::Group(group) if contains_compile_error(group.stream()) => return true,
+            _ => {}
+        }
+    }
+
+    false
+}
+
+/// A list of errors for a panic message, with the details `ExpectedError` can check.
+struct Report<'a>(&'a [Error]);
+
+impl fmt::Display for Report<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, error) in self.0.iter().enumerate() {
+            write!(f, "{}. [{}", index + 1, error.kind().name())?;
+            if let Some(code) = error.code() {
+                write!(f, " {}", code)?;
+            }
+            write!(f, "] {}", error)?;
+            if let Some(snippet) = snippet(error) {
+                write!(f, " (spans `{}`)", snippet)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,422 @@
+use std::collections::btree_map::BTreeMap;
+use std::collections::hash_map::HashMap;
+use std::hash::BuildHasher;
+use std::num;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use proc_macro2::Literal;
+use syn::{parse_quote, Expr, Ident, Lit, Meta, Path};
+
+use crate::ast::NestedMeta;
+use crate::util::{meta_list, meta_name_value, path_from_string};
+
+/// Convert a value back into the meta item syntax that its [`FromMeta`](crate::FromMeta) impl
+/// accepts.
+///
+/// This is the inverse of `FromMeta`: for any value `x` produced by parsing, the following
+/// should hold:
+///
+/// ```rust,ignore
+/// let meta = x.to_meta(&parse_quote!(my_attr)).unwrap();
+/// assert_eq!(T::from_meta(&meta)?, x);
+/// ```
+///
+/// This makes it possible to re-emit options onto generated items, e.g. `quote!(#[#meta])`.
+///
+/// # Provided Implementations
+/// ## bool
+/// * `true` becomes a word, e.g. `foo`.
+/// * `false` becomes a boolean literal, e.g. `foo = false`.
+///
+/// ## String, char, numbers
+/// * As a literal, e.g. `foo = "hello"` or `foo = 404`. Negative numbers are emitted as
+///   string literals, since that is the only form `FromMeta` accepts for them.
+///
+/// ## Option
+/// * `None` omits the item; `Some` delegates to the inner value.
+///
+/// ## Maps
+/// * As a list of the entries, e.g. `foo(a = 1, b = 2)`.
+pub trait ToMeta {
+    /// Create a meta item named `path` that represents `self`.
+    ///
+    /// Returns `None` if the value is expressed by the absence of the item, such as an
+    /// absent [`Flag`](crate::util::Flag) or `Option::None`.
+    fn to_meta(&self, path: &Path) -> Option<Meta>;
+
+    /// Create the items that would appear inside the parentheses of a list-form meta item.
+    ///
+    /// This is used when the value is flattened into its parent. The default implementation
+    /// unwraps the list produced by [`ToMeta::to_meta`], and returns no items for any other form.
+    fn to_nested_meta(&self) -> Vec<NestedMeta> {
+        match self.to_meta(&path_from_string("__darling")) {
+            Some(Meta::List(list)) => NestedMeta::parse_meta_list(list.tokens)
+                .expect("ToMeta::to_meta should produce a valid meta list"),
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl ToMeta for () {
+    fn to_meta(&self, path: &Path) -> Option<Meta> {
+        Some(Meta::Path(path.clone()))
+    }
+}
+
+impl ToMeta for bool {
+    fn to_meta(&self, path: &Path) -> Option<Meta> {
+        Some(if *self {
+            Meta::Path(path.clone())
+        } else {
+            meta_name_value(path, parse_quote!(false))
+        })
+    }
+}
+
+impl ToMeta for char {
+    fn to_meta(&self, path: &Path) -> Option<Meta> {
+        lit(path, Literal::character(*self))
+    }
+}
+
+impl ToMeta for String {
+    fn to_meta(&self, path: &Path) -> Option<Meta> {
+        lit(path, Literal::string(self))
+    }
+}
+
+impl ToMeta for std::path::PathBuf {
+    fn to_meta(&self, path: &Path) -> Option<Meta> {
+        lit(path, Literal::string(&self.to_string_lossy()))
+    }
+}
+
+/// Generate an impl of `ToMeta` for an unsigned integer, which is always emitted as an
+/// integer literal.
+macro_rules! to_meta_unsigned {
+    ($ty:path, $lit:ident) => {
+        impl ToMeta for $ty {
+            fn to_meta(&self, path: &Path) -> Option<Meta> {
+                lit(path, Literal::$lit((*self).into()))
+            }
+        }
+    };
+}
+
+/// Generate an impl of `ToMeta` for a signed integer. Negative values can only be
+/// parsed from a string literal, so they are quoted.
+macro_rules! to_meta_signed {
+    ($ty:path, $prim:ident, $lit:ident) => {
+        impl ToMeta for $ty {
+            fn to_meta(&self, path: &Path) -> Option<Meta> {
+                let value: $prim = (*self).into();
+                if value < 0 {
+                    lit(path, Literal::string(&value.to_string()))
+                } else {
+                    lit(path, Literal::$lit(value))
+                }
+            }
+        }
+    };
+}
+
+to_meta_unsigned!(u8, u8_unsuffixed);
+to_meta_unsigned!(u16, u16_unsuffixed);
+to_meta_unsigned!(u32, u32_unsuffixed);
+to_meta_unsigned!(u64, u64_unsuffixed);
+to_meta_unsigned!(u128, u128_unsuffixed);
+to_meta_unsigned!(usize, usize_unsuffixed);
+to_meta_unsigned!(num::NonZeroU8, u8_unsuffixed);
+to_meta_unsigned!(num::NonZeroU16, u16_unsuffixed);
+to_meta_unsigned!(num::NonZeroU32, u32_unsuffixed);
+to_meta_unsigned!(num::NonZeroU64, u64_unsuffixed);
+to_meta_unsigned!(num::NonZeroU128, u128_unsuffixed);
+to_meta_unsigned!(num::NonZeroUsize, usize_unsuffixed);
+to_meta_signed!(i8, i8, i8_unsuffixed);
+to_meta_signed!(i16, i16, i16_unsuffixed);
+to_meta_signed!(i32, i32, i32_unsuffixed);
+to_meta_signed!(i64, i64, i64_unsuffixed);
+to_meta_signed!(i128, i128, i128_unsuffixed);
+to_meta_signed!(isize, isize, isize_unsuffixed);
+to_meta_signed!(num::NonZeroI8, i8, i8_unsuffixed);
+to_meta_signed!(num::NonZeroI16, i16, i16_unsuffixed);
+to_meta_signed!(num::NonZeroI32, i32, i32_unsuffixed);
+to_meta_signed!(num::NonZeroI64, i64, i64_unsuffixed);
+to_meta_signed!(num::NonZeroI128, i128, i128_unsuffixed);
+to_meta_signed!(num::NonZeroIsize, isize, isize_unsuffixed);
+
+/// Generate an impl of `ToMeta` for a float. Negative and non-finite values are quoted,
+/// since they have no literal form that `FromMeta` accepts.
+macro_rules! to_meta_float {
+    ($ty:ident, $lit:ident) => {
+        impl ToMeta for $ty {
+            fn to_meta(&self, path: &Path) -> Option<Meta> {
+                if self.is_finite() && self.is_sign_positive() {
+                    lit(path, Literal::$lit(*self))
+                } else {
+                    lit(path, Literal::string(&self.to_string()))
+                }
+            }
+        }
+    };
+}
+
+to_meta_float!(f32, f32_unsuffixed);
+to_meta_float!(f64, f64_unsuffixed);
+
+impl ToMeta for syn::Expr {
+    fn to_meta(&self, path: &Path) -> Option<Meta> {
+        Some(meta_name_value(path, self.clone()))
+    }
+}
+
+impl ToMeta for syn::Path {
+    fn to_meta(&self, path: &Path) -> Option<Meta> {
+        // Paths with generic arguments aren't valid expressions, so those are quoted.
+        if self.segments.iter().all(|s| s.arguments.is_empty()) {
+            Some(meta_name_value(path, parse_quote!(#self)))
+        } else {
+            lit(path, Literal::string(&quote::quote!(#self).to_string()))
+        }
+    }
+}
+
+impl ToMeta for syn::Ident {
+    fn to_meta(&self, path: &Path) -> Option<Meta> {
+        Some(meta_name_value(path, parse_quote!(#self)))
+    }
+}
+
+/// Generate an impl of `ToMeta` for a type that `FromMeta` parses from a quoted string.
+macro_rules! to_meta_quoted {
+    ($ty:path) => {
+        impl ToMeta for $ty {
+            fn to_meta(&self, path: &Path) -> Option<Meta> {
+                lit(path, Literal::string(&quote::quote!(#self).to_string()))
+            }
+        }
+    };
+}
+
+to_meta_quoted!(syn::Type);
+to_meta_quoted!(syn::TypePath);
+to_meta_quoted!(syn::Visibility);
+to_meta_quoted!(syn::WhereClause);
+
+impl ToMeta for syn::Lit {
+    fn to_meta(&self, path: &Path) -> Option<Meta> {
+        Some(meta_name_value(path, parse_quote!(#self)))
+    }
+}
+
+impl ToMeta for syn::LitStr {
+    fn to_meta(&self, path: &Path) -> Option<Meta> {
+        Some(meta_name_value(path, parse_quote!(#self)))
+    }
+}
+
+impl ToMeta for syn::LitInt {
+    fn to_meta(&self, path: &Path) -> Option<Meta> {
+        Some(meta_name_value(path, parse_quote!(#self)))
+    }
+}
+
+impl ToMeta for syn::LitBool {
+    fn to_meta(&self, path: &Path) -> Option<Meta> {
+        Some(meta_name_value(path, parse_quote!(#self)))
+    }
+}
+
+impl ToMeta for syn::Meta {
+    fn to_meta(&self, path: &Path) -> Option<Meta> {
+        let mut meta = self.clone();
+        match meta {
+            Meta::Path(ref mut p) => *p = path.clone(),
+            Meta::List(ref mut list) => list.path = path.clone(),
+            Meta::NameValue(ref mut nv) => nv.path = path.clone(),
+        }
+        Some(meta)
+    }
+}
+
+impl ToMeta for Vec<Ident> {
+    fn to_meta(&self, path: &Path) -> Option<Meta> {
+        let items = self
+            .iter()
+            .map(|ident| NestedMeta::Meta(Meta::Path(ident.clone().into())))
+            .collect::<Vec<_>>();
+        Some(meta_list(path, &items))
+    }
+}
+
+impl ToMeta for Vec<Path> {
+    fn to_meta(&self, path: &Path) -> Option<Meta> {
+        let items = self
+            .iter()
+            .map(|p| NestedMeta::Meta(Meta::Path(p.clone())))
+            .collect::<Vec<_>>();
+        Some(meta_list(path, &items))
+    }
+}
+
+impl<T: ToMeta> ToMeta for Option<T> {
+    fn to_meta(&self, path: &Path) -> Option<Meta> {
+        self.as_ref().and_then(|value| value.to_meta(path))
+    }
+}
+
+/// Generate an impl of `ToMeta` that delegates to the pointee.
+macro_rules! smart_pointer_t {
+    ($ty:path) => {
+        impl<T: ToMeta + ?Sized> ToMeta for $ty {
+            fn to_meta(&self, path: &Path) -> Option<Meta> {
+                (**self).to_meta(path)
+            }
+
+            fn to_nested_meta(&self) -> Vec<NestedMeta> {
+                (**self).to_nested_meta()
+            }
+        }
+    };
+}
+
+smart_pointer_t!(Box<T>);
+smart_pointer_t!(Rc<T>);
+smart_pointer_t!(Arc<T>);
+
+/// Trait to convert a map key back into the path it was parsed from.
+trait KeyToPath {
+    fn to_path(&self) -> Path;
+}
+
+impl KeyToPath for String {
+    fn to_path(&self) -> Path {
+        path_from_string(self)
+    }
+}
+
+impl KeyToPath for syn::Path {
+    fn to_path(&self) -> Path {
+        self.clone()
+    }
+}
+
+impl KeyToPath for syn::Ident {
+    fn to_path(&self) -> Path {
+        self.clone().into()
+    }
+}
+
+fn map_entries<'a, K, V, I>(path: &Path, entries: I) -> Option<Meta>
+where
+    K: KeyToPath + 'a,
+    V: ToMeta + 'a,
+    I: IntoIterator<Item = (&'a K, &'a V)>,
+{
+    let items = entries
+        .into_iter()
+        .filter_map(|(key, value)| value.to_meta(&key.to_path()))
+        .map(NestedMeta::Meta)
+        .collect::<Vec<_>>();
+    Some(meta_list(path, &items))
+}
+
+macro_rules! map {
+    (hash_map, $key:ty) => {
+        impl<V: ToMeta, S: BuildHasher> ToMeta for HashMap<$key, V, S> {
+            fn to_meta(&self, path: &Path) -> Option<Meta> {
+                map_entries(path, self)
+            }
+        }
+    };
+
+    (btree_map, $key:ty) => {
+        impl<V: ToMeta> ToMeta for BTreeMap<$key, V> {
+            fn to_meta(&self, path: &Path) -> Option<Meta> {
+                map_entries(path, self)
+            }
+        }
+    };
+}
+
+map!(hash_map, String);
+map!(hash_map, syn::Ident);
+map!(hash_map, syn::Path);
+
+map!(btree_map, String);
+map!(btree_map, syn::Ident);
+
+/// Create a `path = literal` meta item.
+fn lit(path: &Path, literal: Literal) -> Option<Meta> {
+    let lit = Lit::new(literal);
+    Some(meta_name_value(path, Expr::Lit(parse_quote!(#lit))))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use proc_macro2::TokenStream;
+    use quote::quote;
+    use syn::{parse_quote, Meta};
+
+    use super::ToMeta;
+    use crate::FromMeta;
+
+    /// Convert a value to a meta item named `ignore` and parse it back.
+    fn round_trip<T: ToMeta + FromMeta>(value: &T) -> T {
+        let meta = value
+            .to_meta(&parse_quote!(ignore))
+            .expect("value is present");
+        T::from_meta(&meta).expect("emitted meta should parse")
+    }
+
+    fn emit<T: ToMeta>(value: &T) -> TokenStream {
+        let meta: Option<Meta> = value.to_meta(&parse_quote!(ignore));
+        quote!(#meta)
+    }
+
+    #[test]
+    fn primitives() {
+        assert!(round_trip(&true));
+        assert!(!round_trip(&false));
+        assert_eq!(round_trip(&'#'), '#');
+        assert_eq!(round_trip(&"hello".to_string()), "hello");
+        assert_eq!(round_trip(&404u16), 404);
+        assert_eq!(round_trip(&-25i32), -25);
+        assert_eq!(round_trip(&1.5f64), 1.5);
+        assert_eq!(round_trip(&-0.5f32), -0.5);
+    }
+
+    #[test]
+    fn word_forms() {
+        assert_eq!(emit(&true).to_string(), quote!(ignore).to_string());
+        assert_eq!(emit(&()).to_string(), quote!(ignore).to_string());
+        assert!(emit(&None::<String>).is_empty());
+    }
+
+    #[test]
+    fn syn_types() {
+        let path: syn::Path = parse_quote!(a::b);
+        assert_eq!(round_trip(&path), path);
+        let generic: syn::Path = parse_quote!(Vec<u8>);
+        assert_eq!(round_trip(&generic), generic);
+        let ty: syn::Type = parse_quote!(Option<&'static str>);
+        assert_eq!(round_trip(&ty), ty);
+        let expr: syn::Expr = parse_quote!(1 + 2);
+        assert_eq!(round_trip(&expr), expr);
+    }
+
+    #[test]
+    fn maps() {
+        let mut map = BTreeMap::new();
+        map.insert("first".to_string(), 1u8);
+        map.insert("type".to_string(), 2u8);
+        assert_eq!(
+            emit(&map).to_string(),
+            quote!(ignore(first = 1, type = 2)).to_string()
+        );
+        assert_eq!(round_trip(&map), map);
+    }
+}
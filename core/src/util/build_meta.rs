@@ -0,0 +1,92 @@
+use proc_macro2::{Ident, Span};
+use syn::{punctuated::Punctuated, Expr, MacroDelimiter, Meta, MetaList, MetaNameValue, Path};
+
+use crate::ast::NestedMeta;
+
+/// Build a path from a string produced by [`path_to_string`](crate::util::path_to_string).
+///
+/// Unlike `syn::parse_str`, this accepts keywords such as `type` as path segments, since
+/// those are valid meta item names.
+///
+/// # Panics
+/// This panics if any `::`-separated segment of `value` is not a valid identifier.
+///
+/// # Example
+/// ```rust
+/// # use darling_core::util::{path_from_string, path_to_string};
+/// assert_eq!(path_to_string(&path_from_string("a::type")), "a::type");
+/// ```
+pub fn path_from_string(value: &str) -> Path {
+    let mut segments = Punctuated::new();
+    for segment in value.split("::") {
+        let ident = match segment.strip_prefix("r#") {
+            Some(raw) => Ident::new_raw(raw, Span::call_site()),
+            None => Ident::new(segment, Span::call_site()),
+        };
+        segments.push(ident.into());
+    }
+
+    Path {
+        leading_colon: None,
+        segments,
+    }
+}
+
+/// Build a `path(items...)` meta item.
+pub fn meta_list(path: &Path, items: &[NestedMeta]) -> Meta {
+    Meta::List(MetaList {
+        path: path.clone(),
+        delimiter: MacroDelimiter::Paren(Default::default()),
+        tokens: quote::quote!(#(#items),*),
+    })
+}
+
+/// Build a `path = value` meta item.
+pub fn meta_name_value(path: &Path, value: Expr) -> Meta {
+    Meta::NameValue(MetaNameValue {
+        path: path.clone(),
+        eq_token: Default::default(),
+        value,
+    })
+}
+
+/// Convert a meta item produced by [`ToMeta`](crate::ToMeta) into a positional argument by
+/// dropping its name, so `name = "value"` becomes `"value"` and `name = Value` becomes `Value`.
+///
+/// Meta items without a plain value, such as lists, are returned unchanged.
+pub fn positional_item(meta: Meta) -> NestedMeta {
+    match meta {
+        Meta::NameValue(MetaNameValue {
+            value: Expr::Lit(expr),
+            ..
+        }) => NestedMeta::Lit(expr.lit),
+        Meta::NameValue(MetaNameValue {
+            value: Expr::Path(expr),
+            ..
+        }) if expr.qself.is_none() => NestedMeta::Meta(Meta::Path(expr.path)),
+        meta => NestedMeta::Meta(meta),
+    }
+}
+
+/// Convert a meta item produced by [`ToMeta`](crate::ToMeta) for an enum into the items that
+/// name its variant, as written for a `#[darling(one_of_keys)]` field.
+///
+/// A list such as `name(variant = "value")` is unwrapped, and a unit variant written as
+/// `name = "variant"` becomes the word `variant`. Any other item produces nothing.
+pub fn variant_items(meta: Meta) -> Vec<NestedMeta> {
+    match meta {
+        Meta::List(list) => NestedMeta::parse_meta_list(list.tokens)
+            .expect("ToMeta::to_meta should produce a valid meta list"),
+        Meta::NameValue(MetaNameValue {
+            value:
+                Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(name),
+                    ..
+                }),
+            ..
+        }) => vec![NestedMeta::Meta(Meta::Path(path_from_string(
+            &name.value(),
+        )))],
+        _ => Vec::new(),
+    }
+}
@@ -1,7 +1,9 @@
+use std::borrow::Cow;
+
 use quote::ToTokens;
 use syn::{parse::Parse, Expr, ExprClosure, ExprLit, ExprPath, Lit, Path};
 
-use crate::{Error, FromMeta, Result};
+use crate::{Error, FromMeta, Result, ToMeta};
 
 /// Either a path or a closure.
 ///
@@ -66,6 +68,10 @@ impl From<Callable> for Expr {
 }
 
 impl FromMeta for Callable {
+    fn expected() -> Option<Cow<'static, str>> {
+        Some(Cow::Borrowed("a path or a closure"))
+    }
+
     fn from_expr(expr: &Expr) -> Result<Self> {
         match expr {
             Expr::Path(_) | Expr::Closure(_) => Ok(Self { call: expr.clone() }),
@@ -82,6 +88,12 @@ impl FromMeta for Callable {
     }
 }
 
+impl ToMeta for Callable {
+    fn to_meta(&self, path: &Path) -> Option<syn::Meta> {
+        self.call.to_meta(path)
+    }
+}
+
 impl ToTokens for Callable {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         self.call.to_tokens(tokens);
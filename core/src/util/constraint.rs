@@ -0,0 +1,198 @@
+//! Checks used by the `range`, `len`, `non_empty` and `one_of` field options in derived impls.
+//!
+//! Each check returns an unspanned error; generated code adds the span of the offending meta
+//! item and the name of the field. The traits describe which types each check applies to, and
+//! can be implemented for custom types to make them usable with those options.
+//!
+//! All checks pass for `None`, so an optional field is only checked when a value is present.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt;
+use std::ops::RangeBounds;
+
+use crate::util::{PathList, SpannedValue};
+use crate::{Error, Result};
+
+/// A value which can be compared against the bounds of `#[darling(range = ...)]`.
+pub trait RangeValue {
+    /// The type of the range's bounds.
+    type Bound: PartialOrd + fmt::Display;
+
+    /// Get the value to compare, or `None` if there is nothing to check.
+    fn range_value(&self) -> Option<&Self::Bound>;
+}
+
+/// A value whose length can be checked by `#[darling(len = ...)]` and `#[darling(non_empty)]`.
+pub trait LenValue {
+    /// Get the length of the value, or `None` if there is nothing to check.
+    fn len_value(&self) -> Option<usize>;
+}
+
+/// A value which can be checked against the names given to `#[darling(one_of(...))]`.
+pub trait StrValue {
+    /// Get the value as a string, or `None` if there is nothing to check.
+    fn str_value(&self) -> Option<&str>;
+}
+
+/// Check that a value is within `range`.
+pub fn check_range<T, R>(value: &T, range: R) -> Result<()>
+where
+    T: RangeValue + ?Sized,
+    R: RangeBounds<T::Bound> + fmt::Debug,
+{
+    match value.range_value() {
+        Some(value) if !range.contains(value) => Err(Error::out_of_range(value, &range)),
+        _ => Ok(()),
+    }
+}
+
+/// Check that the length of a value is within `range`.
+pub fn check_len<T, R>(value: &T, range: R) -> Result<()>
+where
+    T: LenValue + ?Sized,
+    R: RangeBounds<usize> + fmt::Debug,
+{
+    match value.len_value() {
+        Some(len) if !range.contains(&len) => Err(Error::invalid_length(len, &range)),
+        _ => Ok(()),
+    }
+}
+
+/// Check that a value is not empty.
+pub fn check_non_empty<T: LenValue + ?Sized>(value: &T) -> Result<()> {
+    if value.len_value() == Some(0) {
+        Err(Error::empty_value())
+    } else {
+        Ok(())
+    }
+}
+
+/// Check that a value is one of the `allowed` strings.
+pub fn check_one_of<T: StrValue + ?Sized>(value: &T, allowed: &[&str]) -> Result<()> {
+    match value.str_value() {
+        Some(value) if !allowed.contains(&value) => {
+            Err(Error::unknown_value_with_alts(value, allowed))
+        }
+        _ => Ok(()),
+    }
+}
+
+macro_rules! range_value {
+    ($($ty:ty),*) => {
+        $(
+            impl RangeValue for $ty {
+                type Bound = $ty;
+
+                fn range_value(&self) -> Option<&Self::Bound> {
+                    Some(self)
+                }
+            }
+        )*
+    };
+}
+
+range_value!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, char);
+
+macro_rules! len_value {
+    ($(<$($param:ident),*> $ty:ty => $len:ident),* $(,)?) => {
+        $(
+            impl<$($param),*> LenValue for $ty {
+                fn len_value(&self) -> Option<usize> {
+                    Some(self.$len())
+                }
+            }
+        )*
+    };
+}
+
+len_value!(
+    <> str => len,
+    <> String => len,
+    <> PathList => len,
+    <T> [T] => len,
+    <T> Vec<T> => len,
+    <T, S> HashSet<T, S> => len,
+    <T> BTreeSet<T> => len,
+    <K, V, S> HashMap<K, V, S> => len,
+    <K, V> BTreeMap<K, V> => len,
+);
+
+impl StrValue for str {
+    fn str_value(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl StrValue for String {
+    fn str_value(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+macro_rules! wrapper {
+    ($(<$param:ident $(: ?$sized:ident)?> $ty:ty => |$v:ident| $inner:expr),* $(,)?) => {
+        $(
+            impl<$param: RangeValue $(+ ?$sized)?> RangeValue for $ty {
+                type Bound = $param::Bound;
+
+                fn range_value(&self) -> Option<&Self::Bound> {
+                    let $v = self;
+                    $inner.and_then(RangeValue::range_value)
+                }
+            }
+
+            impl<$param: LenValue $(+ ?$sized)?> LenValue for $ty {
+                fn len_value(&self) -> Option<usize> {
+                    let $v = self;
+                    $inner.and_then(LenValue::len_value)
+                }
+            }
+
+            impl<$param: StrValue $(+ ?$sized)?> StrValue for $ty {
+                fn str_value(&self) -> Option<&str> {
+                    let $v = self;
+                    $inner.and_then(StrValue::str_value)
+                }
+            }
+        )*
+    };
+}
+
+wrapper!(
+    <T> Option<T> => |v| v.as_ref(),
+    <T> SpannedValue<T> => |v| Some(&**v),
+    <T: ?Sized> Box<T> => |v| Some(&**v),
+    <T: ?Sized> &T => |v| Some(&**v),
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range() {
+        assert!(check_range(&5u32, 1..=10).is_ok());
+        assert!(check_range(&None::<u32>, 1..=10).is_ok());
+        assert_eq!(
+            check_range(&Some(0u16), 1..=65535).unwrap_err().to_string(),
+            "Value out of range: `0` is not in `1..=65535`"
+        );
+    }
+
+    #[test]
+    fn len() {
+        assert!(check_len("abc", 1..).is_ok());
+        assert_eq!(
+            check_len(&vec![1, 2, 3], ..=2).unwrap_err().to_string(),
+            "Invalid length: expected a length in `..=2`, found 3"
+        );
+        assert!(check_non_empty(&String::new()).is_err());
+        assert!(check_non_empty(&None::<String>).is_ok());
+    }
+
+    #[test]
+    fn one_of() {
+        assert!(check_one_of("a", &["a", "b"]).is_ok());
+        assert!(check_one_of(&Some("c".to_string()), &["a", "b"]).is_err());
+    }
+}
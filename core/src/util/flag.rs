@@ -1,7 +1,9 @@
+use std::borrow::Cow;
+
 use proc_macro2::Span;
 use syn::{spanned::Spanned, Meta};
 
-use crate::{FromMeta, Result};
+use crate::{FromMeta, Result, ToMeta};
 
 /// A meta-item that can be present as a word - with no value - or absent.
 ///
@@ -71,6 +73,10 @@ impl FromMeta for Flag {
         Some(Flag(None))
     }
 
+    fn expected() -> Option<Cow<'static, str>> {
+        <()>::expected()
+    }
+
     fn from_meta(mi: &syn::Meta) -> Result<Self> {
         if let Meta::Path(p) = mi {
             Ok(Flag(Some(p.span())))
@@ -82,6 +88,16 @@ impl FromMeta for Flag {
     }
 }
 
+impl ToMeta for Flag {
+    fn to_meta(&self, path: &syn::Path) -> Option<Meta> {
+        if self.is_present() {
+            Some(Meta::Path(path.clone()))
+        } else {
+            None
+        }
+    }
+}
+
 impl From<Flag> for bool {
     fn from(flag: Flag) -> Self {
         flag.is_present()
@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::fmt;
 use std::hash::{Hash, Hasher};
 
@@ -5,7 +6,7 @@ use proc_macro2::{Span, TokenStream};
 use quote::ToTokens;
 use syn::{Ident, Meta};
 
-use crate::{FromMeta, Result};
+use crate::{FromMeta, Result, ToMeta};
 
 #[cfg(feature = "serde")]
 mod serde;
@@ -136,11 +137,21 @@ impl fmt::Display for IdentString {
 }
 
 impl FromMeta for IdentString {
+    fn expected() -> Option<Cow<'static, str>> {
+        Ident::expected()
+    }
+
     fn from_meta(item: &Meta) -> Result<Self> {
         Ident::from_meta(item).map(IdentString::from)
     }
 }
 
+impl ToMeta for IdentString {
+    fn to_meta(&self, path: &syn::Path) -> Option<Meta> {
+        self.ident.to_meta(path)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use syn::parse_quote;
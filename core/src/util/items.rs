@@ -0,0 +1,52 @@
+use syn::punctuated::Punctuated;
+use syn::{FnArg, ImplItem, Token, TraitItem};
+
+use crate::{Error, FromFnArg, FromImplItemFn, FromTraitItemFn, Result};
+
+/// Parse every argument of a function signature using [`FromFnArg`].
+///
+/// Errors from all arguments are accumulated, rather than stopping at the first failure.
+/// Derived `FromSignature` and `FromFn` impls use this to populate the `inputs` magic field.
+pub fn parse_fn_args<T: FromFnArg>(inputs: &Punctuated<FnArg, Token![,]>) -> Result<Vec<T>> {
+    let mut errors = Error::accumulator();
+    let items = inputs
+        .iter()
+        .filter_map(|arg| errors.handle(T::from_fn_arg(arg)))
+        .collect();
+
+    errors.finish_with(items)
+}
+
+/// Parse every method of an impl block using [`FromImplItemFn`].
+///
+/// Items which are not methods, such as associated types and consts, are ignored.
+/// Errors from all methods are accumulated, rather than stopping at the first failure.
+pub fn parse_impl_fns<T: FromImplItemFn>(items: &[ImplItem]) -> Result<Vec<T>> {
+    let mut errors = Error::accumulator();
+    let items = items
+        .iter()
+        .filter_map(|item| match item {
+            ImplItem::Fn(item) => errors.handle(T::from_impl_item_fn(item)),
+            _ => None,
+        })
+        .collect();
+
+    errors.finish_with(items)
+}
+
+/// Parse every method of a trait definition using [`FromTraitItemFn`].
+///
+/// Items which are not methods, such as associated types and consts, are ignored.
+/// Errors from all methods are accumulated, rather than stopping at the first failure.
+pub fn parse_trait_fns<T: FromTraitItemFn>(items: &[TraitItem]) -> Result<Vec<T>> {
+    let mut errors = Error::accumulator();
+    let items = items
+        .iter()
+        .filter_map(|item| match item {
+            TraitItem::Fn(item) => errors.handle(T::from_trait_item_fn(item)),
+            _ => None,
+        })
+        .collect();
+
+    errors.finish_with(items)
+}
@@ -1,10 +1,13 @@
 //! Utility types for attribute parsing.
 
+mod build_meta;
 mod callable;
+pub mod constraint;
 pub mod extract_option;
 mod flag;
 mod ident_string;
 mod ignored;
+mod items;
 mod over_ride;
 mod parse_attribute;
 pub mod parse_expr;
@@ -16,10 +19,14 @@ mod shape;
 mod spanned_value;
 mod with_original;
 
+pub use self::build_meta::{
+    meta_list, meta_name_value, path_from_string, positional_item, variant_items,
+};
 pub use self::callable::Callable;
 pub use self::flag::Flag;
 pub use self::ident_string::IdentString;
 pub use self::ignored::Ignored;
+pub use self::items::{parse_fn_args, parse_impl_fns, parse_trait_fns};
 pub use self::over_ride::Override;
 pub use self::parse_attribute::parse_attribute_to_meta_list;
 pub use self::path_list::PathList;
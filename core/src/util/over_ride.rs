@@ -1,9 +1,10 @@
+use std::borrow::Cow;
 use std::fmt;
 
 use syn::Lit;
 
 use crate::ast::NestedMeta;
-use crate::{FromMeta, Result};
+use crate::{FromMeta, Result, ToMeta};
 
 use self::Override::*;
 
@@ -137,6 +138,10 @@ impl<T: fmt::Display> fmt::Display for Override<T> {
 /// Parses a `Meta`. A bare word will produce `Override::Inherit`, while
 /// any value will be forwarded to `T::from_meta`.
 impl<T: FromMeta> FromMeta for Override<T> {
+    fn expected() -> Option<Cow<'static, str>> {
+        T::expected().map(|expected| format!("a word or {}", expected).into())
+    }
+
     fn from_meta(item: &syn::Meta) -> Result<Self> {
         match item {
             syn::Meta::Path(_) => Self::from_word(),
@@ -172,3 +177,12 @@ impl<T: FromMeta> FromMeta for Override<T> {
         Ok(Explicit(FromMeta::from_bool(value)?))
     }
 }
+
+impl<T: ToMeta> ToMeta for Override<T> {
+    fn to_meta(&self, path: &syn::Path) -> Option<syn::Meta> {
+        match *self {
+            Inherit => Some(syn::Meta::Path(path.clone())),
+            Explicit(ref val) => val.to_meta(path),
+        }
+    }
+}
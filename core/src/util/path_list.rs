@@ -1,9 +1,10 @@
+use std::borrow::Cow;
 use std::ops::Deref;
 
 use syn::{Meta, Path};
 
 use crate::ast::NestedMeta;
-use crate::{Error, FromMeta, Result};
+use crate::{Error, FromMeta, Result, ToMeta};
 
 use super::path_to_string;
 
@@ -62,6 +63,10 @@ impl From<Vec<Path>> for PathList {
 }
 
 impl FromMeta for PathList {
+    fn expected() -> Option<Cow<'static, str>> {
+        Some(Cow::Borrowed("a list of paths"))
+    }
+
     fn from_list(v: &[NestedMeta]) -> Result<Self> {
         let mut paths = Vec::with_capacity(v.len());
         for nmi in v {
@@ -76,6 +81,12 @@ impl FromMeta for PathList {
     }
 }
 
+impl ToMeta for PathList {
+    fn to_meta(&self, path: &Path) -> Option<Meta> {
+        self.0.to_meta(path)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::PathList;
@@ -1,4 +1,5 @@
-use crate::{FromMeta, Result};
+use crate::{FromMeta, Result, ToMeta};
+use std::borrow::Cow;
 use syn::Expr;
 
 /// A wrapper around [`Expr`] that preserves the original expression
@@ -26,11 +27,21 @@ use syn::Expr;
 pub struct PreservedStrExpr(pub Expr);
 
 impl FromMeta for PreservedStrExpr {
+    fn expected() -> Option<Cow<'static, str>> {
+        Some(Cow::Borrowed("an expression"))
+    }
+
     fn from_expr(expr: &Expr) -> Result<Self> {
         Ok(Self(expr.clone()))
     }
 }
 
+impl ToMeta for PreservedStrExpr {
+    fn to_meta(&self, path: &syn::Path) -> Option<syn::Meta> {
+        self.0.to_meta(path)
+    }
+}
+
 impl From<Expr> for PreservedStrExpr {
     fn from(value: Expr) -> Self {
         Self(value)
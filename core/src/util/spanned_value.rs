@@ -1,10 +1,11 @@
 use proc_macro2::Span;
+use std::borrow::Cow;
 use std::ops::{Deref, DerefMut};
 use syn::spanned::Spanned;
 
 use crate::{
     FromDeriveInput, FromField, FromGenericParam, FromGenerics, FromMeta, FromTypeParam,
-    FromVariant, Result,
+    FromVariant, Result, ToMeta,
 };
 
 /// A value and an associated position in source code. The main use case for this is
@@ -86,6 +87,18 @@ macro_rules! spanned {
 }
 
 impl<T: FromMeta> FromMeta for SpannedValue<T> {
+    fn known_keys() -> Option<Vec<&'static str>> {
+        T::known_keys()
+    }
+
+    fn describe() -> Option<crate::schema::Schema> {
+        T::describe()
+    }
+
+    fn expected() -> Option<Cow<'static, str>> {
+        T::expected()
+    }
+
     fn from_meta(item: &syn::Meta) -> Result<Self> {
         let value = T::from_meta(item).map_err(|e| e.with_span(item))?;
         let span = match item {
@@ -122,6 +135,16 @@ impl<T: FromMeta> FromMeta for SpannedValue<T> {
     }
 }
 
+impl<T: ToMeta> ToMeta for SpannedValue<T> {
+    fn to_meta(&self, path: &syn::Path) -> Option<syn::Meta> {
+        self.value.to_meta(path)
+    }
+
+    fn to_nested_meta(&self) -> Vec<crate::ast::NestedMeta> {
+        self.value.to_nested_meta()
+    }
+}
+
 spanned!(FromGenericParam, from_generic_param, syn::GenericParam);
 spanned!(FromGenerics, from_generics, syn::Generics);
 spanned!(FromTypeParam, from_type_param, syn::TypeParam);
@@ -0,0 +1,363 @@
+//! Non-fatal diagnostics produced during attribute parsing.
+//!
+//! Unlike [`Error`](crate::Error), a [`Warning`] does not stop parsing. Derived impls record
+//! warnings - for example when a caller uses a key marked `#[darling(deprecated)]` - with [`push`].
+//! Hand-written `FromMeta` impls and validation functions can record their own soft violations
+//! the same way.
+//!
+//! The proc-macro author collects the warnings recorded while parsing with [`Warnings::capture`],
+//! or with [`FromDeriveInput::from_derive_input_with_warnings`](crate::FromDeriveInput::from_derive_input_with_warnings)
+//! and [`FromMeta::from_meta_with_warnings`](crate::FromMeta::from_meta_with_warnings), and writes
+//! them into the macro output with [`Warnings::write`]. Warnings recorded outside of a capture
+//! are discarded, so nothing carries over from one macro invocation to the next. In particular,
+//! calling `from_derive_input` or `from_meta` on their own drops every warning.
+//!
+//! # Example
+//! ```rust,ignore
+//! let (receiver, warnings) = MyReceiver::from_derive_input_with_warnings(&input);
+//! let receiver = match receiver {
+//!     Ok(receiver) => receiver,
+//!     Err(err) => return err.write_errors(),
+//! };
+//!
+//! let warnings = warnings.write();
+//!
+//! quote! {
+//!     #warnings
+//!     #receiver
+//! }
+//! ```
+
+use std::cell::RefCell;
+use std::{fmt, mem, slice, vec};
+
+use proc_macro2::{Span, TokenStream};
+use syn::spanned::Spanned;
+
+/// A non-fatal diagnostic encountered during attribute parsing.
+#[derive(Debug, Clone)]
+pub struct Warning {
+    message: String,
+    span: Option<Span>,
+}
+
+impl Warning {
+    /// Create a new warning with a custom message.
+    pub fn new<T: fmt::Display>(message: T) -> Self {
+        Warning {
+            message: message.to_string(),
+            span: None,
+        }
+    }
+
+    /// Create a warning that a deprecated key was used, with an optional note
+    /// explaining what to use instead.
+    pub fn deprecated(name: &str, note: Option<&str>) -> Self {
+        match note {
+            Some(note) => Warning::new(format!("`{}` is deprecated: {}", name, note)),
+            None => Warning::new(format!("`{}` is deprecated", name)),
+        }
+    }
+
+    /// Tie a span to the warning if none is already present.
+    pub fn with_span<T: Spanned>(mut self, node: &T) -> Self {
+        if self.span.is_none() {
+            self.span = Some(node.span());
+        }
+
+        self
+    }
+
+    /// Get the span for the warning, or [`Span::call_site()`] if none has been set.
+    pub fn span(&self) -> Span {
+        self.span.unwrap_or_else(Span::call_site)
+    }
+
+    /// Get the message of the warning.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Write this warning into tokens that will make the compiler display it.
+    ///
+    /// On stable Rust, this produces a reference to a `#[deprecated]` item whose note is
+    /// the warning message, spanned to the warning's location. With the `diagnostics`
+    /// feature, the warning is emitted directly and the returned tokens are empty.
+    pub fn write(self) -> TokenStream {
+        #[cfg(feature = "diagnostics")]
+        {
+            self.emit();
+            TokenStream::default()
+        }
+
+        #[cfg(not(feature = "diagnostics"))]
+        {
+            let message = &self.message;
+            let usage = quote::quote_spanned!(self.span()=> let _ = darling_warning;);
+            quote::quote_spanned! {self.span()=>
+                const _: () = {
+                    #[deprecated(note = #message)]
+                    #[allow(non_upper_case_globals)]
+                    const darling_warning: () = ();
+                    #usage
+                };
+            }
+        }
+    }
+
+    /// Write this warning into tokens that will make the compiler display it, using a
+    /// `#[must_use]` function instead of a `#[deprecated]` item.
+    ///
+    /// This is useful when the caller's crate may have `#![allow(deprecated)]` set, which
+    /// would silence warnings written with [`Warning::write`]. With the `diagnostics`
+    /// feature, the warning is emitted directly and the returned tokens are empty.
+    pub fn write_must_use(self) -> TokenStream {
+        #[cfg(feature = "diagnostics")]
+        {
+            self.emit();
+            TokenStream::default()
+        }
+
+        #[cfg(not(feature = "diagnostics"))]
+        {
+            let message = &self.message;
+            let usage = quote::quote_spanned!(self.span()=> darling_warning(););
+            quote::quote_spanned! {self.span()=>
+                const _: () = {
+                    #[allow(dead_code)]
+                    struct DarlingWarning;
+                    #[allow(dead_code)]
+                    #[must_use = #message]
+                    fn darling_warning() -> DarlingWarning {
+                        DarlingWarning
+                    }
+                    fn _darling_warning() {
+                        #usage
+                    }
+                };
+            }
+        }
+    }
+
+    /// Emit this warning as a compiler diagnostic.
+    ///
+    /// # Panics
+    /// This method panics if invoked outside of a proc-macro.
+    #[cfg(feature = "diagnostics")]
+    pub fn emit(self) {
+        self.span().unwrap().warning(self.message).emit()
+    }
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+/// A collection of [`Warning`]s gathered while parsing.
+///
+/// Unlike [`Accumulator`](crate::error::Accumulator), this has no drop bomb: ignoring
+/// warnings is allowed, though usually they should be written into the macro output.
+#[derive(Debug, Clone, Default)]
+pub struct Warnings(Vec<Warning>);
+
+impl Warnings {
+    /// Create an empty collection.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run a closure and collect the warnings recorded on the current thread while it ran.
+    ///
+    /// Captures can be nested; each warning goes to the innermost one. If the closure panics,
+    /// its warnings are discarded and any enclosing capture is left as it was.
+    pub fn capture<T, F: FnOnce() -> T>(f: F) -> (T, Self) {
+        let scope = CaptureScope::begin();
+        let value = f();
+        (value, Warnings(scope.end()))
+    }
+
+    /// Add one warning to the collection.
+    pub fn push(&mut self, warning: Warning) {
+        self.0.push(warning);
+    }
+
+    /// Gets the number of warnings in the collection.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the collection contains no warnings.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterate over the warnings in the order they were recorded.
+    pub fn iter(&self) -> slice::Iter<'_, Warning> {
+        self.0.iter()
+    }
+
+    /// Write all warnings into tokens using [`Warning::write`].
+    pub fn write(self) -> TokenStream {
+        self.0.into_iter().map(Warning::write).collect()
+    }
+
+    /// Write all warnings into tokens using [`Warning::write_must_use`].
+    pub fn write_must_use(self) -> TokenStream {
+        self.0.into_iter().map(Warning::write_must_use).collect()
+    }
+
+    /// Emit all warnings as compiler diagnostics.
+    ///
+    /// # Panics
+    /// This method panics if invoked outside of a proc-macro.
+    #[cfg(feature = "diagnostics")]
+    pub fn emit(self) {
+        for warning in self.0 {
+            warning.emit();
+        }
+    }
+}
+
+impl Extend<Warning> for Warnings {
+    fn extend<I: IntoIterator<Item = Warning>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl FromIterator<Warning> for Warnings {
+    fn from_iter<I: IntoIterator<Item = Warning>>(iter: I) -> Self {
+        Warnings(iter.into_iter().collect())
+    }
+}
+
+impl From<Vec<Warning>> for Warnings {
+    fn from(warnings: Vec<Warning>) -> Self {
+        Warnings(warnings)
+    }
+}
+
+impl IntoIterator for Warnings {
+    type Item = Warning;
+    type IntoIter = vec::IntoIter<Warning>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Warnings {
+    type Item = &'a Warning;
+    type IntoIter = slice::Iter<'a, Warning>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+thread_local! {
+    /// The warnings recorded by each active capture on this thread, innermost last.
+    static CAPTURES: RefCell<Vec<Vec<Warning>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Record a warning in the innermost [`Warnings::capture`] on the current thread.
+///
+/// Derived impls call this for soft violations such as deprecated keys. If no capture is
+/// active, the warning is discarded.
+pub fn push(warning: Warning) {
+    CAPTURES.with(|captures| {
+        if let Some(current) = captures.borrow_mut().last_mut() {
+            current.push(warning);
+        }
+    });
+}
+
+/// An active capture, which is removed when dropped so that a panic can't leave it in place.
+struct CaptureScope;
+
+impl CaptureScope {
+    fn begin() -> Self {
+        CAPTURES.with(|captures| captures.borrow_mut().push(Vec::new()));
+        CaptureScope
+    }
+
+    /// Take the warnings recorded in this capture.
+    fn end(self) -> Vec<Warning> {
+        CAPTURES.with(|captures| {
+            captures
+                .borrow_mut()
+                .last_mut()
+                .map(mem::take)
+                .unwrap_or_default()
+        })
+    }
+}
+
+impl Drop for CaptureScope {
+    fn drop(&mut self) {
+        CAPTURES.with(|captures| captures.borrow_mut().pop());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic;
+
+    use super::{push, Warning, Warnings, CAPTURES};
+
+    #[test]
+    fn capture_collects_warnings() {
+        let ((), warnings) = Warnings::capture(|| {
+            push(Warning::deprecated("old", Some("use `new` instead")));
+            push(Warning::deprecated("older", None));
+        });
+
+        let warnings: Vec<_> = warnings.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            warnings,
+            vec![
+                "`old` is deprecated: use `new` instead",
+                "`older` is deprecated"
+            ]
+        );
+    }
+
+    #[test]
+    fn capture_is_nested() {
+        let ((), outer) = Warnings::capture(|| {
+            push(Warning::new("before"));
+            let ((), inner) = Warnings::capture(|| push(Warning::new("inner")));
+            assert_eq!(inner.iter().next().unwrap().message(), "inner");
+            push(Warning::new("after"));
+        });
+
+        let outer: Vec<_> = outer.iter().map(Warning::message).collect();
+        assert_eq!(outer, vec!["before", "after"]);
+    }
+
+    #[test]
+    fn uncaptured_warnings_are_discarded() {
+        push(Warning::new("lost"));
+        let ((), warnings) = Warnings::capture(|| {});
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn panic_ends_capture() {
+        let ((), outer) = Warnings::capture(|| {
+            push(Warning::new("outer"));
+            let result = panic::catch_unwind(|| {
+                Warnings::capture(|| {
+                    push(Warning::new("inner"));
+                    panic!("oops");
+                })
+            });
+            assert!(result.is_err());
+        });
+
+        assert_eq!(outer.iter().next().unwrap().message(), "outer");
+        assert_eq!(outer.len(), 1);
+        assert!(CAPTURES.with(|captures| captures.borrow().is_empty()));
+    }
+}
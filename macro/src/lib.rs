@@ -7,6 +7,11 @@ pub fn derive_from_meta(input: TokenStream) -> TokenStream {
     derive::from_meta(&parse_macro_input!(input)).into()
 }
 
+#[proc_macro_derive(ToMeta, attributes(darling))]
+pub fn derive_to_meta(input: TokenStream) -> TokenStream {
+    derive::to_meta(&parse_macro_input!(input)).into()
+}
+
 #[proc_macro_derive(FromMetaItem, attributes(darling))]
 pub fn derive_from_meta_item(_input: TokenStream) -> TokenStream {
     Error::custom("darling::FromMetaItem has been replaced by darling::FromMeta")
@@ -29,11 +34,61 @@ pub fn derive_field(input: TokenStream) -> TokenStream {
     derive::from_field(&parse_macro_input!(input)).into()
 }
 
+#[proc_macro_derive(FromFn, attributes(darling))]
+pub fn derive_fn(input: TokenStream) -> TokenStream {
+    derive::from_fn(&parse_macro_input!(input)).into()
+}
+
+#[proc_macro_derive(FromFnArg, attributes(darling))]
+pub fn derive_fn_arg(input: TokenStream) -> TokenStream {
+    derive::from_fn_arg(&parse_macro_input!(input)).into()
+}
+
+#[proc_macro_derive(FromImplBlock, attributes(darling))]
+pub fn derive_impl_block(input: TokenStream) -> TokenStream {
+    derive::from_impl_block(&parse_macro_input!(input)).into()
+}
+
+#[proc_macro_derive(FromImplItemFn, attributes(darling))]
+pub fn derive_impl_item_fn(input: TokenStream) -> TokenStream {
+    derive::from_impl_item_fn(&parse_macro_input!(input)).into()
+}
+
+#[proc_macro_derive(FromTraitDef, attributes(darling))]
+pub fn derive_trait_def(input: TokenStream) -> TokenStream {
+    derive::from_trait_def(&parse_macro_input!(input)).into()
+}
+
+#[proc_macro_derive(FromTraitItemFn, attributes(darling))]
+pub fn derive_trait_item_fn(input: TokenStream) -> TokenStream {
+    derive::from_trait_item_fn(&parse_macro_input!(input)).into()
+}
+
+#[proc_macro_derive(FromSignature, attributes(darling))]
+pub fn derive_signature(input: TokenStream) -> TokenStream {
+    derive::from_signature(&parse_macro_input!(input)).into()
+}
+
 #[proc_macro_derive(FromTypeParam, attributes(darling))]
 pub fn derive_type_param(input: TokenStream) -> TokenStream {
     derive::from_type_param(&parse_macro_input!(input)).into()
 }
 
+#[proc_macro_derive(FromLifetimeParam, attributes(darling))]
+pub fn derive_lifetime_param(input: TokenStream) -> TokenStream {
+    derive::from_lifetime_param(&parse_macro_input!(input)).into()
+}
+
+#[proc_macro_derive(FromConstParam, attributes(darling))]
+pub fn derive_const_param(input: TokenStream) -> TokenStream {
+    derive::from_const_param(&parse_macro_input!(input)).into()
+}
+
+#[proc_macro_derive(FromGenericParam, attributes(darling))]
+pub fn derive_generic_param(input: TokenStream) -> TokenStream {
+    derive::from_generic_param(&parse_macro_input!(input)).into()
+}
+
 #[proc_macro_derive(FromVariant, attributes(darling))]
 pub fn derive_variant(input: TokenStream) -> TokenStream {
     derive::from_variant(&parse_macro_input!(input)).into()
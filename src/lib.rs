@@ -10,6 +10,7 @@
 //!
 //! Proc-macro crates should provide their own structs which implement or derive `FromDeriveInput`,
 //! `FromField`, `FromVariant`, `FromGenerics`, _et alia_ to gather settings relevant to their operation.
+//! Attribute macros on functions can do the same using `FromFn`, `FromSignature`, and `FromFnArg`.
 //!
 //! ## Attributes
 //! There are a number of attributes that `darling` exposes to enable finer-grained control over the code
@@ -17,7 +18,42 @@
 //!
 //! * **Field renaming**: You can use `#[darling(rename="new_name")]` on a field to change the name Darling looks for.
 //!   You can also use `#[darling(rename_all="...")]` at the struct or enum level to apply a casing rule to all fields or variants.
+//! * **Aliases**: You can use `#[darling(alias = "old_name")]` on a field or variant to accept additional names, for example
+//!   to keep accepting a key after renaming it. This can be repeated. Use `#[darling(alias(name = "old_name", hidden))]` to
+//!   accept the alias without suggesting it in "did you mean" errors. Each name or alias may only be used by one field or variant.
+//! * **Deprecated keys**: You can use `#[darling(deprecated = "use `foo` instead")]` (or just `#[darling(deprecated)]`) on a field
+//!   or variant. Parsing still succeeds when the key is used, but a [`Warning`] is recorded; collect these with
+//!   [`FromDeriveInput::from_derive_input_with_warnings`] or [`Warnings::capture`] and write them into the macro output
+//!   to show them to the caller. Calling `from_derive_input` on its own discards them.
 //! * **Map function**: You can use `#[darling(map="path::to::function")]` to run code on a field before it's stored in the struct.
+//! * **Constraints**: You can use `#[darling(range = 1..=10)]`, `#[darling(len = 1..)]`, `#[darling(non_empty)]` and
+//!   `#[darling(one_of("a", "b"))]` on a field to check its parsed value. Each violation is reported as a spanned error, and
+//!   all of them are accumulated. On `multiple` fields, `len` and `non_empty` apply to the number of values, while `range`
+//!   and `one_of` apply to each value. See [`util::constraint`] for the supported types.
+//! * **Field relations**: You can use `#[darling(conflicts_with = "other")]`, `#[darling(requires = "other")]` and
+//!   `#[darling(required_if(other = value))]` on a field, where `other` is the Rust name of another field in the same
+//!   struct or variant. The rules are checked after parsing, and errors point to the keys involved. `required_if` parses
+//!   `value` as the other field's type and compares it using `PartialEq`. Since this happens when the macro runs, a `value`
+//!   which isn't valid for that type is reported as an error whenever the other field is present.
+//! * **Flattening**: You can use `#[darling(flatten)]` on a field to read its keys from the parent's list. When several
+//!   fields are flattened, each is given the keys its type claims, as returned by [`FromMeta::known_keys`]. Keys that no
+//!   field claims go to the field marked `#[darling(flatten, catch_all)]`, such as a `HashMap`, or are reported as unknown.
+//!   A type whose `known_keys` is `None` can't be given any keys this way, so it must be the catch-all when other fields
+//!   are flattened; otherwise parsing fails with an error naming the field.
+//!   Fields of struct-like enum variants can be flattened too, and `#[darling(allow_unknown_fields)]` can be set per variant.
+//! * **Exclusive keys**: You can use `#[darling(one_of_keys)]` on a field whose type is an enum deriving `FromMeta`. The
+//!   enum's variant names become keys of the parent, so `source: Source` accepts `path = "..."` or `url = "..."` directly.
+//!   Exactly one key is required, or at most one if the field has a default or is an `Option`, and giving more than one is a single
+//!   error that names every key given. The keys come from [`FromMeta::known_keys`].
+//! * **Schema introspection**: You can use `#[darling(schema)]` on a type deriving `FromMeta`, `FromDeriveInput` or
+//!   `FromAttributes` to generate `fn schema() -> darling::schema::Schema`. It describes the accepted keys with their types,
+//!   defaults, aliases and options such as `multiple` or `flatten`, an enum's variants, and the schemas of nested types which
+//!   also opted in, as returned by [`FromMeta::describe`]. [`docs::markdown`] renders a schema as a Markdown reference of the
+//!   accepted keys, including the `///` comments of the receiver's fields and variants.
+//! * **Validation**: You can use `#[darling(validate = path::to::function)]` on a struct or enum to check the parsed value
+//!   as a whole. The function takes `&Self` and `&mut darling::error::Accumulator`, and runs once all fields are present
+//!   and parsed without errors; everything it pushes is returned together as a single error. An enum's value is checked
+//!   whichever `FromMeta` method produced it, so calling `from_string` or `from_list` directly also runs the function.
 //! * **Default values**: You can use `#[darling(default)]` at the type or field level to use that type's default value to fill
 //!   in values not specified by the caller. You can also set a custom default value by passing in a function path or a closure:
 //!   `#[darling(default = path::to::function)]` or `#[darling(default = || get_default())]`.
@@ -38,6 +74,25 @@
 //! * **Transparency**: Tuple structs or tuple variants with a single field delegate trait implementations
 //!   to the inner type. If the field is named, you can use `#[darling(transparent)]` on the container for
 //!   the same effect
+//! * **Positional arguments**: Tuple structs with several fields are parsed from their items in order, so
+//!   `struct Range(u32, u32)` accepts `range(1, 10)` and rejects named items such as `range(a = 1, 10)`. In structs with named fields, `#[darling(positional)]`
+//!   lets a field be given as a leading literal, e.g. `#[route("/path", method = "GET")]`, as well as by name.
+//! * **Enum representation**: By default, a `FromMeta` enum is written as the variant name wrapping its fields, e.g.
+//!   `cache(lru(size = 10))`. Use `#[darling(tag = "kind")]` on the enum to select the variant from the value of a key
+//!   instead, parsing the remaining keys into the variant, e.g. `cache(kind = "lru", size = 10)`. Use `#[darling(untagged)]`
+//!   to try each variant in order; if none succeeds, the errors from every attempt are returned together.
+//! * **Bare variant names**: Unit variants of a `FromMeta` enum can be written as a string, a bare identifier, or a path
+//!   prefixed with the enum's name, so `mode = "fast"`, `mode = fast`, `mode = Fast` and `mode = Mode::Fast` are all accepted.
+//!   The variant's Rust name is accepted in addition to its name in attributes. This applies to enums whose variants are
+//!   all unit variants, apart from an `other` variant, which receives any path that doesn't name another variant.
+//! * **Integer discriminants**: Add `#[darling(discriminant)]` to a `FromMeta` enum whose variants are all unit variants to
+//!   also accept integers, such as `code = 3`, which are matched against each variant's explicit or implicit discriminant.
+//! * **Catch-all variant**: Use `#[darling(other)]` on one variant of a `FromMeta` enum to produce it for any name that doesn't
+//!   match another variant, instead of an error. The variant can be a unit variant, or a newtype holding the unrecognized name
+//!   as a `String`, `syn::Path`, `SpannedValue<String>`, or any other type that implements `FromMeta`.
+//! * **Round-tripping**: Deriving `ToMeta` alongside `FromMeta` generates the inverse conversion, turning a parsed
+//!   value back into a `syn::Meta` that honors the same `rename`, `rename_all`, `skip`, `multiple` and `flatten` options.
+//!   This is useful for re-emitting options onto generated items.
 //!
 //! ## Forwarded Fields
 //! All derivable traits except `FromMeta` support forwarding some fields from the input AST to the derived struct.
@@ -72,6 +127,78 @@
 //! |`default`|`Option<syn::Type>`|The default type of the parameter, if one exists|
 //! |`attrs`|`Vec<syn::Attribute>` (or anything, using `#[darling(with = ...)]`)|The forwarded attributes from the passed in type param. These are controlled using the `forward_attrs` attribute.|
 //!
+//! ### [`FromLifetimeParam`]
+//! |Field name|Type|Meaning|
+//! |---|---|---|
+//! |`ident`|`syn::Ident` (or anything, using `#[darling(with = ...)]`)|The identifier of the passed-in lifetime param, without the leading `'`|
+//! |`lifetime`|`syn::Lifetime`|The passed-in lifetime|
+//! |`bounds`|`Vec<syn::Lifetime>`|The bounds applied to the lifetime param|
+//! |`attrs`|`Vec<syn::Attribute>` (or anything, using `#[darling(with = ...)]`)|The forwarded attributes from the passed in lifetime param. These are controlled using the `forward_attrs` attribute.|
+//!
+//! ### [`FromConstParam`]
+//! |Field name|Type|Meaning|
+//! |---|---|---|
+//! |`ident`|`syn::Ident` (or anything, using `#[darling(with = ...)]`)|The identifier of the passed-in const param|
+//! |`ty`|`syn::Type`|The type of the const param|
+//! |`default`|`Option<syn::Expr>`|The default value of the parameter, if one exists|
+//! |`attrs`|`Vec<syn::Attribute>` (or anything, using `#[darling(with = ...)]`)|The forwarded attributes from the passed in const param. These are controlled using the `forward_attrs` attribute.|
+//!
+//! ### [`FromGenericParam`]
+//! |Field name|Type|Meaning|
+//! |---|---|---|
+//! |`ident`|`syn::Ident` (or anything, using `#[darling(with = ...)]`)|The identifier of the passed-in param; for lifetimes, this omits the leading `'`|
+//! |`attrs`|`Vec<syn::Attribute>` (or anything, using `#[darling(with = ...)]`)|The forwarded attributes from the passed in param. These are controlled using the `forward_attrs` attribute.|
+//!
+//! ### [`FromFn`]
+//! |Field name|Type|Meaning|
+//! |---|---|---|
+//! |`ident`|`syn::Ident` (or anything, using `#[darling(with = ...)]`)|The identifier of the passed-in function|
+//! |`vis`|`syn::Visibility`|The visibility of the passed-in function|
+//! |`sig`|`T: darling::FromSignature` (or anything, using `#[darling(with = ...)]`)|The signature of the passed-in function|
+//! |`inputs`|`Vec<T: darling::FromFnArg>` (or anything, using `#[darling(with = ...)]`)|The arguments of the passed-in function, including any `self` receiver|
+//! |`output`|`syn::ReturnType`|The return type of the passed-in function|
+//! |`asyncness`|`bool`|Whether the passed-in function is `async`|
+//! |`constness`|`bool`|Whether the passed-in function is `const`|
+//! |`generics`|`T: darling::FromGenerics` (or anything, using `#[darling(with = ...)]`)|The generics of the passed-in function|
+//! |`block`|`Box<syn::Block>`|The body of the passed-in function|
+//! |`attrs`|`Vec<syn::Attribute>` (or anything, using `#[darling(with = ...)]`)|The forwarded attributes from the passed in function. These are controlled using the `forward_attrs` attribute.|
+//!
+//! ### [`FromImplItemFn`] and [`FromTraitItemFn`]
+//! These accept the same fields as [`FromFn`], with two differences: the `block` of an impl method is a `syn::Block`,
+//! and the `block` of a trait method is an `Option<syn::Block>` holding the default body, if any.
+//! Trait methods do not have a `vis` field.
+//!
+//! ### [`FromSignature`]
+//! Signatures do not have attributes, so this accepts the signature-related fields of [`FromFn`]: `ident`, `inputs`,
+//! `output`, `asyncness`, `constness`, and `generics`.
+//!
+//! ### [`FromImplBlock`]
+//! |Field name|Type|Meaning|
+//! |---|---|---|
+//! |`self_ty`|`syn::Type`|The type the impl block is for|
+//! |`trait_`|`Option<syn::Path>`|The trait being implemented, or `None` for inherent impls|
+//! |`generics`|`T: darling::FromGenerics` (or anything, using `#[darling(with = ...)]`)|The generics of the impl block|
+//! |`items`|`Vec<T: darling::FromImplItemFn>` (or anything, using `#[darling(with = ...)]`)|The methods of the impl block; other items are skipped|
+//! |`attrs`|`Vec<syn::Attribute>` (or anything, using `#[darling(with = ...)]`)|The forwarded attributes from the passed in impl block. These are controlled using the `forward_attrs` attribute.|
+//!
+//! ### [`FromTraitDef`]
+//! |Field name|Type|Meaning|
+//! |---|---|---|
+//! |`ident`|`syn::Ident` (or anything, using `#[darling(with = ...)]`)|The identifier of the passed-in trait|
+//! |`vis`|`syn::Visibility`|The visibility of the passed-in trait|
+//! |`supertraits`|`Vec<syn::TypeParamBound>`|The supertraits of the passed-in trait|
+//! |`generics`|`T: darling::FromGenerics` (or anything, using `#[darling(with = ...)]`)|The generics of the passed-in trait|
+//! |`items`|`Vec<T: darling::FromTraitItemFn>` (or anything, using `#[darling(with = ...)]`)|The methods of the trait; other items are skipped|
+//! |`attrs`|`Vec<syn::Attribute>` (or anything, using `#[darling(with = ...)]`)|The forwarded attributes from the passed in trait. These are controlled using the `forward_attrs` attribute.|
+//!
+//! ### [`FromFnArg`]
+//! |Field name|Type|Meaning|
+//! |---|---|---|
+//! |`ident`|`Option<syn::Ident>` (or anything, using `#[darling(with = ...)]`)|The identifier bound by the passed-in argument; this is `self` for receivers and `None` for destructuring patterns|
+//! |`pat`|`Option<syn::Pat>`|The pattern of the passed-in argument, or `None` for receivers|
+//! |`ty`|`syn::Type`|The type of the passed-in argument|
+//! |`attrs`|`Vec<syn::Attribute>` (or anything, using `#[darling(with = ...)]`)|The forwarded attributes from the passed in argument. These are controlled using the `forward_attrs` attribute.|
+//!
 //! ### [`FromVariant`]
 //! |Field name|Type|Meaning|
 //! |---|---|---|
@@ -90,15 +217,21 @@ pub use darling_macro::*;
 
 #[doc(inline)]
 pub use darling_core::{
-    FromAttributes, FromDeriveInput, FromField, FromGenericParam, FromGenerics, FromMeta,
-    FromTypeParam, FromVariant,
+    FromAttributes, FromConstParam, FromDeriveInput, FromField, FromFn, FromFnArg,
+    FromGenericParam, FromGenerics, FromImplBlock, FromImplItemFn, FromLifetimeParam, FromMeta,
+    FromSignature, FromTraitDef, FromTraitItemFn, FromTypeParam, FromVariant, ToMeta, Warning,
+    Warnings,
 };
 
 #[doc(inline)]
 pub use darling_core::{Error, Result};
 
 #[doc(inline)]
-pub use darling_core::{ast, error, usage, util};
+pub use darling_core::{ast, docs, error, schema, usage, util, warning};
+
+#[cfg(feature = "testing")]
+#[doc(inline)]
+pub use darling_core::testing;
 
 // XXX exported so that `ExtractAttribute::extractor` can convert a path into tokens.
 // This is likely to change in the future, so only generated code should depend on this export.
@@ -116,9 +249,11 @@ pub mod export {
     pub use core::option::Option::{self, None, Some};
     pub use core::result::Result::{self, Err, Ok};
     pub use darling_core::syn;
+    pub use std::borrow::Cow;
+    pub use std::boxed::Box;
     pub use std::clone::Clone;
     pub use std::marker::PhantomData;
-    pub use std::string::ToString;
+    pub use std::string::{String, ToString};
     pub use std::vec::Vec;
 
     pub use crate::ast::NestedMeta;
@@ -127,5 +262,8 @@ pub mod export {
 #[doc(hidden)]
 pub use darling_core::autoref_specialization;
 
+#[doc(hidden)]
+pub use darling_core::from_meta_with_expected;
+
 #[macro_use]
 mod macros_public;
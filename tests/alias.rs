@@ -0,0 +1,82 @@
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+enum Mode {
+    #[darling(alias = "quick", alias(name = "zoom", hidden))]
+    Fast,
+    Slow,
+    #[darling(alias = "custom")]
+    Manual {
+        speed: u8,
+    },
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(demo))]
+struct Receiver {
+    #[darling(alias = "label", alias(name = "title", hidden))]
+    name: String,
+    #[darling(multiple, alias = "tags")]
+    tag: Vec<String>,
+}
+
+#[test]
+fn field_alias() {
+    let di = parse_quote! {
+        #[demo(label = "hello", tags = "a", tag = "b")]
+        pub struct Foo;
+    };
+
+    let parsed = Receiver::from_derive_input(&di).unwrap();
+    assert_eq!(parsed.name, "hello");
+    assert_eq!(parsed.tag, vec!["a", "b"]);
+}
+
+#[test]
+fn hidden_field_alias() {
+    let di = parse_quote! {
+        #[demo(title = "hello")]
+        pub struct Foo;
+    };
+
+    assert_eq!(Receiver::from_derive_input(&di).unwrap().name, "hello");
+}
+
+#[test]
+fn alias_and_canonical_are_duplicates() {
+    let di = parse_quote! {
+        #[demo(name = "a", label = "b")]
+        pub struct Foo;
+    };
+
+    let err = Receiver::from_derive_input(&di).unwrap_err();
+    assert_eq!(err.to_string(), "Duplicate field `name`");
+}
+
+#[test]
+fn variant_alias() {
+    assert_eq!(Mode::from_string("quick").unwrap(), Mode::Fast);
+    assert_eq!(Mode::from_string("zoom").unwrap(), Mode::Fast);
+    assert_eq!(
+        Mode::from_list(&[parse_quote!(custom(speed = 3))]).unwrap(),
+        Mode::Manual { speed: 3 }
+    );
+}
+
+#[cfg(feature = "suggestions")]
+#[test]
+fn hidden_aliases_are_not_suggested() {
+    let err = Mode::from_string("zooom").unwrap_err().to_string();
+    assert!(!err.contains("zoom"), "{}", err);
+
+    let err = Mode::from_string("quik").unwrap_err().to_string();
+    assert!(err.contains("Did you mean `quick`?"), "{}", err);
+
+    let di = parse_quote! {
+        #[demo(titl = "hello")]
+        pub struct Foo;
+    };
+    let err = Receiver::from_derive_input(&di).unwrap_err().to_string();
+    assert!(!err.contains("title"), "{}", err);
+}
@@ -0,0 +1,20 @@
+//! Test that two fields or variants cannot accept the same name.
+
+use darling::FromMeta;
+
+#[derive(FromMeta)]
+struct Example {
+    #[darling(alias = "b")]
+    a: String,
+    b: String,
+}
+
+#[derive(FromMeta)]
+#[darling(rename_all = "snake_case")]
+enum Mode {
+    Fast,
+    #[darling(alias = "fast")]
+    Quick,
+}
+
+fn main() {}
@@ -1,4 +1,4 @@
-//! Test that multiple fields cannot be marked `flatten` at once.
+//! Test that two flattened fields of the same type cannot claim the same keys.
 
 use darling::{FromDeriveInput, FromMeta};
 
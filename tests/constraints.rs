@@ -0,0 +1,125 @@
+//! Tests for the declarative field constraints `range`, `len`, `non_empty` and `one_of`.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::{parse_quote, spanned::Spanned};
+
+#[derive(Debug, FromMeta)]
+struct Server {
+    #[darling(range = 1..=65535)]
+    port: u16,
+    #[darling(non_empty)]
+    host: String,
+    #[darling(default, range = 1..)]
+    workers: Option<u32>,
+    #[darling(default, one_of("tcp", "udp"))]
+    protocol: Option<String>,
+    #[darling(multiple, len = ..=2, one_of("a", "b", "c"))]
+    tag: Vec<String>,
+}
+
+#[test]
+fn valid() {
+    let server = Server::from_meta(&parse_quote!(server(
+        port = 8080,
+        host = "localhost",
+        workers = 4,
+        protocol = "tcp",
+        tag = "a",
+        tag = "c"
+    )))
+    .unwrap();
+    assert_eq!(server.port, 8080);
+    assert_eq!(server.host, "localhost");
+    assert_eq!(server.workers, Some(4));
+    assert_eq!(server.tag, vec!["a", "c"]);
+}
+
+#[test]
+fn optional_values_are_only_checked_when_present() {
+    let server = Server::from_meta(&parse_quote!(server(port = 1, host = "h"))).unwrap();
+    assert_eq!(server.workers, None);
+    assert_eq!(server.protocol, None);
+}
+
+#[test]
+fn range() {
+    let err = Server::from_meta(&parse_quote!(server(port = 0, host = "h"))).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Value out of range: `0` is not in `1..=65535` at port"
+    );
+}
+
+#[test]
+fn violations_are_accumulated() {
+    let meta: syn::Meta = parse_quote!(server(
+        port = 0,
+        host = "",
+        workers = 0,
+        protocol = "icmp",
+        tag = "a",
+        tag = "d",
+        tag = "b"
+    ));
+    let err = Server::from_meta(&meta).unwrap_err();
+    let messages: Vec<_> = err.into_iter().map(|e| e.to_string()).collect();
+    assert_eq!(messages.len(), 6, "{:#?}", messages);
+    assert_eq!(
+        messages[0],
+        "Value out of range: `0` is not in `1..=65535` at port"
+    );
+    assert_eq!(messages[1], "Value must not be empty at host");
+    assert_eq!(
+        messages[2],
+        "Value out of range: `0` is not in `1..` at workers"
+    );
+    assert!(messages[3].starts_with("Unknown value: `icmp`"));
+    assert!(messages[3].ends_with("at protocol"));
+    assert!(messages[4].starts_with("Unknown value: `d`"));
+    assert!(messages[4].ends_with("at tag[1]"));
+    assert_eq!(
+        messages[5],
+        "Invalid length: expected a length in `..=2`, found 3 at tag"
+    );
+}
+
+#[test]
+fn errors_are_spanned() {
+    let meta: syn::Meta = parse_quote!(server(port = 0, host = "h"));
+    let err = Server::from_meta(&meta).unwrap_err();
+    assert!(err.has_span());
+    let syn::Meta::List(list) = &meta else {
+        unreachable!()
+    };
+    let items = darling::ast::NestedMeta::parse_meta_list(list.tokens.clone()).unwrap();
+    assert_eq!(
+        format!("{:?}", err.span()),
+        format!("{:?}", items[0].span())
+    );
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(builder))]
+struct Builder {
+    #[darling(len = 1..=8)]
+    prefix: String,
+}
+
+#[test]
+fn derive_input() {
+    let di = parse_quote! {
+        #[builder(prefix = "with_")]
+        struct Foo;
+    };
+    assert_eq!(Builder::from_derive_input(&di).unwrap().prefix, "with_");
+
+    let di = parse_quote! {
+        #[builder(prefix = "much_too_long_")]
+        struct Foo;
+    };
+    let err = Builder::from_derive_input(&di).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Invalid length: expected a length in `1..=8`, found 14 at prefix"
+    );
+}
@@ -0,0 +1,168 @@
+use darling::{FromDeriveInput, FromMeta, Warnings};
+use syn::parse_quote;
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+enum Mode {
+    Fast,
+    #[darling(deprecated = "use `fast` instead")]
+    Quick,
+    #[darling(deprecated)]
+    Custom {
+        speed: u8,
+    },
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(demo))]
+struct Receiver {
+    name: Option<String>,
+    #[darling(deprecated = "use `name` instead", alias = "title")]
+    label: Option<String>,
+    mode: Option<Mode>,
+}
+
+#[test]
+fn unused_deprecated_keys_do_not_warn() {
+    let di = parse_quote! {
+        #[demo(name = "hello", mode = "fast")]
+        pub struct Foo;
+    };
+
+    let (parsed, warnings) = Warnings::capture(|| Receiver::from_derive_input(&di));
+    assert_eq!(parsed.unwrap().name.as_deref(), Some("hello"));
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn deprecated_field_warns() {
+    let di = parse_quote! {
+        #[demo(label = "hello")]
+        pub struct Foo;
+    };
+
+    let (parsed, warnings) = Warnings::capture(|| Receiver::from_derive_input(&di));
+    assert_eq!(parsed.unwrap().label.as_deref(), Some("hello"));
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        warnings.iter().next().unwrap().to_string(),
+        "`label` is deprecated: use `name` instead"
+    );
+
+    // Aliases of a deprecated field are deprecated too.
+    let di = parse_quote! {
+        #[demo(title = "hello")]
+        pub struct Foo;
+    };
+    let (parsed, warnings) = Warnings::capture(|| Receiver::from_derive_input(&di));
+    parsed.unwrap();
+    assert_eq!(warnings.len(), 1);
+}
+
+#[test]
+fn deprecated_variant_warns() {
+    let di = parse_quote! {
+        #[demo(mode = "quick")]
+        pub struct Foo;
+    };
+
+    let (parsed, warnings) = Warnings::capture(|| Receiver::from_derive_input(&di));
+    assert_eq!(parsed.unwrap().mode, Some(Mode::Quick));
+    assert_eq!(
+        warnings.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        vec!["`quick` is deprecated: use `fast` instead"]
+    );
+
+    let (mode, warnings) =
+        Warnings::capture(|| Mode::from_list(&[parse_quote!(custom(speed = 1))]));
+    assert_eq!(mode.unwrap(), Mode::Custom { speed: 1 });
+    assert_eq!(
+        warnings.iter().next().unwrap().to_string(),
+        "`custom` is deprecated"
+    );
+}
+
+/// Get the message of each warning, with the source text its span covers.
+fn parse_warnings(source: &str) -> Vec<(String, Option<String>)> {
+    let di = syn::parse_str(source).unwrap();
+    let (parsed, warnings) = Warnings::capture(|| Receiver::from_derive_input(&di));
+    parsed.unwrap();
+    warnings
+        .iter()
+        .map(|warning| (warning.to_string(), warning.span().source_text()))
+        .collect()
+}
+
+#[test]
+fn field_warning_is_spanned_to_key() {
+    assert_eq!(
+        parse_warnings(r#"#[demo(name = "a", label = "b")] struct Foo;"#),
+        vec![(
+            "`label` is deprecated: use `name` instead".to_string(),
+            Some("label".to_string())
+        )]
+    );
+
+    // The warning names the field, but points at the alias that was written.
+    assert_eq!(
+        parse_warnings(r#"#[demo(title = "b")] struct Foo;"#),
+        vec![(
+            "`label` is deprecated: use `name` instead".to_string(),
+            Some("title".to_string())
+        )]
+    );
+}
+
+#[test]
+fn variant_warning_is_spanned_to_name() {
+    assert_eq!(
+        parse_warnings(r#"#[demo(mode = "quick")] struct Foo;"#),
+        vec![(
+            "`quick` is deprecated: use `fast` instead".to_string(),
+            Some("\"quick\"".to_string())
+        )]
+    );
+
+    assert_eq!(
+        parse_warnings(r#"#[demo(mode(custom(speed = 1)))] struct Foo;"#),
+        vec![(
+            "`custom` is deprecated".to_string(),
+            Some("custom".to_string())
+        )]
+    );
+}
+
+#[test]
+fn each_use_warns() {
+    let warnings = parse_warnings(
+        r#"
+        #[demo(label = "a")]
+        #[demo(mode = "quick")]
+        struct Foo;
+        "#,
+    );
+    assert_eq!(warnings.len(), 2);
+}
+
+#[cfg(not(feature = "diagnostics"))]
+#[test]
+fn write_uses_deprecated_item() {
+    let tokens = darling::Warning::new("careful").write().to_string();
+    assert!(tokens.contains("deprecated"));
+    assert!(tokens.contains("\"careful\""));
+}
+
+#[test]
+fn entry_points_return_warnings() {
+    let di = parse_quote! {
+        #[demo(label = "hello")]
+        pub struct Foo;
+    };
+
+    let (parsed, warnings) = Receiver::from_derive_input_with_warnings(&di);
+    assert_eq!(parsed.unwrap().label.as_deref(), Some("hello"));
+    assert_eq!(warnings.len(), 1);
+
+    let (mode, warnings) = Mode::from_meta_with_warnings(&parse_quote!(mode = "quick"));
+    assert_eq!(mode.unwrap(), Mode::Quick);
+    assert_eq!(warnings.len(), 1);
+}
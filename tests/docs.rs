@@ -0,0 +1,197 @@
+//! Tests for the Markdown reference generated by `darling::docs`.
+
+use darling::{FromDeriveInput, FromMeta};
+
+/// How requests are retried.
+#[derive(Debug, Default, FromMeta)]
+#[darling(schema, default)]
+#[allow(dead_code)]
+struct Retry {
+    /// The number of attempts | at most.
+    attempts: u32,
+}
+
+#[derive(Debug, FromMeta)]
+#[darling(schema)]
+#[allow(dead_code)]
+enum Mode {
+    /// Skip validation.
+    Fast,
+    #[darling(alias = "careful")]
+    Safe,
+}
+
+#[derive(Debug, FromMeta)]
+#[darling(schema)]
+#[allow(dead_code)]
+enum Backend {
+    Memory,
+    /// Store on disk.
+    Disk {
+        path: String,
+    },
+}
+
+#[derive(Debug, Default, FromMeta)]
+#[darling(schema, default)]
+#[allow(dead_code)]
+struct Common {
+    /// Hide the generated item
+    /// from documentation.
+    hidden: bool,
+}
+
+fn default_name() -> String {
+    "server".into()
+}
+
+/// Options for `#[derive(Server)]`.
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(server), schema)]
+#[allow(dead_code)]
+struct Server {
+    /// The port to listen on.
+    port: u16,
+    #[darling(default = default_name, alias = "title")]
+    name: String,
+    #[darling(multiple)]
+    route: Vec<String>,
+    mode: Option<Mode>,
+    retry: Option<Retry>,
+    backend: Backend,
+    #[darling(flatten)]
+    common: Common,
+    #[darling(skip)]
+    cache: bool,
+    #[darling(deprecated = "use `port`")]
+    listen: Option<u16>,
+}
+
+#[test]
+fn markdown() {
+    let expected = r#"## `Server`
+
+Options for `#[derive(Server)]`.
+
+Read from `#[server(...)]`.
+
+| Key | Type | Default | Description |
+|---|---|---|---|
+| `port` | `u16` | required | The port to listen on. |
+| `name` (alias `title`) | `String` | `default_name()` |  |
+| `route` (repeatable) | `Vec<String>` | none |  |
+| `mode` | one of `fast`, `safe` | optional |  |
+| `retry` | `Option<Retry>` | optional |  |
+| `backend` | `Backend` | required |  |
+| `hidden` | `bool` | from the container default | Hide the generated item from documentation. |
+| `listen` | `Option<u16>` | optional | **Deprecated**: use `port`. |
+
+## `Retry`
+
+How requests are retried.
+
+| Key | Type | Default | Description |
+|---|---|---|---|
+| `attempts` | `u32` | from the container default | The number of attempts \| at most. |
+
+## `Backend`
+
+Written as one of the following variants.
+
+| Variant | Value | Description |
+|---|---|---|
+| `memory` |  |  |
+| `disk` | keys below | Store on disk. |
+
+### `disk`
+
+| Key | Type | Default | Description |
+|---|---|---|---|
+| `path` | `String` | required |  |
+"#;
+
+    assert_eq!(darling::docs::markdown(&Server::schema()), expected);
+}
+
+#[test]
+fn heading_level() {
+    let docs = darling::docs::Markdown::new(&Backend::schema())
+        .heading_level(3)
+        .to_string();
+    assert!(docs.starts_with("### `Backend`\n"));
+    assert!(docs.contains("\n#### `disk`\n"));
+}
+
+#[test]
+fn doc_comments_in_schema() {
+    let schema = Server::schema();
+    assert_eq!(schema.doc, Some("Options for `#[derive(Server)]`."));
+    assert_eq!(
+        Common::schema().fields().unwrap()[0].doc,
+        Some("Hide the generated item\nfrom documentation.")
+    );
+    assert_eq!(
+        Mode::schema().variants().unwrap()[0].doc,
+        Some("Skip validation.")
+    );
+    assert_eq!(Mode::schema().variants().unwrap()[1].doc, None);
+}
+
+#[derive(Debug, FromMeta)]
+#[darling(schema, tag = "kind", allow_unknown_fields)]
+#[allow(dead_code)]
+enum Store {
+    Memory,
+    Disk(String),
+}
+
+#[derive(Debug, FromMeta)]
+#[darling(schema, allow_unknown_fields)]
+#[allow(dead_code)]
+struct Lenient {
+    #[darling(positional)]
+    path: String,
+    store: Store,
+}
+
+#[test]
+fn tagging_and_unknown_fields() {
+    let docs = darling::docs::markdown(&Lenient::schema());
+    assert!(docs.contains("| `path` (positional) | `String` | required |  |"));
+    assert!(docs.contains("\nUnknown keys are ignored.\n"));
+    assert!(docs.contains("The variant is named by `kind = \"...\"`"));
+    assert!(docs.contains("| `disk` | `String` |  |"));
+}
+
+#[derive(Debug, FromMeta)]
+#[darling(schema)]
+#[allow(dead_code)]
+struct Menu {
+    label: String,
+    #[darling(multiple)]
+    item: Vec<Menu>,
+}
+
+#[test]
+fn recursive_type_has_one_section() {
+    let docs = darling::docs::markdown(&Menu::schema());
+    assert_eq!(docs.matches("## `Menu`").count(), 1);
+    assert!(docs.contains("| `item` (repeatable) | `Vec<Menu>` | none |  |"));
+}
+
+#[derive(Debug, FromMeta)]
+#[darling(schema)]
+#[allow(dead_code)]
+struct Closures {
+    #[darling(default = || 5)]
+    n: u32,
+    #[darling(default = || u8::MAX | 1)]
+    mask: u8,
+}
+
+#[test]
+fn pipes_are_escaped_in_every_cell() {
+    let docs = darling::docs::markdown(&Closures::schema());
+    assert!(docs.contains("| `n` | `u32` | `\\|\\| 5` |  |"));
+    assert!(docs.contains("| `mask` | `u8` | `\\|\\| u8::MAX \\| 1` |  |"));
+}
@@ -0,0 +1,114 @@
+//! Test matching unit variants by their integer discriminant.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FromMeta)]
+#[darling(discriminant)]
+#[repr(u8)]
+enum Code {
+    Ping = 1,
+    Pong,
+    Data = 10,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FromMeta)]
+#[darling(discriminant)]
+enum Implicit {
+    Zero,
+    One,
+    #[darling(skip)]
+    #[allow(dead_code)]
+    Two,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FromMeta)]
+#[darling(discriminant)]
+#[repr(i8)]
+enum Signed {
+    Minus = -1,
+    Plus = 1,
+    #[darling(other)]
+    Unknown = 0,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(msg))]
+struct Receiver {
+    code: Code,
+    implicit: Option<Implicit>,
+    signed: Option<Signed>,
+}
+
+fn parse(di: syn::DeriveInput) -> darling::Result<Receiver> {
+    Receiver::from_derive_input(&di)
+}
+
+#[test]
+fn explicit_and_implicit_discriminants() {
+    let parsed = parse(parse_quote! {
+        #[msg(code = 2, implicit = 1)]
+        pub struct Foo;
+    })
+    .unwrap();
+    assert_eq!(parsed.code, Code::Pong);
+    assert_eq!(parsed.implicit, Some(Implicit::One));
+
+    let parsed = parse(parse_quote! {
+        #[msg(code = 10)]
+        pub struct Foo;
+    })
+    .unwrap();
+    assert_eq!(parsed.code, Code::Data);
+}
+
+#[test]
+fn names_still_accepted() {
+    let parsed = parse(parse_quote! {
+        #[msg(code = "ping", implicit = Zero)]
+        pub struct Foo;
+    })
+    .unwrap();
+    assert_eq!(parsed.code, Code::Ping);
+    assert_eq!(parsed.implicit, Some(Implicit::Zero));
+}
+
+#[test]
+fn negative_and_other() {
+    let parsed = parse(parse_quote! {
+        #[msg(code = 1, signed = -1)]
+        pub struct Foo;
+    })
+    .unwrap();
+    assert_eq!(parsed.signed, Some(Signed::Minus));
+
+    let parsed = parse(parse_quote! {
+        #[msg(code = 1, signed = 7)]
+        pub struct Foo;
+    })
+    .unwrap();
+    assert_eq!(parsed.signed, Some(Signed::Unknown));
+}
+
+#[test]
+fn unknown_code_lists_valid_codes() {
+    let err = parse(parse_quote! {
+        #[msg(code = 4)]
+        pub struct Foo;
+    })
+    .unwrap_err()
+    .to_string();
+    assert!(err.contains("Unknown value: `4`"), "{}", err);
+    for code in ["`1`", "`2`", "`10`"] {
+        assert!(err.contains(code), "{}", err);
+    }
+
+    // Skipped variants can't be selected by discriminant.
+    let err = parse(parse_quote! {
+        #[msg(code = 1, implicit = 2)]
+        pub struct Foo;
+    })
+    .unwrap_err()
+    .to_string();
+    assert!(err.contains("Unknown value: `2`"), "{}", err);
+}
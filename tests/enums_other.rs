@@ -0,0 +1,162 @@
+//! Test the `other` catch-all variant in `FromMeta` enums.
+
+use darling::{util::SpannedValue, FromDeriveInput, FromMeta, ToMeta};
+use syn::parse_quote;
+
+#[derive(Debug, PartialEq, Eq, FromMeta, ToMeta)]
+enum Level {
+    Low,
+    High,
+    #[darling(other)]
+    Unknown,
+}
+
+#[derive(Debug, PartialEq, Eq, FromMeta, ToMeta)]
+enum Format {
+    Json,
+    #[darling(other)]
+    Custom(String),
+}
+
+#[derive(Debug, FromMeta)]
+enum Backend {
+    Memory,
+    #[darling(other)]
+    Other(SpannedValue<String>),
+}
+
+#[derive(Debug, PartialEq, Eq, FromMeta, ToMeta)]
+#[darling(tag = "kind")]
+enum Store {
+    Disk {
+        path: String,
+    },
+    #[darling(other)]
+    Plugin(syn::Path),
+}
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+enum Mode {
+    Fast,
+    Slow,
+    #[darling(other)]
+    Custom(syn::Path),
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(demo))]
+struct Receiver {
+    level: Option<Level>,
+    format: Option<Format>,
+    backend: Option<Backend>,
+    store: Option<Store>,
+    mode: Option<Mode>,
+}
+
+fn parse(di: syn::DeriveInput) -> Receiver {
+    Receiver::from_derive_input(&di).unwrap()
+}
+
+#[test]
+fn unit_fallback() {
+    assert_eq!(Level::from_string("low").unwrap(), Level::Low);
+    assert_eq!(Level::from_string("medium").unwrap(), Level::Unknown);
+    assert_eq!(
+        Level::from_list(&[parse_quote!(medium)]).unwrap(),
+        Level::Unknown
+    );
+
+    // The `other` variant can't be selected by its own name.
+    assert_eq!(Level::from_string("unknown").unwrap(), Level::Unknown);
+}
+
+#[test]
+fn newtype_fallback() {
+    let parsed = parse(parse_quote! {
+        #[demo(level = "medium", format = "json", backend = "sqlite")]
+        pub struct Foo;
+    });
+    assert_eq!(parsed.level, Some(Level::Unknown));
+    assert_eq!(parsed.format, Some(Format::Json));
+    assert_eq!(*parsed.backend.unwrap().unwrap_other(), "sqlite");
+
+    assert_eq!(
+        Format::from_string("yaml").unwrap(),
+        Format::Custom("yaml".into())
+    );
+    assert_eq!(
+        Format::from_list(&[parse_quote!(toml)]).unwrap(),
+        Format::Custom("toml".into())
+    );
+}
+
+#[test]
+fn tagged_fallback() {
+    let parsed = parse(parse_quote! {
+        #[demo(store(kind = "disk", path = "/tmp"))]
+        pub struct Foo;
+    });
+    assert_eq!(
+        parsed.store,
+        Some(Store::Disk {
+            path: "/tmp".into()
+        })
+    );
+
+    let parsed = parse(parse_quote! {
+        #[demo(store(kind = "my_crate::Store", url = "x"))]
+        pub struct Foo;
+    });
+    assert_eq!(
+        parsed.store,
+        Some(Store::Plugin(parse_quote!(my_crate::Store)))
+    );
+}
+
+#[test]
+fn path_fallback() {
+    let parsed = parse(parse_quote! {
+        #[demo(mode = some::path)]
+        pub struct Foo;
+    });
+    assert_eq!(parsed.mode, Some(Mode::Custom(parse_quote!(some::path))));
+
+    let parsed = parse(parse_quote! {
+        #[demo(mode = Mode::Fast)]
+        pub struct Foo;
+    });
+    assert_eq!(parsed.mode, Some(Mode::Fast));
+
+    // The path keeps the span of the value it was parsed from.
+    let expr: syn::Expr = syn::parse_str("other::Mode").unwrap();
+    let Mode::Custom(path) = Mode::from_expr(&expr).unwrap() else {
+        panic!("expected other");
+    };
+    assert_eq!(
+        path.segments[0].ident.span().source_text().as_deref(),
+        Some("other::Mode")
+    );
+}
+
+#[test]
+fn round_trip() {
+    for value in [Format::Json, Format::Custom("yaml".into())] {
+        let meta = value.to_meta(&parse_quote!(format)).unwrap();
+        assert_eq!(Format::from_meta(&meta).unwrap(), value);
+    }
+
+    let value = Store::Plugin(parse_quote!(my_crate::Store));
+    let meta = value.to_meta(&parse_quote!(store)).unwrap();
+    assert_eq!(Store::from_meta(&meta).unwrap(), value);
+
+    assert!(Level::Unknown.to_meta(&parse_quote!(level)).is_none());
+}
+
+impl Backend {
+    fn unwrap_other(self) -> SpannedValue<String> {
+        match self {
+            Backend::Other(value) => value,
+            Backend::Memory => panic!("expected other"),
+        }
+    }
+}
@@ -0,0 +1,209 @@
+//! Test internally-tagged and untagged enums in `FromMeta`.
+
+use darling::{FromDeriveInput, FromMeta, ToMeta, Warnings};
+use syn::parse_quote;
+
+#[derive(Debug, Clone, PartialEq, Eq, FromMeta, ToMeta)]
+struct FifoOptions {
+    capacity: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FromMeta, ToMeta)]
+#[darling(tag = "kind")]
+enum Cache {
+    Lru {
+        size: u32,
+        #[darling(default)]
+        shards: Option<u8>,
+    },
+    Fifo(FifoOptions),
+    #[darling(deprecated = "use `lru` instead", alias = "none")]
+    Disabled,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, FromMeta, ToMeta)]
+#[darling(untagged)]
+enum Limit {
+    Unlimited,
+    Fixed(u32),
+    Range { min: u32, max: u32 },
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(demo))]
+struct Receiver {
+    cache: Option<Cache>,
+    limit: Option<Limit>,
+}
+
+fn parse(di: syn::DeriveInput) -> darling::Result<Receiver> {
+    Receiver::from_derive_input(&di)
+}
+
+#[test]
+fn tagged_struct_variant() {
+    let parsed = parse(parse_quote! {
+        #[demo(cache(kind = "lru", size = 10))]
+        pub struct Foo;
+    })
+    .unwrap();
+
+    assert_eq!(
+        parsed.cache,
+        Some(Cache::Lru {
+            size: 10,
+            shards: None
+        })
+    );
+}
+
+#[test]
+fn tagged_bare_ident() {
+    let parsed = parse(parse_quote! {
+        #[demo(cache(kind = lru, size = 10))]
+        pub struct Foo;
+    })
+    .unwrap();
+
+    assert_eq!(
+        parsed.cache,
+        Some(Cache::Lru {
+            size: 10,
+            shards: None
+        })
+    );
+}
+
+#[test]
+fn tagged_newtype_variant() {
+    let parsed = parse(parse_quote! {
+        #[demo(cache(capacity = 5, kind = "fifo"))]
+        pub struct Foo;
+    })
+    .unwrap();
+
+    assert_eq!(parsed.cache, Some(Cache::Fifo(FifoOptions { capacity: 5 })));
+}
+
+#[test]
+fn tagged_unit_variant() {
+    let (parsed, warnings) = Warnings::capture(|| {
+        parse(parse_quote! {
+            #[demo(cache(kind = "none"))]
+            pub struct Foo;
+        })
+    });
+
+    assert_eq!(parsed.unwrap().cache, Some(Cache::Disabled));
+    assert_eq!(warnings.len(), 1);
+
+    let err = parse(parse_quote! {
+        #[demo(cache(kind = "disabled", size = 10))]
+        pub struct Foo;
+    })
+    .unwrap_err();
+    assert_eq!(err.to_string(), "Unknown field: `size` at cache");
+}
+
+#[test]
+fn tagged_errors() {
+    let err = parse(parse_quote! {
+        #[demo(cache(size = 10))]
+        pub struct Foo;
+    })
+    .unwrap_err();
+    assert_eq!(err.to_string(), "Missing field `kind` at cache");
+
+    let err = parse(parse_quote! {
+        #[demo(cache(kind = "lru", kind = "fifo", size = 10))]
+        pub struct Foo;
+    })
+    .unwrap_err();
+    assert_eq!(err.to_string(), "Duplicate field `kind` at cache");
+
+    let err = parse(parse_quote! {
+        #[demo(cache(kind = "lfu", size = 10))]
+        pub struct Foo;
+    })
+    .unwrap_err();
+    let err = err.to_string();
+    assert!(err.starts_with("Unknown value: `lfu`"), "{}", err);
+    assert!(err.ends_with("at cache/kind"), "{}", err);
+
+    let err = parse(parse_quote! {
+        #[demo(cache(kind = "lru"))]
+        pub struct Foo;
+    })
+    .unwrap_err();
+    assert_eq!(err.to_string(), "Missing field `size` at cache");
+}
+
+#[test]
+fn untagged() {
+    let parsed = parse(parse_quote! {
+        #[demo(limit)]
+        pub struct Foo;
+    })
+    .unwrap();
+    assert_eq!(parsed.limit, Some(Limit::Unlimited));
+
+    let parsed = parse(parse_quote! {
+        #[demo(limit = 3)]
+        pub struct Foo;
+    })
+    .unwrap();
+    assert_eq!(parsed.limit, Some(Limit::Fixed(3)));
+
+    let parsed = parse(parse_quote! {
+        #[demo(limit(min = 1, max = 4))]
+        pub struct Foo;
+    })
+    .unwrap();
+    assert_eq!(parsed.limit, Some(Limit::Range { min: 1, max: 4 }));
+}
+
+#[test]
+fn untagged_merges_errors() {
+    let err = parse(parse_quote! {
+        #[demo(limit(min = 1))]
+        pub struct Foo;
+    })
+    .unwrap_err();
+
+    // `Fixed` and `Range` were both attempted.
+    assert_eq!(err.len(), 2);
+    let messages = err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>();
+    assert!(
+        messages.contains(&"Missing field `max` at range".to_string()),
+        "{:?}",
+        messages
+    );
+}
+
+#[test]
+fn round_trip() {
+    let values = [
+        Cache::Lru {
+            size: 10,
+            shards: Some(2),
+        },
+        Cache::Fifo(FifoOptions { capacity: 5 }),
+        Cache::Disabled,
+    ];
+
+    for value in values {
+        let meta = value.to_meta(&parse_quote!(cache)).unwrap();
+        assert_eq!(Cache::from_meta(&meta).unwrap(), value);
+    }
+
+    let values = [
+        Limit::Unlimited,
+        Limit::Fixed(3),
+        Limit::Range { min: 1, max: 4 },
+    ];
+
+    for value in values {
+        let meta = value.to_meta(&parse_quote!(limit)).unwrap();
+        assert_eq!(Limit::from_meta(&meta).unwrap(), value);
+    }
+}
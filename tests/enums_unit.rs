@@ -150,3 +150,58 @@ fn rejected_in_unit_enum_variants() {
         )
     }
 }
+
+#[test]
+fn bare_ident_and_path_values() {
+    #[derive(Debug, FromMeta)]
+    struct Test {
+        pattern: Pattern,
+    }
+
+    for tokens in [
+        parse_quote!(na(pattern = Owned)),
+        parse_quote!(na(pattern = owned)),
+        parse_quote!(na(pattern = Pattern::Owned)),
+        parse_quote!(na(pattern = "owned")),
+    ] {
+        let parsed = Test::from_meta(&tokens).unwrap();
+        assert!(matches!(parsed.pattern, Pattern::Owned));
+    }
+}
+
+#[test]
+fn bare_ident_errors() {
+    #[derive(Debug, FromMeta)]
+    #[allow(dead_code)]
+    struct Test {
+        pattern: Pattern,
+    }
+
+    let meta: Meta = parse_quote!(na(pattern = Ownd));
+    let err = Test::from_meta(&meta).unwrap_err();
+    assert!(err.to_string().contains("Unknown value: `Ownd`"), "{}", err);
+    assert!(err.to_string().contains("`owned`"), "{}", err);
+
+    let err = Test::from_meta(&parse_quote!(na(pattern = Other::Owned))).unwrap_err();
+    assert!(err.to_string().contains("`Other::Owned`"), "{}", err);
+
+    let err = Test::from_meta(&parse_quote!(na(pattern = Pattern::Owned::Deep))).unwrap_err();
+    assert!(
+        err.to_string().contains("`Pattern::Owned::Deep`"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn data_enums_keep_default_from_expr() {
+    #[derive(Debug, FromMeta)]
+    #[allow(dead_code)]
+    enum Plain {
+        Alpha { x: u8 },
+        Beta(String),
+    }
+
+    let err = Plain::from_expr(&parse_quote!(Alpha)).unwrap_err();
+    assert_eq!(err.to_string(), "Unexpected type `path`");
+}
@@ -0,0 +1,96 @@
+//! Errors can be inspected through `Error::kind` and `Error::code` instead of their messages.
+
+#![allow(dead_code)]
+
+use darling::{error::ErrorKindRef, Error, FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, FromMeta)]
+struct Retry {
+    attempts: u32,
+    backoff: Option<u64>,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(server))]
+struct Server {
+    port: u16,
+    retry: Option<Retry>,
+}
+
+fn parse(di: syn::DeriveInput) -> Vec<Error> {
+    Server::from_derive_input(&di)
+        .unwrap_err()
+        .into_iter()
+        .collect()
+}
+
+#[test]
+fn missing_field() {
+    let errors = parse(parse_quote! {
+        #[server]
+        struct Foo;
+    });
+
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(
+        errors[0].kind(),
+        ErrorKindRef::MissingField("port")
+    ));
+    assert_eq!(errors[0].code(), Some("D0003"));
+}
+
+#[test]
+fn unknown_field() {
+    let errors = parse(parse_quote! {
+        #[server(port = 80, retry(attempts = 3, backof = 5))]
+        struct Foo;
+    });
+
+    assert_eq!(errors.len(), 1);
+    let ErrorKindRef::UnknownField(unknown) = errors[0].kind() else {
+        panic!("expected an unknown field, got {:?}", errors[0].kind());
+    };
+    assert_eq!(unknown.value(), "backof");
+    assert_eq!(
+        unknown.alternates().collect::<Vec<_>>(),
+        vec!["attempts", "backoff"]
+    );
+    assert_eq!(errors[0].code(), Some("D0005"));
+}
+
+#[test]
+fn unexpected_type() {
+    let errors = parse(parse_quote! {
+        #[server(port = true)]
+        struct Foo;
+    });
+
+    assert!(matches!(
+        errors[0].kind(),
+        ErrorKindRef::UnexpectedType {
+            ty: "bool",
+            expected: Some("an integer literal"),
+        }
+    ));
+    assert_eq!(errors[0].code(), Some("D0007"));
+}
+
+#[test]
+fn multiple() {
+    let error = Error::multiple(vec![Error::too_few_items(2), Error::too_many_items(4)]);
+    let ErrorKindRef::Multiple(errors) = error.kind() else {
+        panic!("expected multiple errors, got {:?}", error.kind());
+    };
+    assert!(matches!(errors[0].kind(), ErrorKindRef::TooFewItems(2)));
+    assert!(matches!(errors[1].kind(), ErrorKindRef::TooManyItems(4)));
+    assert_eq!(error.code(), None);
+}
+
+#[test]
+fn single_multiple() {
+    // `Error::multiple` returns a lone error as itself.
+    let error = Error::multiple(vec![Error::custom("oops")]);
+    assert!(matches!(error.kind(), ErrorKindRef::Custom("oops")));
+    assert_eq!(error.code(), Some("D0001"));
+}
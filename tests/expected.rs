@@ -0,0 +1,161 @@
+//! Unexpected type and format errors describe the input that was expected, using `FromMeta::expected`.
+
+#![allow(dead_code)]
+
+use darling::{util::Override, FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, FromMeta)]
+struct Retry {
+    attempts: u32,
+}
+
+#[derive(Debug, FromMeta)]
+enum Mode {
+    Fast,
+    Safe,
+    #[darling(skip)]
+    Internal,
+}
+
+#[derive(Debug, FromMeta)]
+#[darling(tag = "kind")]
+enum Store {
+    Memory,
+    Disk { path: String },
+}
+
+#[derive(Debug, FromMeta)]
+#[darling(untagged)]
+enum Limit {
+    Unlimited,
+    Count(u32),
+}
+
+#[derive(Debug, FromMeta)]
+struct Name(String);
+
+/// Overrides `from_meta` instead of the per-format methods, so its errors skip the default
+/// dispatch which would describe the expected input.
+#[derive(Debug)]
+struct Port(u16);
+
+impl FromMeta for Port {
+    fn from_meta(item: &syn::Meta) -> darling::Result<Self> {
+        match item {
+            syn::Meta::NameValue(nv) => u16::from_expr(&nv.value).map(Port),
+            _ => Err(darling::Error::unsupported_format("list")),
+        }
+    }
+
+    fn expected() -> Option<std::borrow::Cow<'static, str>> {
+        Some("a port number".into())
+    }
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(server))]
+struct Server {
+    name: Option<String>,
+    retry: Option<Retry>,
+    mode: Option<Mode>,
+    port: Option<Port>,
+}
+
+#[test]
+fn derived() {
+    assert_eq!(
+        Retry::expected().as_deref(),
+        Some("a list like `name(key = value)`")
+    );
+    assert_eq!(Mode::expected().as_deref(), Some("one of `fast`, `safe`"));
+    assert_eq!(
+        Store::expected().as_deref(),
+        Some("a list with `kind` naming one of `memory`, `disk`")
+    );
+    assert_eq!(
+        Limit::expected().as_deref(),
+        Some("a word or an integer literal")
+    );
+    assert_eq!(Name::expected().as_deref(), Some("a string literal"));
+}
+
+#[test]
+fn wrappers_forward() {
+    assert_eq!(<Option<bool>>::expected(), bool::expected());
+    assert_eq!(
+        <Override<String>>::expected().as_deref(),
+        Some("a word or a string literal")
+    );
+}
+
+#[test]
+fn built_in_in_error() {
+    let di = parse_quote! {
+        #[server(name = true)]
+        struct Foo;
+    };
+
+    let error = Server::from_derive_input(&di).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Unexpected type `bool`, expected a string literal at name"
+    );
+}
+
+#[test]
+fn derived_struct_in_error() {
+    let di = parse_quote! {
+        #[server(retry)]
+        struct Foo;
+    };
+
+    let error = Server::from_derive_input(&di).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Unexpected meta-item format `word`, expected a list like `name(key = value)` at retry"
+    );
+}
+
+#[test]
+fn derived_enum_in_error() {
+    let di = parse_quote! {
+        #[server(mode = 5)]
+        struct Foo;
+    };
+
+    let error = Server::from_derive_input(&di).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Unexpected type `int`, expected one of `fast`, `safe` at mode"
+    );
+}
+
+/// The description belongs to the type being parsed, not to the types that contain it.
+#[test]
+fn innermost_wins() {
+    let di = parse_quote! {
+        #[server(retry(attempts = true))]
+        struct Foo;
+    };
+
+    let error = Server::from_derive_input(&di).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Unexpected type `bool`, expected an integer literal at retry/attempts"
+    );
+}
+
+#[test]
+fn overridden_from_meta_in_error() {
+    let di = parse_quote! {
+        #[server(port(80))]
+        struct Foo;
+    };
+
+    let error = Server::from_derive_input(&di).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Unexpected meta-item format `list`, expected a port number at port"
+    );
+}
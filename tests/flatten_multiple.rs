@@ -0,0 +1,177 @@
+//! Tests for structs with several `#[darling(flatten)]` fields, which are each given the keys
+//! their type claims.
+
+use std::collections::HashMap;
+
+use darling::{util::Flag, FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, Default, FromMeta)]
+#[darling(default)]
+struct CommonOpts {
+    crate_path: Option<syn::Path>,
+    skip_docs: Flag,
+}
+
+#[derive(Debug, Default, FromMeta)]
+#[darling(default)]
+struct SerdeOpts {
+    rename_all: Option<String>,
+    deny_unknown_fields: Flag,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(model))]
+struct Model {
+    table: String,
+    #[darling(flatten)]
+    common: CommonOpts,
+    #[darling(flatten)]
+    serde: SerdeOpts,
+}
+
+#[test]
+fn keys_are_routed_by_type() {
+    let di = parse_quote! {
+        #[model(table = "users", skip_docs, rename_all = "camelCase")]
+        struct User;
+    };
+    let model = Model::from_derive_input(&di).unwrap();
+    assert_eq!(model.table, "users");
+    assert!(model.common.skip_docs.is_present());
+    assert_eq!(model.common.crate_path, None);
+    assert_eq!(model.serde.rename_all.as_deref(), Some("camelCase"));
+    assert!(!model.serde.deny_unknown_fields.is_present());
+}
+
+#[test]
+fn across_attributes() {
+    let di = parse_quote! {
+        #[model(table = "users", deny_unknown_fields)]
+        #[model(crate_path = my_crate)]
+        struct User;
+    };
+    let model = Model::from_derive_input(&di).unwrap();
+    assert!(model.serde.deny_unknown_fields.is_present());
+    assert!(model.common.crate_path.is_some());
+}
+
+#[test]
+fn unclaimed_key() {
+    let di = parse_quote! {
+        #[model(table = "users", rename_al = "camelCase")]
+        struct User;
+    };
+    let err = Model::from_derive_input(&di).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unknown field: `rename_al`. Did you mean `rename_all`?"
+    );
+    assert!(err.has_span());
+}
+
+#[derive(Debug, FromMeta)]
+#[allow(dead_code)]
+struct Endpoint {
+    path: String,
+    #[darling(flatten)]
+    common: CommonOpts,
+    #[darling(flatten)]
+    serde: SerdeOpts,
+}
+
+#[test]
+fn known_keys_include_flattened_fields() {
+    assert_eq!(
+        Endpoint::known_keys(),
+        Some(vec![
+            "path",
+            "crate_path",
+            "skip_docs",
+            "rename_all",
+            "deny_unknown_fields"
+        ])
+    );
+}
+
+#[derive(Debug, Default, FromMeta)]
+#[darling(default)]
+struct Overlapping {
+    skip_docs: Flag,
+}
+
+#[derive(Debug, FromMeta)]
+#[allow(dead_code)]
+struct Ambiguous {
+    #[darling(flatten)]
+    common: CommonOpts,
+    #[darling(flatten)]
+    overlapping: Overlapping,
+}
+
+#[test]
+fn key_claimed_twice() {
+    let err = Ambiguous::from_meta(&parse_quote!(ambiguous(skip_docs))).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "`skip_docs` is claimed by more than one flattened field: `common`, `overlapping`"
+    );
+
+    let ambiguous = Ambiguous::from_meta(&parse_quote!(ambiguous(crate_path = a))).unwrap();
+    assert!(ambiguous.common.crate_path.is_some());
+}
+
+#[derive(Debug, FromMeta)]
+struct WithCatchAll {
+    #[darling(flatten)]
+    common: CommonOpts,
+    #[darling(flatten, catch_all)]
+    rest: HashMap<String, syn::Lit>,
+}
+
+#[test]
+fn catch_all() {
+    let parsed =
+        WithCatchAll::from_meta(&parse_quote!(with(skip_docs, color = "red", size = 3))).unwrap();
+    assert!(parsed.common.skip_docs.is_present());
+    assert_eq!(parsed.rest.len(), 2);
+    assert!(parsed.rest.contains_key("color"));
+    assert!(parsed.rest.contains_key("size"));
+}
+
+#[derive(Debug, FromMeta)]
+#[darling(allow_unknown_fields)]
+struct Lenient {
+    #[darling(flatten)]
+    common: CommonOpts,
+    #[darling(flatten)]
+    serde: SerdeOpts,
+}
+
+#[test]
+fn allow_unknown_fields() {
+    let parsed = Lenient::from_meta(&parse_quote!(lenient(skip_docs, other = 1))).unwrap();
+    assert!(parsed.common.skip_docs.is_present());
+    assert_eq!(parsed.serde.rename_all, None);
+}
+
+#[derive(Debug, FromMeta)]
+#[allow(dead_code)]
+struct Unrouted {
+    #[darling(flatten)]
+    common: CommonOpts,
+    #[darling(flatten)]
+    rest: HashMap<String, syn::Lit>,
+}
+
+/// A type without `known_keys` can't be routed any keys, so it must be the catch-all.
+#[test]
+fn unknown_keys_without_catch_all() {
+    let err = Unrouted::from_meta(&parse_quote!(unrouted(skip_docs))).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "`rest` is flattened with other fields, but its type does not implement \
+         `FromMeta::known_keys`; mark it `#[darling(flatten, catch_all)]` to give it every key \
+         no other field claims"
+    );
+}
@@ -0,0 +1,159 @@
+//! Tests for `#[darling(flatten)]` on fields of struct-like enum variants.
+
+use darling::{FromMeta, ToMeta};
+use quote::ToTokens;
+use syn::parse_quote;
+
+#[derive(Debug, Default, PartialEq, Eq, FromMeta, ToMeta)]
+#[darling(default)]
+struct Common {
+    timeout: Option<u32>,
+    retry: bool,
+}
+
+#[derive(Debug, Default, PartialEq, Eq, FromMeta, ToMeta)]
+#[darling(default)]
+struct Auth {
+    token: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, FromMeta, ToMeta)]
+enum Backend {
+    Http {
+        url: String,
+        #[darling(flatten)]
+        common: Common,
+    },
+    File {
+        path: String,
+        #[darling(flatten)]
+        common: Common,
+        #[darling(flatten)]
+        auth: Auth,
+    },
+    #[darling(allow_unknown_fields)]
+    Lenient {
+        #[darling(flatten)]
+        auth: Auth,
+        #[darling(flatten)]
+        common: Common,
+    },
+}
+
+#[derive(Debug, FromMeta)]
+struct Config {
+    backend: Backend,
+}
+
+#[test]
+fn flattened_fields() {
+    let config = Config::from_meta(&parse_quote!(config(backend(http(
+        url = "http://x",
+        timeout = 5,
+        retry
+    )))))
+    .unwrap();
+    assert_eq!(
+        config.backend,
+        Backend::Http {
+            url: "http://x".into(),
+            common: Common {
+                timeout: Some(5),
+                retry: true,
+            },
+        }
+    );
+}
+
+#[test]
+fn omitted_flattened_keys_use_defaults() {
+    let config = Config::from_meta(&parse_quote!(config(backend(http(url = "http://x"))))).unwrap();
+    assert_eq!(
+        config.backend,
+        Backend::Http {
+            url: "http://x".into(),
+            common: Common::default(),
+        }
+    );
+}
+
+#[test]
+fn several_flattened_fields() {
+    let config = Config::from_meta(&parse_quote!(config(backend(file(
+        path = "a",
+        token = "t",
+        retry
+    )))))
+    .unwrap();
+    assert_eq!(
+        config.backend,
+        Backend::File {
+            path: "a".into(),
+            common: Common {
+                timeout: None,
+                retry: true,
+            },
+            auth: Auth {
+                token: Some("t".into()),
+            },
+        }
+    );
+}
+
+#[test]
+fn unknown_field() {
+    let err =
+        Config::from_meta(&parse_quote!(config(backend(http(url = "x", timout = 5))))).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unknown field: `timout`. Did you mean `timeout`? at backend/http"
+    );
+
+    let err = Config::from_meta(&parse_quote!(config(backend(file(path = "x", timout = 5)))))
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unknown field: `timout`. Did you mean `timeout`? at backend/file"
+    );
+}
+
+#[test]
+fn allow_unknown_fields() {
+    let config =
+        Config::from_meta(&parse_quote!(config(backend(lenient(token = "t", other))))).unwrap();
+    assert_eq!(
+        config.backend,
+        Backend::Lenient {
+            auth: Auth {
+                token: Some("t".into()),
+            },
+            common: Common::default(),
+        }
+    );
+}
+
+#[test]
+fn to_meta_round_trip() {
+    let backend = Backend::File {
+        path: "a".into(),
+        common: Common {
+            timeout: Some(1),
+            retry: false,
+        },
+        auth: Auth {
+            token: Some("t".into()),
+        },
+    };
+    let meta = backend.to_meta(&parse_quote!(backend)).unwrap();
+    assert_eq!(
+        meta.to_token_stream().to_string(),
+        quote::quote!(backend(file(
+            path = "a",
+            timeout = 1,
+            retry = false,
+            token = "t"
+        )))
+        .to_string()
+    );
+    assert_eq!(Backend::from_meta(&meta).unwrap(), backend);
+}
@@ -0,0 +1,112 @@
+use darling::{util::Flag, FromAttributes, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, Default, FromMeta)]
+struct Cache {
+    ttl: Option<u32>,
+}
+
+#[derive(Debug, FromAttributes)]
+enum Route {
+    Get {
+        path: String,
+    },
+    Post {
+        path: String,
+        body: Flag,
+    },
+    #[darling(rename = "cached")]
+    Cached(Cache),
+    Health,
+}
+
+fn parse(attrs: Vec<syn::Attribute>) -> darling::Result<Route> {
+    Route::from_attributes(&attrs)
+}
+
+#[test]
+fn struct_variant() {
+    let route = parse(vec![
+        parse_quote!(#[doc = "Ignored"]),
+        parse_quote!(#[post(path = "/", body)]),
+    ])
+    .unwrap();
+
+    let Route::Post { path, body } = route else {
+        panic!("Expected Route::Post");
+    };
+
+    assert_eq!(path, "/");
+    assert!(body.is_present());
+}
+
+#[test]
+fn renamed_by_rule() {
+    let route = parse(vec![parse_quote!(#[get(path = "/users")])]).unwrap();
+    assert!(matches!(route, Route::Get { path } if path == "/users"));
+}
+
+#[test]
+fn merges_repeated_attribute() {
+    let route = parse(vec![
+        parse_quote!(#[post(path = "/")]),
+        parse_quote!(#[post(body)]),
+    ])
+    .unwrap();
+
+    assert!(matches!(route, Route::Post { body, .. } if body.is_present()));
+}
+
+#[test]
+fn newtype_and_unit_variants() {
+    let route = parse(vec![parse_quote!(#[cached(ttl = 5)])]).unwrap();
+    assert!(matches!(route, Route::Cached(Cache { ttl: Some(5) })));
+
+    let route = parse(vec![parse_quote!(#[health])]).unwrap();
+    assert!(matches!(route, Route::Health));
+}
+
+#[test]
+fn unit_variant_rejects_items() {
+    parse(vec![parse_quote!(#[health(verbose)])]).unwrap_err();
+}
+
+#[test]
+fn conflicting_attributes() {
+    let err = parse(vec![
+        parse_quote!(#[get(path = "/")]),
+        parse_quote!(#[post(path = "/")]),
+    ])
+    .unwrap_err();
+
+    assert_eq!(err.len(), 1);
+    assert_eq!(
+        err.to_string(),
+        "Conflicting attributes: `#[post]` cannot be used with `#[get]`"
+    );
+}
+
+#[test]
+fn missing_attribute() {
+    let err = parse(vec![parse_quote!(#[doc = "Hello"])]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Missing attribute: expected one of `#[get]`, `#[post]`, `#[cached]`, `#[health]`"
+    );
+}
+
+#[cfg(feature = "suggestions")]
+#[test]
+fn missing_attribute_suggestion() {
+    let err = parse(vec![parse_quote!(#[posst(path = "/")])]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unknown attribute: `posst`. Did you mean `post`?"
+    );
+}
+
+#[test]
+fn variant_errors_are_accumulated() {
+    let err = parse(vec![parse_quote!(#[post(body, unknown)])]).unwrap_err();
+    assert_eq!(err.len(), 2);
+}
@@ -0,0 +1,93 @@
+use darling::{ast, FromFn, FromFnArg, FromSignature};
+use syn::{parse_quote, Ident};
+
+#[derive(Debug, FromFnArg)]
+#[darling(attributes(my))]
+struct Arg {
+    ident: Option<Ident>,
+    ty: syn::Type,
+    #[darling(default)]
+    skip: bool,
+    rename: Option<String>,
+}
+
+#[derive(FromSignature)]
+struct Sig {
+    ident: Ident,
+    inputs: Vec<Arg>,
+    asyncness: bool,
+    #[darling(default)]
+    extra: Option<String>,
+}
+
+#[derive(FromFn)]
+#[darling(attributes(my), forward_attrs(doc))]
+struct Function {
+    ident: Ident,
+    vis: syn::Visibility,
+    sig: Sig,
+    inputs: Vec<Arg>,
+    output: syn::ReturnType,
+    asyncness: bool,
+    constness: bool,
+    generics: ast::Generics<ast::GenericParam<Ident>>,
+    attrs: Vec<syn::Attribute>,
+    #[darling(default)]
+    route: Option<String>,
+}
+
+#[test]
+fn parse_function() {
+    let item: syn::ItemFn = parse_quote! {
+        /// Gets a user
+        #[my(route = "/users")]
+        pub async fn get_user<T>(
+            #[my(rename = "user_id")] id: u32,
+            #[my(skip)] (a, b): (T, T),
+        ) -> String {
+            String::new()
+        }
+    };
+
+    let function = Function::from_fn(&item).unwrap();
+    assert_eq!(function.ident, "get_user");
+    assert!(matches!(function.vis, syn::Visibility::Public(_)));
+    assert_eq!(function.route.as_deref(), Some("/users"));
+    assert_eq!(function.attrs.len(), 1);
+    assert!(function.asyncness);
+    assert!(!function.constness);
+    assert_eq!(function.output, parse_quote!(-> String));
+    assert_eq!(function.generics.type_params().count(), 1);
+
+    assert_eq!(function.inputs.len(), 2);
+    assert_eq!(function.inputs[0].ident.as_ref().unwrap(), "id");
+    assert_eq!(function.inputs[0].ty, parse_quote!(u32));
+    assert_eq!(function.inputs[0].rename.as_deref(), Some("user_id"));
+    assert!(!function.inputs[0].skip);
+    assert_eq!(function.inputs[1].ident, None);
+    assert!(function.inputs[1].skip);
+
+    assert_eq!(function.sig.ident, "get_user");
+    assert!(function.sig.asyncness);
+    assert_eq!(function.sig.inputs.len(), 2);
+}
+
+#[test]
+fn receiver_arg() {
+    let sig: syn::Signature = parse_quote!(fn method(&self, #[my(skip)] other: u8));
+    let sig = Sig::from_signature(&sig).unwrap();
+    assert_eq!(sig.inputs[0].ident.as_ref().unwrap(), "self");
+    assert_eq!(sig.inputs[0].ty, parse_quote!(&Self));
+    assert!(sig.inputs[1].skip);
+    assert_eq!(sig.extra, None);
+}
+
+#[test]
+fn errors_from_all_args_are_accumulated() {
+    let sig: syn::Signature = parse_quote! {
+        fn method(#[my(unknown)] a: u8, #[my(skip = "yes")] b: u8)
+    };
+
+    let err = Sig::from_signature(&sig).map(|_| ()).unwrap_err();
+    assert_eq!(err.len(), 2);
+}
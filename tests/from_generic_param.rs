@@ -0,0 +1,140 @@
+use darling::{
+    ast, FromConstParam, FromGenericParam, FromGenerics, FromLifetimeParam, FromTypeParam,
+};
+use syn::{parse_quote, DeriveInput, GenericParam, Ident};
+
+#[derive(FromTypeParam)]
+#[darling(attributes(lorem))]
+struct TypeReceiver {
+    ident: Ident,
+    #[darling(default)]
+    foo: bool,
+}
+
+#[derive(FromLifetimeParam)]
+#[darling(attributes(lorem), forward_attrs(doc))]
+struct LifetimeReceiver {
+    ident: Ident,
+    lifetime: syn::Lifetime,
+    bounds: Vec<syn::Lifetime>,
+    attrs: Vec<syn::Attribute>,
+    #[darling(default)]
+    foo: bool,
+}
+
+#[derive(FromConstParam)]
+#[darling(attributes(lorem))]
+struct ConstReceiver {
+    ident: Ident,
+    ty: syn::Type,
+    default: Option<syn::Expr>,
+    #[darling(default)]
+    bar: Option<String>,
+}
+
+#[derive(Debug, FromGenericParam)]
+#[darling(attributes(lorem))]
+struct AnyReceiver {
+    ident: Ident,
+    #[darling(default)]
+    foo: bool,
+    #[darling(default)]
+    bar: Option<String>,
+}
+
+fn input() -> DeriveInput {
+    parse_quote! {
+        struct Baz<
+            #[lorem(foo)]
+            /// Docs for 'a
+            'a: 'b,
+            'b,
+            #[lorem(foo)] T,
+            #[lorem(bar = "x")] const N: usize = 3
+        >(&'a T, &'b [u8; N]);
+    }
+}
+
+#[test]
+fn lifetime_param() {
+    let di = input();
+    let GenericParam::Lifetime(lt) = &di.generics.params[0] else {
+        panic!("Not a lifetime param");
+    };
+
+    let receiver = LifetimeReceiver::from_lifetime_param(lt).unwrap();
+    assert_eq!(receiver.ident, "a");
+    assert_eq!(receiver.lifetime, parse_quote!('a));
+    assert_eq!(receiver.bounds, vec![parse_quote!('b)]);
+    assert_eq!(receiver.attrs.len(), 1);
+    assert!(receiver.foo);
+}
+
+#[test]
+fn const_param() {
+    let di = input();
+    let GenericParam::Const(c) = &di.generics.params[3] else {
+        panic!("Not a const param");
+    };
+
+    let receiver = ConstReceiver::from_const_param(c).unwrap();
+    assert_eq!(receiver.ident, "N");
+    assert_eq!(receiver.ty, parse_quote!(usize));
+    assert_eq!(receiver.default, Some(parse_quote!(3)));
+    assert_eq!(receiver.bar.as_deref(), Some("x"));
+}
+
+#[test]
+fn generic_param() {
+    let di = input();
+    let receivers = di
+        .generics
+        .params
+        .iter()
+        .map(AnyReceiver::from_generic_param)
+        .collect::<darling::Result<Vec<_>>>()
+        .unwrap();
+
+    let idents = receivers
+        .iter()
+        .map(|r| r.ident.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(idents, vec!["a", "b", "T", "N"]);
+    assert_eq!(
+        receivers.iter().map(|r| r.foo).collect::<Vec<_>>(),
+        vec![true, false, true, false]
+    );
+    assert_eq!(receivers[3].bar.as_deref(), Some("x"));
+}
+
+#[test]
+fn generic_param_rejects_unknown_field() {
+    let param: GenericParam = parse_quote!(#[lorem(baz = "x")] const N: usize);
+    AnyReceiver::from_generic_param(&param).unwrap_err();
+}
+
+#[test]
+fn fully_typed_generics() {
+    let di = input();
+    let generics: ast::Generics<ast::GenericParam<TypeReceiver, LifetimeReceiver, ConstReceiver>> =
+        FromGenerics::from_generics(&di.generics).unwrap();
+
+    let mut lifetimes = 0;
+    let mut consts = 0;
+    for param in &generics.params {
+        match param {
+            ast::GenericParam::Type(ty) => {
+                assert_eq!(ty.ident, "T");
+                assert!(ty.foo);
+            }
+            ast::GenericParam::Lifetime(_) => lifetimes += 1,
+            ast::GenericParam::Const(c) => {
+                assert_eq!(c.ident, "N");
+                consts += 1;
+            }
+        }
+    }
+
+    assert_eq!(lifetimes, 2);
+    assert_eq!(consts, 1);
+}
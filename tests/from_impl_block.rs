@@ -0,0 +1,116 @@
+use darling::{FromImplBlock, FromImplItemFn, FromTraitDef, FromTraitItemFn};
+use syn::{parse_quote, Ident};
+
+#[derive(Debug, FromImplItemFn)]
+#[darling(attributes(my))]
+struct Method {
+    ident: Ident,
+    vis: syn::Visibility,
+    block: syn::Block,
+    #[darling(default)]
+    skip: bool,
+}
+
+#[derive(Debug, FromImplBlock)]
+#[darling(attributes(my))]
+struct Impl {
+    self_ty: syn::Type,
+    trait_: Option<syn::Path>,
+    items: Vec<Method>,
+    #[darling(default)]
+    rename_all: Option<String>,
+}
+
+#[derive(Debug, FromTraitItemFn)]
+#[darling(attributes(my))]
+struct TraitMethod {
+    ident: Ident,
+    block: Option<syn::Block>,
+    #[darling(default)]
+    skip: bool,
+}
+
+#[derive(Debug, FromTraitDef)]
+#[darling(attributes(my))]
+struct Trait {
+    ident: Ident,
+    supertraits: Vec<syn::TypeParamBound>,
+    items: Vec<TraitMethod>,
+}
+
+#[test]
+fn impl_block() {
+    let item: syn::ItemImpl = parse_quote! {
+        #[my(rename_all = "camelCase")]
+        impl Display for Foo {
+            type Output = ();
+
+            fn fmt(&self) {}
+
+            #[my(skip)]
+            pub fn other() {}
+        }
+    };
+
+    let parsed = Impl::from_impl_block(&item).unwrap();
+    assert_eq!(parsed.self_ty, parse_quote!(Foo));
+    assert_eq!(parsed.trait_, Some(parse_quote!(Display)));
+    assert_eq!(parsed.rename_all.as_deref(), Some("camelCase"));
+    assert_eq!(parsed.items.len(), 2);
+    assert_eq!(parsed.items[0].ident, "fmt");
+    assert!(parsed.items[0].block.stmts.is_empty());
+    assert!(!parsed.items[0].skip);
+    assert_eq!(parsed.items[1].ident, "other");
+    assert!(matches!(parsed.items[1].vis, syn::Visibility::Public(_)));
+    assert!(parsed.items[1].skip);
+}
+
+#[test]
+fn inherent_impl() {
+    let item: syn::ItemImpl = parse_quote!(impl Foo {});
+    let parsed = Impl::from_impl_block(&item).unwrap();
+    assert_eq!(parsed.trait_, None);
+    assert!(parsed.items.is_empty());
+}
+
+#[test]
+fn impl_block_accumulates_errors() {
+    let item: syn::ItemImpl = parse_quote! {
+        #[my(unknown)]
+        impl Foo {
+            #[my(skip = "yes")]
+            fn a() {}
+
+            #[my(other)]
+            fn b() {}
+        }
+    };
+
+    let err = Impl::from_impl_block(&item).unwrap_err();
+    assert_eq!(err.len(), 3);
+}
+
+#[test]
+fn trait_def() {
+    let item: syn::ItemTrait = parse_quote! {
+        trait Service: Send + Sync {
+            const NAME: &'static str;
+
+            fn call(&self);
+
+            #[my(skip)]
+            fn ready(&self) -> bool {
+                true
+            }
+        }
+    };
+
+    let parsed = Trait::from_trait_def(&item).unwrap();
+    assert_eq!(parsed.ident, "Service");
+    assert_eq!(parsed.supertraits.len(), 2);
+    assert_eq!(parsed.items.len(), 2);
+    assert_eq!(parsed.items[0].ident, "call");
+    assert!(parsed.items[0].block.is_none());
+    assert!(parsed.items[1].block.is_some());
+    assert!(parsed.items[1].skip);
+}
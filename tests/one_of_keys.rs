@@ -0,0 +1,173 @@
+//! Tests for `#[darling(one_of_keys)]`, which lets the variants of an enum field appear as keys
+//! of the parent.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::parse_quote;
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+enum Source {
+    Path(String),
+    Url(String),
+    #[darling(rename = "inline")]
+    Contents(String),
+}
+
+#[derive(Debug, FromMeta)]
+struct Template {
+    #[darling(one_of_keys)]
+    source: Source,
+    #[darling(default)]
+    escape: bool,
+}
+
+#[test]
+fn known_keys() {
+    assert_eq!(Source::known_keys(), Some(vec!["path", "url", "inline"]));
+    assert_eq!(
+        Template::known_keys(),
+        Some(vec!["escape", "path", "url", "inline"])
+    );
+    assert_eq!(<Option<Source>>::known_keys(), Source::known_keys());
+    assert_eq!(String::known_keys(), None);
+}
+
+#[test]
+fn exactly_one() {
+    let template = Template::from_meta(&parse_quote!(template(url = "x", escape))).unwrap();
+    assert_eq!(template.source, Source::Url("x".into()));
+    assert!(template.escape);
+
+    let template = Template::from_meta(&parse_quote!(template(inline = "{{ a }}"))).unwrap();
+    assert_eq!(template.source, Source::Contents("{{ a }}".into()));
+}
+
+#[test]
+fn missing() {
+    let err = Template::from_meta(&parse_quote!(template(escape))).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Missing field: expected one of `path`, `url`, `inline`"
+    );
+}
+
+#[test]
+fn more_than_one() {
+    let err = Template::from_meta(&parse_quote!(template(path = "a", url = "b", inline = "c")))
+        .unwrap_err();
+    let messages: Vec<_> = err.into_iter().map(|e| e.to_string()).collect();
+    assert_eq!(
+        messages,
+        vec!["Only one of `path`, `url`, `inline` can be given"]
+    );
+}
+
+#[test]
+fn conflict_is_spanned_to_second_key() {
+    let meta = syn::parse_str(r#"template(path = "a", url = "b", inline = "c")"#).unwrap();
+    let err = Template::from_meta(&meta).unwrap_err();
+    let snippets: Vec<_> = err
+        .into_iter()
+        .map(|e| e.explicit_span().and_then(|span| span.source_text()))
+        .collect();
+    assert_eq!(snippets, vec![Some(r#"url = "b""#.to_string())]);
+}
+
+#[test]
+fn duplicate_key() {
+    let err = Template::from_meta(&parse_quote!(template(path = "a", path = "b"))).unwrap_err();
+    assert_eq!(err.to_string(), "Duplicate field `path`");
+}
+
+#[test]
+fn errors_are_spanned() {
+    let err = Template::from_meta(&parse_quote!(template(path = "a", url = "b"))).unwrap_err();
+    assert!(err.has_span());
+
+    let err = Template::from_meta(&parse_quote!(template(path = 1))).unwrap_err();
+    assert!(err.has_span());
+}
+
+#[test]
+fn unknown_key_suggests_variants() {
+    let err = Template::from_meta(&parse_quote!(template(pth = "a", url = "b"))).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unknown field: `pth`. Did you mean `path`?"
+    );
+}
+
+#[derive(Debug, FromMeta)]
+struct Optional {
+    #[darling(one_of_keys)]
+    source: Option<Source>,
+}
+
+#[test]
+fn at_most_one() {
+    let optional = Optional::from_meta(&parse_quote!(optional())).unwrap();
+    assert_eq!(optional.source, None);
+
+    let optional = Optional::from_meta(&parse_quote!(optional(path = "a"))).unwrap();
+    assert_eq!(optional.source, Some(Source::Path("a".into())));
+
+    assert!(Optional::from_meta(&parse_quote!(optional(path = "a", url = "b"))).is_err());
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(template))]
+struct WithDefault {
+    #[darling(one_of_keys, default = "default_source")]
+    source: Source,
+}
+
+fn default_source() -> Source {
+    Source::Path("index.html".into())
+}
+
+#[test]
+fn default_across_attributes() {
+    let di = parse_quote! {
+        struct Foo;
+    };
+    let parsed = WithDefault::from_derive_input(&di).unwrap();
+    assert_eq!(parsed.source, default_source());
+
+    let di = parse_quote! {
+        #[template(path = "a")]
+        #[template(url = "b")]
+        struct Foo;
+    };
+    let err = WithDefault::from_derive_input(&di).unwrap_err();
+    assert_eq!(err.to_string(), "Only one of `path`, `url` can be given");
+}
+
+#[derive(Debug, PartialEq, Eq, FromMeta, darling::ToMeta)]
+enum Output {
+    Stdout,
+    File(String),
+}
+
+#[derive(Debug, FromMeta, darling::ToMeta)]
+struct Log {
+    #[darling(one_of_keys)]
+    output: Output,
+    level: String,
+}
+
+#[test]
+fn to_meta_round_trip() {
+    use darling::ToMeta;
+    use quote::ToTokens;
+
+    for meta in [
+        parse_quote!(log(file = "a.log", level = "info")),
+        parse_quote!(log(stdout, level = "warn")),
+    ] {
+        let log = Log::from_meta(&meta).unwrap();
+        let written = log.to_meta(&syn::parse_quote!(log)).unwrap();
+        assert_eq!(
+            written.to_token_stream().to_string(),
+            meta.to_token_stream().to_string()
+        );
+    }
+}
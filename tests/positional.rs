@@ -0,0 +1,145 @@
+//! Tests for positional arguments: multi-field tuple structs and `#[darling(positional)]`.
+
+use darling::{FromMeta, ToMeta};
+use syn::{parse_quote, Meta};
+
+#[derive(Debug, PartialEq, Eq, FromMeta, ToMeta)]
+struct Range(u32, u32);
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+struct Limits {
+    range: Range,
+}
+
+#[derive(Debug, PartialEq, Eq, FromMeta, ToMeta)]
+struct Route {
+    #[darling(positional)]
+    path: String,
+    #[darling(default)]
+    method: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+#[darling(rename_all = "SCREAMING_SNAKE_CASE")]
+enum Method {
+    Get,
+    Post,
+}
+
+#[derive(Debug, PartialEq, Eq, FromMeta)]
+struct Handler(String, Method);
+
+#[test]
+fn tuple_struct() {
+    let limits = Limits::from_meta(&parse_quote!(limits(range(1, 10)))).unwrap();
+    assert_eq!(limits.range, Range(1, 10));
+}
+
+#[test]
+fn tuple_struct_too_few() {
+    let err = Limits::from_meta(&parse_quote!(limits(range(1)))).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Too few items: Expected at least 2 at range"
+    );
+}
+
+#[test]
+fn tuple_struct_too_many() {
+    let err = Limits::from_meta(&parse_quote!(limits(range(1, 2, 3)))).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Too many items: Expected no more than 2 at range"
+    );
+}
+
+#[test]
+fn tuple_struct_path_item() {
+    let handler = Handler::from_list(
+        &darling::ast::NestedMeta::parse_meta_list(quote::quote!("index", GET)).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(handler, Handler("index".into(), Method::Get));
+}
+
+#[test]
+fn positional_field() {
+    let route = Route::from_meta(&parse_quote!(route("/path", method = "GET"))).unwrap();
+    assert_eq!(
+        route,
+        Route {
+            path: "/path".into(),
+            method: Some("GET".into()),
+        }
+    );
+}
+
+#[test]
+fn positional_field_by_name() {
+    let route = Route::from_meta(&parse_quote!(route(path = "/path"))).unwrap();
+    assert_eq!(route.path, "/path");
+    assert_eq!(route.method, None);
+}
+
+#[test]
+fn positional_field_missing() {
+    let err = Route::from_meta(&parse_quote!(route(method = "GET"))).unwrap_err();
+    assert_eq!(err.to_string(), "Missing field `path`");
+}
+
+#[test]
+fn positional_after_named() {
+    let err = Route::from_meta(&parse_quote!(route(method = "GET", "/path"))).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("Positional arguments must come before named arguments"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn positional_too_many() {
+    let err = Route::from_meta(&parse_quote!(route("/path", "/other"))).unwrap_err();
+    assert_eq!(err.to_string(), "Too many items: Expected no more than 1");
+}
+
+#[test]
+fn positional_duplicate() {
+    let err = Route::from_meta(&parse_quote!(route("/path", path = "/other"))).unwrap_err();
+    assert_eq!(err.to_string(), "Duplicate field `path`");
+}
+
+#[test]
+fn round_trip() {
+    let path = parse_quote!(range);
+    let meta = Range(1, 10).to_meta(&path).unwrap();
+    assert_eq!(Range::from_meta(&meta).unwrap(), Range(1, 10));
+
+    let route = Route {
+        path: "/path".into(),
+        method: Some("GET".into()),
+    };
+    let meta: Meta = route.to_meta(&parse_quote!(route)).unwrap();
+    assert_eq!(
+        meta,
+        parse_quote!(route("/path", method = "GET")),
+        "positional fields are written first and without a name"
+    );
+    assert_eq!(Route::from_meta(&meta).unwrap(), route);
+}
+
+#[test]
+fn tuple_struct_named_item() {
+    let err = Range::from_meta(&parse_quote!(r(a = 1, 2))).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Positional arguments must not be named at 0"
+    );
+
+    let err = Range::from_meta(&parse_quote!(r(x(1), 2))).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Positional arguments must not be named at 0"
+    );
+}
@@ -0,0 +1,139 @@
+//! Tests for the field relations `conflicts_with`, `requires` and `required_if`.
+
+use darling::{FromDeriveInput, FromMeta};
+use syn::{parse_quote, Meta};
+
+#[derive(Debug, Default, FromMeta)]
+#[darling(default)]
+struct Source {
+    #[darling(conflicts_with = "url")]
+    path: Option<String>,
+    url: Option<String>,
+    #[darling(requires = "url")]
+    timeout: Option<u32>,
+    mode: Option<String>,
+    #[darling(required_if(mode = "tls"))]
+    cert: Option<String>,
+}
+
+#[test]
+fn valid() {
+    let source = Source::from_meta(&parse_quote!(source(url = "x", timeout = 5))).unwrap();
+    assert_eq!(source.url.as_deref(), Some("x"));
+    assert_eq!(source.timeout, Some(5));
+    assert_eq!(source.path, None);
+
+    let source = Source::from_meta(&parse_quote!(source(mode = "tls", cert = "c"))).unwrap();
+    assert_eq!(source.cert.as_deref(), Some("c"));
+}
+
+#[test]
+fn conflicts_with() {
+    let meta: Meta = parse_quote!(source(path = "a", url = "b"));
+    let err = Source::from_meta(&meta).unwrap_err();
+    let messages: Vec<_> = err.into_iter().map(|e| e.to_string()).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "`path` cannot be used with `url`",
+            "`url` cannot be used with `path`"
+        ]
+    );
+}
+
+#[test]
+fn conflicts_are_spanned_to_both_keys() {
+    let meta: Meta = parse_quote!(source(path = "a", url = "b"));
+    let err = Source::from_meta(&meta).unwrap_err();
+    assert!(err.into_iter().all(|e| e.has_span()));
+}
+
+#[test]
+fn requires() {
+    let err = Source::from_meta(&parse_quote!(source(timeout = 5))).unwrap_err();
+    assert_eq!(err.to_string(), "`timeout` requires `url`");
+}
+
+#[test]
+fn required_if() {
+    let err = Source::from_meta(&parse_quote!(source(mode = "tls"))).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Missing field `cert`, which is required when `mode = \"tls\"`"
+    );
+
+    assert!(Source::from_meta(&parse_quote!(source(mode = "plain"))).is_ok());
+}
+
+#[derive(Debug, FromMeta)]
+#[allow(dead_code)]
+struct Mistyped {
+    kind: Option<String>,
+    #[darling(required_if(kind = 5))]
+    size: Option<u32>,
+}
+
+#[test]
+fn required_if_invalid_value() {
+    let err = Mistyped::from_meta(&parse_quote!(mistyped(kind = "fixed"))).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Invalid value in `required_if(kind = 5)` on `size`: \
+         Unexpected type `int`, expected a string literal"
+    );
+
+    // The value is only read when `kind` is present.
+    assert!(Mistyped::from_meta(&parse_quote!(mistyped())).is_ok());
+}
+
+#[derive(Debug, FromMeta)]
+#[allow(dead_code)]
+enum Check {
+    Http {
+        #[darling(default, conflicts_with = "status_range")]
+        status: Option<u16>,
+        #[darling(default)]
+        status_range: Option<String>,
+    },
+}
+
+#[test]
+fn variant_fields() {
+    let err = Check::from_list(
+        &darling::ast::NestedMeta::parse_meta_list(quote::quote!(http(
+            status = 200,
+            status_range = "2xx"
+        )))
+        .unwrap(),
+    )
+    .unwrap_err();
+    assert_eq!(err.len(), 2);
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(mock))]
+struct Mock {
+    #[darling(default, conflicts_with = "raw")]
+    name: Option<String>,
+    #[darling(default)]
+    raw: bool,
+}
+
+#[test]
+fn across_attributes() {
+    let di = parse_quote! {
+        #[mock(name = "a")]
+        #[mock(raw)]
+        struct Foo;
+    };
+    let err = Mock::from_derive_input(&di).unwrap_err();
+    assert_eq!(err.len(), 2, "{}", err);
+
+    let di = parse_quote! {
+        #[mock(raw)]
+        struct Foo;
+    };
+    let mock = Mock::from_derive_input(&di).unwrap();
+    assert!(mock.raw);
+    assert_eq!(mock.name, None);
+}
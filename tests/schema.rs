@@ -0,0 +1,272 @@
+//! Tests for the `schema` function generated by `#[darling(schema)]`.
+
+use darling::schema::{
+    Deprecation, FieldDefault, Newtype, Schema, SchemaKind, Tagging, VariantKind,
+};
+use darling::{FromAttributes, FromDeriveInput, FromMeta};
+
+#[derive(Debug, Default, FromMeta)]
+#[darling(schema, default)]
+#[allow(dead_code)]
+struct Retry {
+    attempts: u32,
+    #[darling(alias = "wait", alias(name = "pause", hidden))]
+    backoff: Option<u64>,
+}
+
+#[derive(Debug, FromMeta)]
+#[darling(schema)]
+#[allow(dead_code)]
+enum Mode {
+    Fast,
+    #[darling(alias = "careful", deprecated = "use `fast`")]
+    Safe,
+    Custom(String),
+    Tuned {
+        level: u8,
+    },
+    #[darling(skip)]
+    Internal,
+}
+
+fn default_name() -> String {
+    "server".into()
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(server), schema)]
+#[allow(dead_code)]
+struct Server {
+    ident: syn::Ident,
+    port: u16,
+    #[darling(default = default_name)]
+    name: String,
+    #[darling(multiple, rename = "route")]
+    routes: Vec<Retry>,
+    mode: Option<Mode>,
+    #[darling(flatten)]
+    retry: Retry,
+    #[darling(skip)]
+    cache: bool,
+    #[darling(with = darling::util::parse_expr::preserve_str_literal)]
+    handler: syn::Expr,
+    #[darling(deprecated)]
+    legacy: Option<bool>,
+}
+
+#[test]
+fn receiver() {
+    let schema = Server::schema();
+    assert_eq!(schema.ty, "Server");
+    assert_eq!(schema.attributes, vec!["server"]);
+
+    let fields = schema.fields().unwrap();
+    let names: Vec<_> = fields.iter().map(|f| f.name).collect();
+    assert_eq!(
+        names,
+        vec!["port", "name", "route", "mode", "retry", "cache", "handler", "legacy"]
+    );
+
+    let port = &fields[0];
+    assert_eq!(port.ty, "u16");
+    assert!(port.required);
+    assert_eq!(port.default, None);
+    assert_eq!(port.nested, None);
+
+    let name = &fields[1];
+    assert!(!name.required);
+    assert_eq!(name.default, Some(FieldDefault::Function("default_name")));
+
+    let routes = &fields[2];
+    assert!(routes.multiple);
+    assert!(!routes.required);
+    assert_eq!(routes.nested.as_deref(), Some(&Retry::schema()));
+
+    let mode = &fields[3];
+    assert!(!mode.required);
+    assert_eq!(mode.nested.as_deref(), Some(&Mode::schema()));
+
+    let retry = &fields[4];
+    assert!(retry.flatten);
+    assert_eq!(retry.nested.as_deref(), Some(&Retry::schema()));
+
+    let cache = &fields[5];
+    assert!(cache.skip);
+    assert_eq!(cache.default, Some(FieldDefault::Trait));
+
+    assert_eq!(fields[6].nested, None);
+    assert_eq!(fields[7].deprecated, Some(Deprecation::new(None)));
+}
+
+#[test]
+fn nested_struct() {
+    let schema = Retry::schema();
+    assert!(schema.attributes.is_empty());
+    let SchemaKind::Struct(ref fields) = schema.kind else {
+        panic!("expected a struct, got {:?}", schema.kind);
+    };
+    assert!(!fields.allow_unknown_fields);
+
+    let backoff = &fields.fields[1];
+    assert_eq!(backoff.name, "backoff");
+    assert_eq!(backoff.aliases, vec!["wait"]);
+    assert_eq!(backoff.ty, "Option<u64>");
+    assert_eq!(backoff.default, Some(FieldDefault::Container));
+    assert!(!backoff.required);
+}
+
+#[test]
+fn enum_variants() {
+    let schema = Mode::schema();
+    let SchemaKind::Enum(ref body) = schema.kind else {
+        panic!("expected an enum, got {:?}", schema.kind);
+    };
+    assert_eq!(body.tagging, Tagging::External);
+
+    let variants = &body.variants;
+    let names: Vec<_> = variants.iter().map(|v| v.name).collect();
+    assert_eq!(names, vec!["fast", "safe", "custom", "tuned", "internal"]);
+    assert_eq!(variants[0].kind, VariantKind::Unit);
+    assert_eq!(variants[1].aliases, vec!["careful"]);
+    assert_eq!(
+        variants[1].deprecated,
+        Some(Deprecation::new(Some("use `fast`")))
+    );
+    assert_eq!(
+        variants[2].kind,
+        VariantKind::Newtype(Newtype::new("String"))
+    );
+    let VariantKind::Struct(ref tuned) = variants[3].kind else {
+        panic!("expected a struct variant, got {:?}", variants[3].kind);
+    };
+    assert_eq!(tuned.fields[0].name, "level");
+    assert!(tuned.fields[0].required);
+    assert!(variants[4].skip);
+}
+
+#[derive(Debug, FromMeta)]
+#[darling(schema)]
+#[allow(dead_code)]
+struct Defaults {
+    #[darling(default = || 5)]
+    count: u32,
+    #[darling(default = std::string::String::new)]
+    label: String,
+    #[darling(default = || Vec::<u8>::new().len())]
+    size: usize,
+}
+
+#[test]
+fn default_functions_are_written_as_source() {
+    let defaults: Vec<_> = Defaults::schema()
+        .fields()
+        .unwrap()
+        .iter()
+        .map(|f| f.default.clone())
+        .collect();
+    assert_eq!(
+        defaults,
+        vec![
+            Some(FieldDefault::Function("|| 5")),
+            Some(FieldDefault::Function("std::string::String::new")),
+            Some(FieldDefault::Function("|| Vec::<u8>::new().len()")),
+        ]
+    );
+}
+
+#[derive(Debug, FromMeta)]
+#[darling(schema, tag = "kind")]
+#[allow(dead_code)]
+enum Store {
+    Memory,
+    Disk { path: String },
+}
+
+#[test]
+fn internally_tagged() {
+    let SchemaKind::Enum(body) = Store::schema().kind else {
+        panic!("expected an enum");
+    };
+    assert_eq!(body.tagging, Tagging::Internal("kind"));
+}
+
+#[test]
+fn describe() {
+    assert_eq!(Retry::describe(), Some(Retry::schema()));
+    assert_eq!(<Option<Retry>>::describe(), Some(Retry::schema()));
+    assert_eq!(String::describe(), None);
+}
+
+#[derive(Debug, FromMeta)]
+#[allow(dead_code)]
+struct Undescribed {
+    value: String,
+}
+
+#[derive(Debug, FromMeta)]
+#[darling(schema)]
+#[allow(dead_code)]
+struct Wrapper(Undescribed);
+
+#[test]
+fn opt_in() {
+    assert_eq!(Undescribed::describe(), None);
+    assert_eq!(
+        Wrapper::schema().kind,
+        SchemaKind::Newtype(Newtype::new("Undescribed"))
+    );
+}
+
+#[derive(Debug, FromMeta)]
+#[darling(schema)]
+#[allow(dead_code)]
+struct Generic<T> {
+    value: T,
+}
+
+#[test]
+fn generic() {
+    let schema = Generic::<Retry>::schema();
+    assert_eq!(schema.fields().unwrap()[0].ty, "T");
+    assert_eq!(
+        schema.fields().unwrap()[0].nested.as_deref(),
+        Some(&Retry::schema())
+    );
+}
+
+#[derive(Debug, FromAttributes)]
+#[darling(attributes(route), schema)]
+#[allow(dead_code)]
+struct Route {
+    path: String,
+}
+
+#[test]
+fn from_attributes() {
+    let schema: Schema = Route::schema();
+    assert_eq!(schema.attributes, vec!["route"]);
+    assert_eq!(schema.fields().unwrap()[0].name, "path");
+}
+
+#[derive(Debug, FromMeta)]
+#[darling(schema)]
+#[allow(dead_code)]
+struct Tree {
+    label: String,
+    #[darling(multiple)]
+    child: Vec<Tree>,
+    #[darling(default)]
+    first: Option<Box<Tree>>,
+}
+
+/// A type which contains itself is described once, instead of overflowing the stack.
+#[test]
+fn recursive() {
+    let schema = Tree::schema();
+    let fields = schema.fields().unwrap();
+    let recursive = Schema::new("Tree", SchemaKind::Recursive);
+    assert_eq!(fields[1].nested.as_deref(), Some(&recursive));
+
+    // Calling it again starts from the top.
+    assert_eq!(Tree::schema(), schema);
+}
@@ -0,0 +1,103 @@
+//! Tests for the in-process test helpers in `darling::testing`.
+
+#![allow(dead_code)]
+#![cfg(feature = "testing")]
+
+use darling::testing::{self, ExpectedError};
+use darling::{assert_errors, assert_expands, assert_parses, FromDeriveInput, FromMeta};
+
+#[derive(Debug, FromMeta)]
+struct Retry {
+    attempts: u32,
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(server))]
+struct Server {
+    ident: syn::Ident,
+    port: u16,
+    retry: Option<Retry>,
+}
+
+#[test]
+fn parses() {
+    let server = assert_parses!(
+        Server,
+        #[server(port = 80)]
+        struct Api;
+    );
+    assert_eq!(server.ident, "Api");
+    assert_eq!(server.port, 80);
+    assert!(server.retry.is_none());
+}
+
+#[test]
+fn errors() {
+    assert_errors!(Server, { #[server(port = "http", retry(attempts = 3, backoff = 5))] struct Api; }, [
+        { kind: "UnknownValue", code: "D0008", snippet: "\"http\"" },
+        {
+            kind: "UnknownField",
+            message: "Unknown field: `backoff`. Available values: `attempts` at retry",
+            snippet: "backoff = 5",
+        },
+    ]);
+}
+
+#[test]
+fn errors_from_source() {
+    testing::assert_errors::<Server>(
+        "#[server] struct Api;",
+        &[ExpectedError::new().message("Missing field `port`")],
+    );
+}
+
+#[test]
+#[should_panic(expected = "expected snippet \"80\"")]
+fn wrong_snippet() {
+    assert_errors!(Server, { #[server(port = "http")] struct Api; }, [
+        { kind: "UnknownValue", snippet: "80" },
+    ]);
+}
+
+#[test]
+#[should_panic(expected = "Expected 2 errors")]
+fn wrong_count() {
+    assert_errors!(Server, { #[server] struct Api; }, [
+        { kind: "MissingField" },
+        { kind: "MissingField" },
+    ]);
+}
+
+#[test]
+#[should_panic(expected = "Expected `#[server] struct Api;` to parse")]
+fn parse_failure() {
+    assert_parses!(
+        Server,
+        #[server]
+        struct Api;
+    );
+}
+
+#[test]
+fn expands() {
+    let file = assert_expands!(
+        darling_core::derive::from_meta,
+        struct Retry {
+            #[darling(default)]
+            attempts: u32,
+        }
+    );
+    assert!(!file.items.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "to expand without errors")]
+fn expansion_with_errors() {
+    assert_expands!(
+        darling_core::derive::from_meta,
+        struct Retry {
+            #[darling(unknown_option)]
+            attempts: u32,
+        }
+    );
+}
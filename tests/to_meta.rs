@@ -0,0 +1,144 @@
+//! Tests for round-tripping values through `ToMeta` and `FromMeta`.
+
+use std::collections::BTreeMap;
+
+use darling::{
+    util::{Flag, Override, SpannedValue},
+    FromMeta, ToMeta,
+};
+use quote::quote;
+use syn::{parse_quote, Meta};
+
+#[derive(Debug, Default, Clone, PartialEq, FromMeta, ToMeta)]
+#[darling(rename_all = "snake_case")]
+enum Volume {
+    #[default]
+    Whisper,
+    Talk,
+    Shout,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, FromMeta, ToMeta)]
+#[darling(default)]
+struct Inner {
+    level: u8,
+    label: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, FromMeta, ToMeta)]
+enum Mode {
+    Fast,
+    Custom(Inner),
+    Struct { name: String, retries: u32 },
+}
+
+#[derive(Debug, FromMeta, ToMeta)]
+struct Outer {
+    #[darling(rename = "alias")]
+    name: String,
+    volume: Volume,
+    mode: Mode,
+    enabled: Flag,
+    disabled: Flag,
+    inherit: Override<Inner>,
+    #[darling(multiple, rename = "tag")]
+    tags: Vec<String>,
+    weights: BTreeMap<String, i32>,
+    #[darling(default)]
+    spanned: Option<SpannedValue<bool>>,
+    #[darling(skip)]
+    computed: usize,
+    #[darling(flatten)]
+    inner: Inner,
+}
+
+fn round_trip<T: FromMeta + ToMeta>(value: &T) -> (Meta, T) {
+    let meta = value
+        .to_meta(&parse_quote!(demo))
+        .expect("value should be present");
+    let parsed = T::from_meta(&meta).expect("emitted meta should parse");
+    (meta, parsed)
+}
+
+#[test]
+fn struct_round_trip() {
+    let input: Meta = parse_quote!(demo(
+        alias = "hello",
+        volume = "shout",
+        mode(custom(level = 3)),
+        enabled,
+        inherit,
+        tag = "a",
+        tag = "b",
+        weights(x = 1, y = "-2"),
+        level = 9,
+    ));
+    let outer = Outer::from_meta(&input).unwrap();
+    let (meta, parsed) = round_trip(&outer);
+
+    assert_eq!(
+        quote!(#meta).to_string(),
+        quote!(demo(
+            alias = "hello",
+            volume = "shout",
+            mode(custom(level = 3)),
+            enabled,
+            inherit,
+            tag = "a",
+            tag = "b",
+            weights(x = 1, y = "-2"),
+            level = 9
+        ))
+        .to_string()
+    );
+
+    assert_eq!(parsed.name, "hello");
+    assert_eq!(parsed.volume, Volume::Shout);
+    assert_eq!(parsed.mode, outer.mode);
+    assert!(parsed.enabled.is_present());
+    assert!(!parsed.disabled.is_present());
+    assert_eq!(parsed.inherit, Override::Inherit);
+    assert_eq!(parsed.tags, vec!["a", "b"]);
+    assert_eq!(parsed.weights, outer.weights);
+    assert!(parsed.spanned.is_none());
+    assert_eq!(parsed.computed, 0);
+    assert_eq!(parsed.inner, outer.inner);
+}
+
+#[test]
+fn explicit_override() {
+    let input: Meta = parse_quote!(demo(
+        alias = "x",
+        volume = "talk",
+        mode = "fast",
+        inherit(level = 1, label = "l"),
+        weights(),
+        spanned = false,
+    ));
+    let outer = Outer::from_meta(&input).unwrap();
+    let (_, parsed) = round_trip(&outer);
+
+    assert_eq!(parsed.mode, Mode::Fast);
+    assert_eq!(
+        parsed.inherit,
+        Override::Explicit(Inner {
+            level: 1,
+            label: Some("l".into()),
+        })
+    );
+    assert_eq!(parsed.spanned.map(|v| *v), Some(false));
+}
+
+#[test]
+fn struct_variant() {
+    let mode = Mode::Struct {
+        name: "n".into(),
+        retries: 2,
+    };
+    let (meta, parsed) = round_trip(&mode);
+    assert_eq!(
+        quote!(#meta).to_string(),
+        quote!(demo(struct(name = "n", retries = 2))).to_string()
+    );
+    assert_eq!(parsed, mode);
+}
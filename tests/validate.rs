@@ -0,0 +1,177 @@
+//! Tests for the container-level `#[darling(validate = ...)]` hook.
+
+use darling::{
+    ast::NestedMeta, error::Accumulator, util::Flag, util::SpannedValue, Error, FromDeriveInput,
+    FromMeta,
+};
+use syn::parse_quote;
+
+#[derive(Debug, FromMeta)]
+#[darling(validate = Self::validate)]
+struct Range {
+    min: u32,
+    max: u32,
+    #[darling(default)]
+    step: u32,
+}
+
+impl Range {
+    fn validate(&self, errors: &mut Accumulator) {
+        if self.min > self.max {
+            errors.push(Error::custom("`min` must not be greater than `max`"));
+        }
+
+        if self.step > self.max - self.min.min(self.max) {
+            errors.push(Error::custom("`step` must fit in the range"));
+        }
+    }
+}
+
+#[test]
+fn struct_valid() {
+    let range = Range::from_meta(&parse_quote!(range(min = 1, max = 10, step = 2))).unwrap();
+    assert_eq!((range.min, range.max, range.step), (1, 10, 2));
+}
+
+#[test]
+fn struct_errors_are_merged() {
+    let err = Range::from_meta(&parse_quote!(range(min = 10, max = 1, step = 2))).unwrap_err();
+    assert_eq!(err.len(), 2, "{}", err);
+    let messages: Vec<_> = err.into_iter().map(|e| e.to_string()).collect();
+    assert!(messages[0].contains("`min` must not be greater than `max`"));
+    assert!(messages[1].contains("`step` must fit in the range"));
+}
+
+#[test]
+fn not_run_when_parsing_fails() {
+    let err = Range::from_meta(&parse_quote!(range(min = 10))).unwrap_err();
+    assert_eq!(err.to_string(), "Missing field `max`");
+}
+
+fn no_debug_without_inline(opts: &Mode, errors: &mut Accumulator) {
+    if let Mode::Debug { inline } = opts {
+        if !inline.is_present() {
+            errors.push(Error::custom("`debug` requires `inline`"));
+        }
+    }
+}
+
+#[derive(Debug, FromMeta)]
+#[darling(validate = no_debug_without_inline)]
+enum Mode {
+    Release,
+    Debug { inline: Flag },
+}
+
+#[derive(Debug, FromMeta)]
+struct Profile {
+    mode: Mode,
+}
+
+#[test]
+fn enum_valid() {
+    let profile = Profile::from_meta(&parse_quote!(profile(mode(debug(inline))))).unwrap();
+    assert!(matches!(profile.mode, Mode::Debug { .. }));
+
+    let profile = Profile::from_meta(&parse_quote!(profile(mode = "release"))).unwrap();
+    assert!(matches!(profile.mode, Mode::Release));
+}
+
+#[test]
+fn enum_invalid() {
+    let err = Profile::from_meta(&parse_quote!(profile(mode(debug())))).unwrap_err();
+    assert_eq!(err.to_string(), "`debug` requires `inline` at mode");
+}
+
+#[test]
+fn enum_from_list() {
+    let err = Mode::from_list(&[parse_quote!(debug())]).unwrap_err();
+    assert_eq!(err.to_string(), "`debug` requires `inline`");
+}
+
+fn not_legacy(level: &Level, errors: &mut Accumulator) {
+    if let Level::Legacy = level {
+        errors.push(Error::custom("`legacy` is no longer supported"));
+    }
+}
+
+#[derive(Debug, FromMeta)]
+#[darling(validate = not_legacy, discriminant)]
+enum Level {
+    Modern = 1,
+    Legacy = 2,
+}
+
+/// Each method validates the value once, whether it is called directly or by another method.
+#[test]
+fn enum_every_entry_point() {
+    let results = [
+        Level::from_string("legacy"),
+        Level::from_value(&parse_quote!("legacy")),
+        Level::from_value(&parse_quote!(2)),
+        Level::from_expr(&parse_quote!(legacy)),
+        Level::from_expr(&parse_quote!(Level::Legacy)),
+        Level::from_meta(&parse_quote!(level = "legacy")),
+        Level::from_nested_meta(&parse_quote!("legacy")),
+        SpannedValue::<Level>::from_value(&parse_quote!("legacy")).map(SpannedValue::into_inner),
+    ];
+
+    for result in results {
+        let err = result.unwrap_err();
+        assert_eq!(err.len(), 1, "{:?}", err);
+        assert_eq!(err.to_string(), "`legacy` is no longer supported");
+    }
+
+    assert!(matches!(
+        Level::from_nested_meta(&NestedMeta::Lit(parse_quote!(1))),
+        Ok(Level::Modern)
+    ));
+}
+
+#[derive(Debug, FromMeta)]
+#[darling(untagged, validate = Self::validate)]
+enum Limit {
+    Unlimited,
+    Count(u32),
+}
+
+impl Limit {
+    fn validate(&self, errors: &mut Accumulator) {
+        if let Limit::Count(0) = self {
+            errors.push(Error::custom("`0` is not a limit"));
+        }
+    }
+}
+
+#[test]
+fn untagged_enum() {
+    let err = Limit::from_expr(&parse_quote!(0)).unwrap_err();
+    assert_eq!(err.to_string(), "`0` is not a limit");
+    assert!(matches!(Limit::from_word(), Ok(Limit::Unlimited)));
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(builder), validate = |opts: &Self, errors: &mut Accumulator| {
+    if opts.prefix.as_deref() == Some("") {
+        errors.push(Error::custom("`prefix` must not be empty"));
+    }
+})]
+struct Builder {
+    prefix: Option<String>,
+}
+
+#[test]
+fn derive_input_closure() {
+    let di = parse_quote! {
+        #[builder(prefix = "with_")]
+        struct Foo;
+    };
+    assert!(Builder::from_derive_input(&di).is_ok());
+
+    let di = parse_quote! {
+        #[builder(prefix = "")]
+        struct Foo;
+    };
+    let err = Builder::from_derive_input(&di).unwrap_err();
+    assert_eq!(err.to_string(), "`prefix` must not be empty");
+}
@@ -0,0 +1,73 @@
+use darling::{ast::NestedMeta, Error, FromDeriveInput, FromMeta, Warning, Warnings};
+use syn::parse_quote;
+
+/// A level which accepts values above 10, but warns about them.
+#[derive(Debug, PartialEq, Eq)]
+struct Level(u8);
+
+impl FromMeta for Level {
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        let mut errors = Error::accumulator();
+        let value = errors.handle(u8::from_list(items));
+        if let Some(value) = value.filter(|value| *value > 10) {
+            darling::warning::push(Warning::new(format!("level {} is unusually high", value)));
+        }
+        errors.finish_with(Level(value.unwrap_or_default()))
+    }
+
+    fn from_value(value: &syn::Lit) -> darling::Result<Self> {
+        let level = u8::from_value(value)?;
+        if level > 10 {
+            darling::warning::push(
+                Warning::new(format!("level {} is unusually high", level)).with_span(value),
+            );
+        }
+        Ok(Level(level))
+    }
+}
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(demo))]
+struct Receiver {
+    level: Level,
+}
+
+#[test]
+fn capture_collects_soft_violations() {
+    let di = parse_quote! {
+        #[demo(level = 12)]
+        pub struct Foo;
+    };
+
+    let (parsed, warnings) = Warnings::capture(|| Receiver::from_derive_input(&di));
+    assert_eq!(parsed.unwrap().level, Level(12));
+    assert_eq!(
+        warnings.iter().map(Warning::message).collect::<Vec<_>>(),
+        vec!["level 12 is unusually high"]
+    );
+}
+
+#[test]
+fn no_warnings_for_valid_input() {
+    let di = parse_quote! {
+        #[demo(level = 3)]
+        pub struct Foo;
+    };
+
+    let (parsed, warnings) = Warnings::capture(|| Receiver::from_derive_input(&di));
+    assert_eq!(parsed.unwrap().level, Level(3));
+    assert!(warnings.is_empty());
+}
+
+#[cfg(not(feature = "diagnostics"))]
+#[test]
+fn write_all_warnings() {
+    let warnings: Warnings = vec![Warning::new("first"), Warning::new("second")].into();
+    let tokens = warnings.clone().write().to_string();
+    assert!(tokens.contains("\"first\""));
+    assert!(tokens.contains("\"second\""));
+
+    let tokens = warnings.write_must_use().to_string();
+    assert!(tokens.contains("must_use"));
+    assert!(tokens.contains("\"first\""));
+}